@@ -0,0 +1,156 @@
+// Reader for NIST's allascii.txt (https://pml.nist.gov/cuu/Constants/Table/allascii.txt),
+// the file the built-in table was transcribed from, so a newer or pinned edition can be
+// loaded without recompiling. Columns are separated by runs of two or more spaces; values
+// such as "1.054 571 817... e-34" and "(exact)" uncertainties are read as published.
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::decimal::Decimal;
+use crate::units::Unit;
+use crate::Constant;
+
+// `line` counts from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllasciiError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for AllasciiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AllasciiError {}
+
+// The constants below the dashed line that ends the header; a file without a header is
+// read from the start
+pub fn parse_allascii(text: &str) -> Result<Vec<Constant>, AllasciiError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let first = lines
+        .iter()
+        .position(|line| line.trim().starts_with("-----"))
+        .map_or(0, |dashes| dashes + 1);
+
+    lines
+        .iter()
+        .enumerate()
+        .skip(first)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse_line(line).map_err(|message| AllasciiError {
+                line: index + 1,
+                message,
+            })
+        })
+        .collect()
+}
+
+// "alpha particle mass   6.644 657 3450 e-27   0.000 000 0021 e-27   kg"
+fn parse_line(line: &str) -> Result<Constant, String> {
+    let fields: Vec<&str> = line
+        .split("  ")
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .collect();
+
+    let (name, value, uncertainty, unit) = match fields[..] {
+        [name, value, uncertainty] => (name, value, uncertainty, None),
+        [name, value, uncertainty, unit] => (name, value, uncertainty, Some(unit)),
+        _ => {
+            return Err(format!(
+                "expected a name, value, uncertainty and unit separated by two or more spaces, \
+                 found {} fields",
+                fields.len()
+            ))
+        }
+    };
+
+    // "..." marks a value NIST truncated rather than rounded
+    let decimal = Decimal::parse(&value.replace("...", "")).map_err(|error| error.to_string())?;
+    let uncertainty = match uncertainty {
+        "(exact)" => None,
+        uncertainty => Some(
+            Decimal::parse(uncertainty)
+                .map_err(|error| error.to_string())?
+                .to_f64(),
+        ),
+    };
+
+    Ok(Constant {
+        name: Cow::Owned(name.to_string()),
+        value: decimal.to_f64(),
+        decimal,
+        unit: unit.map_or(Unit::Dimensionless, |unit| {
+            Unit::Published(Cow::Owned(unit.to_string()))
+        }),
+        uncertainty,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "             Fundamental Physical Constants --- Complete Listing
+             2022 CODATA adjustment
+
+
+  Quantity                                               Value                 Uncertainty           Unit
+-----------------------------------------------------------------------------------------------------------------------------
+";
+
+    #[test]
+    fn skips_the_header_at_the_dashed_line() {
+        let text = format!(
+            "{}alpha particle mass                                    6.644 657 3450 e-27      0.000 000 0021 e-27      kg\n",
+            HEADER
+        );
+        let constants = parse_allascii(&text).unwrap();
+
+        assert_eq!(constants.len(), 1);
+        assert_eq!(constants[0].name, "alpha particle mass");
+        assert_eq!(constants[0].decimal, Decimal::new(66446573450, -37));
+        assert_eq!(constants[0].uncertainty, Some(0.0000000021e-27));
+        assert_eq!(constants[0].unit, Unit::Published(Cow::Borrowed("kg")));
+    }
+
+    #[test]
+    fn reads_truncated_and_exact_values() {
+        let text = "reduced Planck constant        1.054 571 817... e-34    (exact)                  J s\n\
+                    speed of light in vacuum       299 792 458              (exact)                  m s^-1\n";
+        let constants = parse_allascii(text).unwrap();
+
+        assert_eq!(constants[0].decimal, Decimal::new(1054571817, -43));
+        assert!(constants[0].is_exact());
+        assert_eq!(constants[1].value, 299792458.0);
+        assert!(constants[1].is_exact());
+    }
+
+    #[test]
+    fn lines_without_a_unit_are_dimensionless() {
+        let text = "fine-structure constant        7.297 352 5643 e-3       0.000 000 0011 e-3\n";
+        let constants = parse_allascii(text).unwrap();
+
+        assert_eq!(constants[0].unit, Unit::Dimensionless);
+        assert_eq!(constants[0].uncertainty, Some(0.0000000011e-3));
+    }
+
+    #[test]
+    fn errors_name_the_line_in_the_file() {
+        let text = format!(
+            "{}electron mass        9.109 383 7139 e-31    0.000 000 0028 e-31    kg\n\n\
+             electron volt        1.602 176 634 e-19\n",
+            HEADER
+        );
+        let error = parse_allascii(&text).unwrap_err();
+
+        // Six header lines, the electron mass and a blank line come first
+        assert_eq!(error.line, 9);
+        assert!(error.message.contains("found 2 fields"), "{}", error);
+
+        let error = parse_allascii("x    1.5 e    0.1\n").unwrap_err();
+        assert_eq!(error.line, 1);
+    }
+}
@@ -0,0 +1,414 @@
+// Uncertainty budgets in the layout of JCGM 100:2008 (GUM), as in its examples: one row
+// per input quantity X_i with its estimate x_i, standard uncertainty u(x_i), sensitivity
+// coefficient c_i and contribution u_i(y) = |c_i| u(x_i), then the combined standard
+// uncertainty u_c(y) and the expanded uncertainty U = k u_c(y). Inputs are taken to be
+// uncorrelated, as the table gives no covariances.
+use std::fmt;
+
+use crate::calc::Calculation;
+use crate::csv::{quote, Delimiter};
+use crate::sensitivity::SensitivityBudget;
+use crate::Dataset;
+
+const HEADER: [&str; 6] = [
+    "Quantity X_i",
+    "Estimate x_i",
+    "Standard uncertainty u(x_i)",
+    "Unit",
+    "Sensitivity coefficient c_i",
+    "Contribution u_i(y)",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetFormat {
+    Text,
+    Markdown,
+    Csv,
+}
+
+impl BudgetFormat {
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.to_ascii_lowercase().as_str() {
+            "text" => Some(BudgetFormat::Text),
+            "markdown" | "md" => Some(BudgetFormat::Markdown),
+            "csv" => Some(BudgetFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetInput {
+    pub name: String,
+    pub value: f64,
+    // Zero for exact inputs
+    pub uncertainty: f64,
+    // None when dimensionless
+    pub unit: Option<String>,
+    // ∂y/∂x_i in the units of y and x_i
+    pub sensitivity: f64,
+}
+
+impl BudgetInput {
+    pub fn contribution(&self) -> f64 {
+        (self.sensitivity * self.uncertainty).abs()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UncertaintyBudget {
+    // Name of the output quantity Y
+    pub quantity: String,
+    pub value: f64,
+    pub unit: Option<String>,
+    pub inputs: Vec<BudgetInput>,
+    pub coverage_factor: f64,
+}
+
+impl UncertaintyBudget {
+    // The coverage factor defaults to k = 2, about 95 % for a normal distribution
+    pub fn new(quantity: &str, value: f64, unit: Option<&str>, inputs: Vec<BudgetInput>) -> Self {
+        UncertaintyBudget {
+            quantity: quantity.to_string(),
+            value,
+            unit: unit.map(str::to_string),
+            inputs,
+            coverage_factor: 2.0,
+        }
+    }
+
+    // The inputs of a sensitivity analysis, exact ones included, in the order they were named
+    pub fn from_sensitivity(
+        quantity: &str,
+        unit: Option<&str>,
+        budget: &SensitivityBudget,
+    ) -> Self {
+        let inputs = budget
+            .entries
+            .iter()
+            .map(|entry| BudgetInput {
+                name: entry.constant.name.to_string(),
+                value: entry.constant.value,
+                uncertainty: entry.uncertainty(),
+                unit: entry.constant.unit.as_str().map(str::to_string),
+                sensitivity: entry.sensitivity,
+            })
+            .collect();
+
+        UncertaintyBudget::new(quantity, budget.value, unit, inputs)
+    }
+
+    // The constants with an uncertainty that a calculator result depends on, each in the unit
+    // the dataset lists it in; exact constants contribute nothing and are left out
+    pub fn from_calculation(quantity: &str, calculation: &Calculation, dataset: &Dataset) -> Self {
+        let inputs = calculation
+            .quantity
+            .components
+            .iter()
+            .map(|component| {
+                let listed = dataset
+                    .get(&component.name)
+                    .and_then(|constant| Some((constant, constant.si_unit()?)));
+
+                match listed {
+                    Some((constant, si_unit)) => BudgetInput {
+                        name: component.name.clone(),
+                        value: constant.value,
+                        uncertainty: constant.uncertainty.unwrap_or(0.0),
+                        unit: constant.unit.as_str().map(str::to_string),
+                        sensitivity: component.sensitivity * si_unit.scale / calculation.scale,
+                    },
+                    // No longer listed (or no longer readable), so left in SI base units
+                    None => BudgetInput {
+                        name: component.name.clone(),
+                        value: component.value,
+                        uncertainty: component.uncertainty,
+                        unit: None,
+                        sensitivity: component.sensitivity / calculation.scale,
+                    },
+                }
+            })
+            .collect();
+
+        UncertaintyBudget::new(
+            quantity,
+            calculation.rounded_value(),
+            calculation.unit_text().as_deref(),
+            inputs,
+        )
+    }
+
+    pub fn with_coverage_factor(mut self, coverage_factor: f64) -> Self {
+        self.coverage_factor = coverage_factor;
+        self
+    }
+
+    // u_c(y), the root sum of squares of the contributions
+    pub fn combined_uncertainty(&self) -> f64 {
+        self.inputs
+            .iter()
+            .fold(0.0, |sum, input| sum + input.contribution().powi(2))
+            .sqrt()
+    }
+
+    // U = k u_c(y)
+    pub fn expanded_uncertainty(&self) -> f64 {
+        self.coverage_factor * self.combined_uncertainty()
+    }
+
+    pub fn render(&self, format: BudgetFormat) -> String {
+        match format {
+            BudgetFormat::Text => self.to_text(),
+            BudgetFormat::Markdown => self.to_markdown(),
+            BudgetFormat::Csv => self.to_csv(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let rows: Vec<[String; 6]> = Some(HEADER.map(str::to_string))
+            .into_iter()
+            .chain(self.input_rows())
+            .collect();
+
+        let mut widths = [0; 6];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut text = String::new();
+        for row in &rows {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            text.push_str(cells.join("  ").trim_end());
+            text.push('\n');
+        }
+        text.push('\n');
+        for (label, value) in self.summary() {
+            text.push_str(&format!("{}: {}\n", label, value));
+        }
+        text
+    }
+
+    fn to_markdown(&self) -> String {
+        let row = |cells: &[String]| {
+            let cells: Vec<String> = cells.iter().map(|cell| cell.replace('|', "\\|")).collect();
+            format!("| {} |\n", cells.join(" | "))
+        };
+
+        let mut text = row(&HEADER.map(str::to_string));
+        // Numbers right-aligned
+        text.push_str(&row(
+            &["---", "---:", "---:", "---", "---:", "---:"].map(str::to_string)
+        ));
+        for cells in self.input_rows() {
+            text.push_str(&row(&cells));
+        }
+        text.push('\n');
+        for (label, value) in self.summary() {
+            text.push_str(&format!("- **{}:** {}\n", label, value));
+        }
+        text
+    }
+
+    // The inputs, then a row for Y with u_c(y) and one for U
+    fn to_csv(&self) -> String {
+        let unit = self.unit.clone().unwrap_or_default();
+        let mut rows: Vec<[String; 6]> = vec![HEADER.map(str::to_string)];
+        rows.extend(self.input_rows());
+        rows.push([
+            self.quantity.clone(),
+            format!("{:e}", self.value),
+            rounded(self.combined_uncertainty()),
+            unit.clone(),
+            String::new(),
+            String::new(),
+        ]);
+        rows.push([
+            format!("expanded uncertainty (k = {})", self.coverage_factor),
+            rounded(self.expanded_uncertainty()),
+            String::new(),
+            unit,
+            String::new(),
+            String::new(),
+        ]);
+
+        rows.iter()
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .map(|cell| quote(cell, Delimiter::Comma))
+                    .collect();
+                cells.join(",") + "\n"
+            })
+            .collect()
+    }
+
+    fn input_rows(&self) -> impl Iterator<Item = [String; 6]> + '_ {
+        self.inputs.iter().map(|input| {
+            [
+                input.name.clone(),
+                format!("{:e}", input.value),
+                match input.uncertainty {
+                    0.0 => "0".to_string(),
+                    uncertainty => format!("{:e}", uncertainty),
+                },
+                input.unit.clone().unwrap_or_default(),
+                format!("{:.4e}", input.sensitivity),
+                rounded(input.contribution()),
+            ]
+        })
+    }
+
+    fn summary(&self) -> [(&'static str, String); 4] {
+        let with_unit = |value: String| match &self.unit {
+            Some(unit) => format!("{} {}", value, unit),
+            None => value,
+        };
+
+        [
+            (
+                "Output quantity",
+                format!(
+                    "{} = {}",
+                    self.quantity,
+                    with_unit(format!("{:e}", self.value))
+                ),
+            ),
+            (
+                "Combined standard uncertainty",
+                format!(
+                    "u_c(y) = {}",
+                    with_unit(rounded(self.combined_uncertainty()))
+                ),
+            ),
+            ("Coverage factor", format!("k = {}", self.coverage_factor)),
+            (
+                "Expanded uncertainty",
+                format!(
+                    "U = k u_c(y) = {}",
+                    with_unit(rounded(self.expanded_uncertainty()))
+                ),
+            ),
+        ]
+    }
+}
+
+// Uncertainties to two significant digits as GUM 7.2.6 recommends; "0" for exact
+fn rounded(uncertainty: f64) -> String {
+    match uncertainty {
+        0.0 => "0".to_string(),
+        uncertainty => format!("{:.1e}", uncertainty),
+    }
+}
+
+impl fmt::Display for UncertaintyBudget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget() -> UncertaintyBudget {
+        let input = |name: &str, value, uncertainty, unit: Option<&str>, sensitivity| BudgetInput {
+            name: name.to_string(),
+            value,
+            uncertainty,
+            unit: unit.map(str::to_string),
+            sensitivity,
+        };
+
+        UncertaintyBudget::new(
+            "area",
+            6.0,
+            Some("m^2"),
+            vec![
+                input("length, a", 2.0, 0.1, Some("m"), 3.0),
+                input("width|b", 3.0, 0.4, Some("m"), 2.0),
+                input("scale", 1.0, 0.0, None, 6.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn expanded_uncertainty_scales_with_k() {
+        let budget = budget();
+        let combined = budget.combined_uncertainty();
+
+        // u_c² = (3 × 0.1)² + (2 × 0.4)², and the exact input adds nothing
+        assert!((combined - 0.73f64.sqrt()).abs() < 1e-15);
+        assert_eq!(budget.expanded_uncertainty(), 2.0 * combined);
+
+        let budget = budget.with_coverage_factor(3.0);
+        assert_eq!(budget.combined_uncertainty(), combined);
+        assert_eq!(budget.expanded_uncertainty(), 3.0 * combined);
+        assert!(budget
+            .render(BudgetFormat::Text)
+            .ends_with("Coverage factor: k = 3\nExpanded uncertainty: U = k u_c(y) = 2.6e0 m^2\n"));
+        assert!(budget
+            .render(BudgetFormat::Csv)
+            .ends_with("expanded uncertainty (k = 3),2.6e0,,m^2,,\n"));
+    }
+
+    #[test]
+    fn text_columns_line_up() {
+        assert_eq!(
+            budget().render(BudgetFormat::Text),
+            "\
+Quantity X_i  Estimate x_i  Standard uncertainty u(x_i)  Unit  Sensitivity coefficient c_i  Contribution u_i(y)
+length, a     2e0           1e-1                         m     3.0000e0                     3.0e-1
+width|b       3e0           4e-1                         m     2.0000e0                     8.0e-1
+scale         1e0           0                                  6.0000e0                     0
+
+Output quantity: area = 6e0 m^2
+Combined standard uncertainty: u_c(y) = 8.5e-1 m^2
+Coverage factor: k = 2
+Expanded uncertainty: U = k u_c(y) = 1.7e0 m^2
+"
+        );
+        assert_eq!(budget().to_string(), budget().render(BudgetFormat::Text));
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_and_right_aligns_numbers() {
+        assert_eq!(
+            budget().render(BudgetFormat::parse("md").unwrap()),
+            "\
+| Quantity X_i | Estimate x_i | Standard uncertainty u(x_i) | Unit | Sensitivity coefficient c_i | Contribution u_i(y) |
+| --- | ---: | ---: | --- | ---: | ---: |
+| length, a | 2e0 | 1e-1 | m | 3.0000e0 | 3.0e-1 |
+| width\\|b | 3e0 | 4e-1 | m | 2.0000e0 | 8.0e-1 |
+| scale | 1e0 | 0 |  | 6.0000e0 | 0 |
+
+- **Output quantity:** area = 6e0 m^2
+- **Combined standard uncertainty:** u_c(y) = 8.5e-1 m^2
+- **Coverage factor:** k = 2
+- **Expanded uncertainty:** U = k u_c(y) = 1.7e0 m^2
+"
+        );
+    }
+
+    #[test]
+    fn csv_ends_with_the_output_and_expanded_rows() {
+        assert_eq!(
+            budget().render(BudgetFormat::Csv),
+            "\
+Quantity X_i,Estimate x_i,Standard uncertainty u(x_i),Unit,Sensitivity coefficient c_i,Contribution u_i(y)
+\"length, a\",2e0,1e-1,m,3.0000e0,3.0e-1
+width|b,3e0,4e-1,m,2.0000e0,8.0e-1
+scale,1e0,0,,6.0000e0,0
+area,6e0,8.5e-1,m^2,,
+expanded uncertainty (k = 2),1.7e0,,m^2,,
+"
+        );
+        assert_eq!(BudgetFormat::parse("CSV"), Some(BudgetFormat::Csv));
+        assert_eq!(BudgetFormat::parse("html"), None);
+    }
+}
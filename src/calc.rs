@@ -0,0 +1,959 @@
+// An expression calculator over the table: `h*c/(k_B*500 nm)` or `m_e*c^2 in MeV`.
+// Identifiers are constant symbols (h, k_B, m_e, see Constant::symbol()), "quoted"
+// table names and units with SI prefixes. Everything is computed in SI base units with
+// the dimension checked at each step, and the uncertainty is propagated to first order
+// from every constant used, so `h/h` is exact. Correlations between different constants
+// are not known to the table and are taken as zero.
+use std::fmt;
+use std::ops::Neg;
+
+use crate::concise::{format_concise, ConciseFormat};
+use crate::units::{parse_unit_with_provider, Dimension};
+use crate::{Constant, Dataset};
+
+// How much one input constant contributes: its value, its standard uncertainty and the
+// partial derivative of the result with respect to it, all in SI base units
+#[derive(Debug, Clone, PartialEq)]
+pub struct Component {
+    pub name: String,
+    pub value: f64,
+    pub uncertainty: f64,
+    pub sensitivity: f64,
+}
+
+impl Component {
+    // sensitivity × uncertainty, the term that enters the combined uncertainty
+    pub fn contribution(&self) -> f64 {
+        self.sensitivity * self.uncertainty
+    }
+}
+
+// A value in SI base units with its dimension and uncertainty components
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantity {
+    pub value: f64,
+    pub dimension: Dimension,
+    pub components: Vec<Component>,
+}
+
+impl Quantity {
+    pub fn exact(value: f64, dimension: Dimension) -> Self {
+        Quantity {
+            value,
+            dimension,
+            components: Vec::new(),
+        }
+    }
+
+    // The constant in SI base units; None if its unit cannot be read
+    pub fn from_constant(constant: &Constant) -> Option<Self> {
+        let unit = constant.si_unit()?;
+        let components = constant
+            .uncertainty
+            .map(|uncertainty| Component {
+                name: constant.name.to_string(),
+                value: unit.to_si(constant.value),
+                uncertainty: unit.to_si(uncertainty),
+                sensitivity: 1.0,
+            })
+            .into_iter()
+            .collect();
+
+        Some(Quantity {
+            value: unit.to_si(constant.value),
+            dimension: unit.dimension,
+            components,
+        })
+    }
+
+    // Combined standard uncertainty, the root sum of squares of the contributions
+    pub fn uncertainty(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|component| component.contribution().powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.uncertainty() == 0.0
+    }
+
+    // None when the dimensions differ
+    pub fn checked_add(&self, other: &Quantity) -> Option<Quantity> {
+        (self.dimension == other.dimension).then(|| Quantity {
+            value: self.value + other.value,
+            dimension: self.dimension,
+            components: linear(self, 1.0, other, 1.0),
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Quantity) -> Option<Quantity> {
+        self.checked_add(&-other.clone())
+    }
+
+    // Fails when a dimension exponent would not be an integer, as in m^0.5, or would
+    // leave the i8 range, as in m^200
+    pub fn powf(&self, power: f64) -> Result<Quantity, String> {
+        let exponents = [
+            self.dimension.mass,
+            self.dimension.length,
+            self.dimension.time,
+            self.dimension.current,
+            self.dimension.temperature,
+            self.dimension.amount,
+            self.dimension.luminous_intensity,
+        ]
+        .map(|exponent| exponent as f64 * power);
+
+        if exponents.iter().any(|exponent| exponent.fract() != 0.0) {
+            return Err(format!(
+                "{} to the power {} has no integer dimension",
+                describe(self.dimension),
+                power
+            ));
+        }
+        if exponents
+            .iter()
+            .any(|exponent| !(i8::MIN as f64..=i8::MAX as f64).contains(exponent))
+        {
+            return Err(format!(
+                "{} to the power {} has a dimension exponent outside {}..={}",
+                describe(self.dimension),
+                power,
+                i8::MIN,
+                i8::MAX
+            ));
+        }
+        let [mass, length, time, current, temperature, amount, luminous_intensity] =
+            exponents.map(|exponent| exponent as i8);
+
+        let value = self.value.powf(power);
+        Ok(Quantity {
+            value,
+            dimension: Dimension::new(
+                mass,
+                length,
+                time,
+                current,
+                temperature,
+                amount,
+                luminous_intensity,
+            ),
+            components: scaled(&self.components, power * self.value.powf(power - 1.0)),
+        })
+    }
+
+    // d(xy) = y dx + x dy; None when a dimension exponent leaves the i8 range
+    pub fn checked_mul(&self, other: &Quantity) -> Option<Quantity> {
+        Some(Quantity {
+            value: self.value * other.value,
+            dimension: self.dimension.checked_mul(other.dimension)?,
+            components: linear(self, other.value, other, self.value),
+        })
+    }
+
+    // d(x/y) = dx/y - x dy/y^2; None when a dimension exponent leaves the i8 range
+    pub fn checked_div(&self, other: &Quantity) -> Option<Quantity> {
+        let quotient = self.value / other.value;
+
+        Some(Quantity {
+            value: quotient,
+            dimension: self.dimension.checked_div(other.dimension)?,
+            components: linear(self, other.value.recip(), other, -quotient / other.value),
+        })
+    }
+
+    // f(x) for a dimensionless x, given f(x) and f'(x)
+    fn map(&self, value: f64, derivative: f64) -> Quantity {
+        Quantity {
+            value,
+            dimension: Dimension::NONE,
+            components: scaled(&self.components, derivative),
+        }
+    }
+}
+
+fn scaled(components: &[Component], factor: f64) -> Vec<Component> {
+    components
+        .iter()
+        .map(|component| Component {
+            sensitivity: component.sensitivity * factor,
+            ..component.clone()
+        })
+        .collect()
+}
+
+// Components of a·x + b·y, with the same constant in both merged into one
+fn linear(x: &Quantity, a: f64, y: &Quantity, b: f64) -> Vec<Component> {
+    let mut components = scaled(&x.components, a);
+
+    for component in scaled(&y.components, b) {
+        match components
+            .iter_mut()
+            .find(|known| known.name == component.name)
+        {
+            Some(known) => known.sensitivity += component.sensitivity,
+            None => components.push(component),
+        }
+    }
+
+    components
+}
+
+impl Neg for Quantity {
+    type Output = Quantity;
+
+    fn neg(self) -> Quantity {
+        Quantity {
+            value: -self.value,
+            dimension: self.dimension,
+            components: scaled(&self.components, -1.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalcError {
+    pub message: String,
+    // Character offset into the expression
+    pub position: usize,
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (column {})", self.message, self.position + 1)
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+// The result of an expression, expressed in the unit after "in" when one was given
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calculation {
+    pub quantity: Quantity,
+    // The unit as typed after "in"; None means SI base units
+    pub unit: Option<String>,
+    pub scale: f64,
+}
+
+impl Calculation {
+    pub fn value(&self) -> f64 {
+        self.quantity.value / self.scale
+    }
+
+    pub fn uncertainty(&self) -> f64 {
+        self.quantity.uncertainty() / self.scale
+    }
+
+    // "MeV" after "in", otherwise SI base units such as "kg m^2 s^-2"; None if dimensionless
+    pub fn unit_text(&self) -> Option<String> {
+        match &self.unit {
+            Some(unit) => Some(unit.clone()),
+            None if self.quantity.dimension.is_dimensionless() => None,
+            None => Some(self.quantity.dimension.to_string()),
+        }
+    }
+
+    // The uncertainty in the result's unit; None when exact
+    pub fn standard_uncertainty(&self) -> Option<f64> {
+        Some(self.uncertainty()).filter(|uncertainty| *uncertainty > 0.0)
+    }
+
+    // Exact results keep the 15 significant digits an f64 is good for, so 500 nm is
+    // 5e-7 m rather than 5.000000000000001e-7 m
+    pub fn rounded_value(&self) -> f64 {
+        match self.standard_uncertainty() {
+            Some(_) => self.value(),
+            None => format!("{:.14e}", self.value())
+                .parse()
+                .unwrap_or(self.value()),
+        }
+    }
+
+    pub fn format(&self, format: &ConciseFormat) -> String {
+        format_concise(
+            self.rounded_value(),
+            self.standard_uncertainty(),
+            self.unit_text().as_deref(),
+            format,
+        )
+    }
+}
+
+// Concise notation, e.g. "0.510 998 950 69(16) MeV"
+impl fmt::Display for Calculation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format(&ConciseFormat::default()))
+    }
+}
+
+// Evaluates expressions against a dataset, with named variables that take precedence
+// over constant symbols and units
+pub struct Calculator<'a> {
+    dataset: &'a Dataset,
+    variables: Vec<(String, Quantity)>,
+}
+
+impl<'a> Calculator<'a> {
+    pub fn new(dataset: &'a Dataset) -> Self {
+        Calculator {
+            dataset,
+            variables: Vec::new(),
+        }
+    }
+
+    pub fn set_variable(&mut self, name: &str, quantity: Quantity) {
+        match self.variables.iter_mut().find(|(known, _)| known == name) {
+            Some((_, known)) => *known = quantity,
+            None => self.variables.push((name.to_string(), quantity)),
+        }
+    }
+
+    pub fn variable(&self, name: &str) -> Option<&Quantity> {
+        self.variables
+            .iter()
+            .find(|(known, _)| known == name)
+            .map(|(_, quantity)| quantity)
+    }
+
+    pub fn variables(&self) -> &[(String, Quantity)] {
+        &self.variables
+    }
+
+    // "EXPRESSION" or "EXPRESSION in UNIT"
+    pub fn evaluate(&self, text: &str) -> Result<Calculation, CalcError> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser {
+            calculator: self,
+            tokens: &tokens,
+            position: 0,
+            end: text.chars().count(),
+        };
+
+        let quantity = parser.sum()?;
+
+        match parser.next() {
+            None => Ok(Calculation {
+                quantity,
+                unit: None,
+                scale: 1.0,
+            }),
+            Some((Token::In, offset)) => {
+                let start = parser.offset();
+                let unit_text: String = text.chars().skip(start).collect();
+                let unit =
+                    parse_unit_with_provider(unit_text.trim(), self.dataset).map_err(|error| {
+                        CalcError {
+                            message: error.to_string(),
+                            position: start,
+                        }
+                    })?;
+
+                if unit.dimension != quantity.dimension {
+                    return Err(CalcError {
+                        message: format!(
+                            "cannot express {} in {}, which is {}",
+                            describe(quantity.dimension),
+                            unit_text.trim(),
+                            describe(unit.dimension)
+                        ),
+                        position: *offset,
+                    });
+                }
+
+                Ok(Calculation {
+                    quantity,
+                    unit: Some(unit_text.trim().to_string()),
+                    scale: unit.scale,
+                })
+            }
+            Some((_, offset)) => Err(CalcError {
+                message: "expected an operator".to_string(),
+                position: *offset,
+            }),
+        }
+    }
+
+    // Variables, then constant symbols, then units such as nm or MeV. A symbol that is
+    // also a unit of another dimension (F, the Faraday constant and the farad) is
+    // rejected rather than read one way here and the other way after "in".
+    fn resolve(&self, name: &str) -> Result<Quantity, String> {
+        if let Some(quantity) = self.variable(name) {
+            return Ok(quantity.clone());
+        }
+        if name == "pi" {
+            return Ok(Quantity::exact(std::f64::consts::PI, Dimension::NONE));
+        }
+
+        let constant = self
+            .dataset
+            .constants
+            .iter()
+            .find(|constant| constant.symbol() == Some(name));
+        let unit = parse_unit_with_provider(name, self.dataset).ok();
+
+        match (constant.and_then(Quantity::from_constant), unit) {
+            (Some(quantity), Some(unit)) if quantity.dimension != unit.dimension => Err(format!(
+                "\"{}\" is both the symbol of \"{}\" and a unit of {}; quote the name for \
+                 the constant or write the unit in other units",
+                name,
+                constant.map_or("", |constant| &constant.name),
+                describe(unit.dimension)
+            )),
+            (Some(quantity), _) => Ok(quantity),
+            (None, Some(unit)) => Ok(Quantity::exact(unit.scale, unit.dimension)),
+            (None, None) => Err(format!("unknown constant, unit or variable \"{}\"", name)),
+        }
+    }
+
+    fn resolve_name(&self, name: &str) -> Option<Quantity> {
+        self.dataset
+            .get(name)
+            .or_else(|| {
+                self.dataset
+                    .constants
+                    .iter()
+                    .find(|constant| constant.name.eq_ignore_ascii_case(name))
+            })
+            .and_then(Quantity::from_constant)
+    }
+}
+
+// "energy (kg m^2 s^-2)" or just "kg m^2 s^-2" for unnamed dimensions
+fn out_of_range(position: usize) -> CalcError {
+    CalcError {
+        message: format!(
+            "a dimension exponent of the result is outside {}..={}",
+            i8::MIN,
+            i8::MAX
+        ),
+        position,
+    }
+}
+
+// For 1e308*10, 10^400 or (-1)^0.5, whose f64 result is infinite or NaN
+fn finite(quantity: Quantity, position: usize) -> Result<Quantity, CalcError> {
+    match quantity.value.is_finite() {
+        true => Ok(quantity),
+        false => Err(CalcError {
+            message: "the result is too large or not a real number".to_string(),
+            position,
+        }),
+    }
+}
+
+fn describe(dimension: Dimension) -> String {
+    match dimension.name() {
+        Some(name) if dimension.is_dimensionless() => name.to_string(),
+        Some(name) => format!("{} ({})", name, dimension),
+        None => dimension.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    // A table name in double quotes
+    Name(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    LeftParen,
+    RightParen,
+    In,
+}
+
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, CalcError> {
+    let characters: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while let Some(&character) = characters.get(position) {
+        let start = position;
+        let token = match character {
+            c if c.is_whitespace() => {
+                position += 1;
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' | '×' | '·' => Token::Star,
+            '/' => Token::Slash,
+            '^' => Token::Caret,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '"' => {
+                let length = characters[start + 1..]
+                    .iter()
+                    .position(|c| *c == '"')
+                    .ok_or_else(|| CalcError {
+                        message: "missing closing '\"'".to_string(),
+                        position: start,
+                    })?;
+                position += length + 2;
+                tokens.push((
+                    Token::Name(characters[start + 1..start + 1 + length].iter().collect()),
+                    start,
+                ));
+                continue;
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                position = number_end(&characters, start);
+                let number: String = characters[start..position].iter().collect();
+                let value: f64 = number.parse().map_err(|_| CalcError {
+                    message: format!("\"{}\" is not a number", number),
+                    position: start,
+                })?;
+                if !value.is_finite() {
+                    return Err(CalcError {
+                        message: format!("\"{}\" is too large", number),
+                        position: start,
+                    });
+                }
+                tokens.push((Token::Number(value), start));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while characters
+                    .get(position)
+                    .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+                {
+                    position += 1;
+                }
+                let word: String = characters[start..position].iter().collect();
+                tokens.push((
+                    match word.as_str() {
+                        "in" => Token::In,
+                        _ => Token::Identifier(word),
+                    },
+                    start,
+                ));
+                continue;
+            }
+            other => {
+                return Err(CalcError {
+                    message: format!("unexpected '{}'", other),
+                    position: start,
+                })
+            }
+        };
+
+        tokens.push((token, start));
+        position += 1;
+    }
+
+    Ok(tokens)
+}
+
+// Digits, a fraction and an exponent; the "e" of "2eV" is not an exponent
+fn number_end(characters: &[char], start: usize) -> usize {
+    let digits = |mut position: usize| {
+        while characters.get(position).is_some_and(|c| c.is_ascii_digit()) {
+            position += 1;
+        }
+        position
+    };
+
+    let mut position = digits(start);
+    if characters.get(position) == Some(&'.') {
+        position = digits(position + 1);
+    }
+    if matches!(characters.get(position), Some('e' | 'E')) {
+        let sign = matches!(characters.get(position + 1), Some('+' | '-')) as usize;
+        if characters
+            .get(position + 1 + sign)
+            .is_some_and(|c| c.is_ascii_digit())
+        {
+            position = digits(position + 1 + sign);
+        }
+    }
+
+    position
+}
+
+// sum     = product (("+" | "-") product)*
+// product = term (("*" | "/") term)*
+// term    = unary power*          juxtaposition binds tighter: 1/500 nm is 1/(500 nm)
+// unary   = "-" unary | power
+// power   = atom ("^" unary)?
+// atom    = number | identifier | name | function "(" sum ")" | "(" sum ")"
+struct Parser<'a, 'b> {
+    calculator: &'a Calculator<'b>,
+    tokens: &'a [(Token, usize)],
+    position: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<&'a (Token, usize)> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    // Character offset of the next token, or of the end of the text
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.end, |(_, offset)| *offset)
+    }
+
+    fn error(&self, message: String) -> CalcError {
+        CalcError {
+            message,
+            position: self.offset(),
+        }
+    }
+
+    fn sum(&mut self) -> Result<Quantity, CalcError> {
+        let mut quantity = self.product()?;
+
+        while let Some(token @ (Token::Plus | Token::Minus)) = self.peek().cloned() {
+            let offset = self.offset();
+            self.position += 1;
+            let other = self.product()?;
+
+            let result = match token {
+                Token::Plus => quantity.checked_add(&other),
+                _ => quantity.checked_sub(&other),
+            };
+            quantity = result.ok_or_else(|| CalcError {
+                message: format!(
+                    "cannot add or subtract {} and {}",
+                    describe(quantity.dimension),
+                    describe(other.dimension)
+                ),
+                position: offset,
+            })?;
+            quantity = finite(quantity, offset)?;
+        }
+
+        Ok(quantity)
+    }
+
+    fn product(&mut self) -> Result<Quantity, CalcError> {
+        let mut quantity = self.term()?;
+
+        loop {
+            let offset = self.offset();
+            let result = match self.peek() {
+                Some(Token::Star) => {
+                    self.position += 1;
+                    quantity.checked_mul(&self.term()?)
+                }
+                Some(Token::Slash) => {
+                    self.position += 1;
+                    let divisor = self.term()?;
+                    if divisor.value == 0.0 {
+                        return Err(CalcError {
+                            message: "division by zero".to_string(),
+                            position: offset,
+                        });
+                    }
+                    quantity.checked_div(&divisor)
+                }
+                _ => return Ok(quantity),
+            };
+            quantity = finite(result.ok_or_else(|| out_of_range(offset))?, offset)?;
+        }
+    }
+
+    fn term(&mut self) -> Result<Quantity, CalcError> {
+        let mut quantity = self.unary()?;
+
+        while matches!(
+            self.peek(),
+            Some(Token::Number(_) | Token::Identifier(_) | Token::Name(_) | Token::LeftParen)
+        ) {
+            let offset = self.offset();
+            let result = quantity
+                .checked_mul(&self.power()?)
+                .ok_or_else(|| out_of_range(offset))?;
+            quantity = finite(result, offset)?;
+        }
+
+        Ok(quantity)
+    }
+
+    fn unary(&mut self) -> Result<Quantity, CalcError> {
+        if self.peek() == Some(&Token::Minus) {
+            self.position += 1;
+            return Ok(-self.unary()?);
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Quantity, CalcError> {
+        let base = self.atom()?;
+
+        if self.peek() != Some(&Token::Caret) {
+            return Ok(base);
+        }
+        let caret = self.offset();
+        self.position += 1;
+
+        let offset = self.offset();
+        let exponent = self.unary()?;
+        let error = |message: String| CalcError {
+            message,
+            position: offset,
+        };
+
+        if !exponent.dimension.is_dimensionless() || !exponent.is_exact() {
+            return Err(error("an exponent must be an exact number".to_string()));
+        }
+        finite(base.powf(exponent.value).map_err(error)?, caret)
+    }
+
+    fn atom(&mut self) -> Result<Quantity, CalcError> {
+        let offset = self.offset();
+        let Some((token, _)) = self.next() else {
+            return Err(self.error("expression ends too early".to_string()));
+        };
+
+        match token.clone() {
+            Token::Number(value) => Ok(Quantity::exact(value, Dimension::NONE)),
+            Token::LeftParen => {
+                let quantity = self.sum()?;
+                self.expect_right_paren()?;
+                Ok(quantity)
+            }
+            Token::Identifier(name)
+                if FUNCTIONS.contains(&name.as_str()) && self.peek() == Some(&Token::LeftParen) =>
+            {
+                self.position += 1;
+                let argument = self.sum()?;
+                self.expect_right_paren()?;
+                function(&name, &argument).map_err(|message| CalcError {
+                    message,
+                    position: offset,
+                })
+            }
+            Token::Identifier(name) => {
+                self.calculator.resolve(&name).map_err(|message| CalcError {
+                    message,
+                    position: offset,
+                })
+            }
+            Token::Name(name) => self
+                .calculator
+                .resolve_name(&name)
+                .ok_or_else(|| CalcError {
+                    message: format!("no constant named \"{}\"", name),
+                    position: offset,
+                }),
+            _ => Err(CalcError {
+                message: "expected a number, constant or unit".to_string(),
+                position: offset,
+            }),
+        }
+    }
+
+    fn expect_right_paren(&mut self) -> Result<(), CalcError> {
+        match self.peek() {
+            Some(Token::RightParen) => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(self.error("missing ')'".to_string())),
+        }
+    }
+}
+
+const FUNCTIONS: [&str; 7] = ["sqrt", "exp", "ln", "log10", "sin", "cos", "tan"];
+
+// sqrt of any quantity whose dimension has even exponents; the others need a pure number
+// An argument for which the function is undefined or overflows, such as ln(0) or sqrt(-1),
+// is an error rather than an infinite or NaN result
+fn function(name: &str, argument: &Quantity) -> Result<Quantity, String> {
+    let result = evaluate_function(name, argument)?;
+
+    if !result.value.is_finite() {
+        return Err(format!(
+            "{} is outside the domain of {}",
+            argument.value, name
+        ));
+    }
+    Ok(result)
+}
+
+fn evaluate_function(name: &str, argument: &Quantity) -> Result<Quantity, String> {
+    if name == "sqrt" {
+        return argument
+            .powf(0.5)
+            .map_err(|_| format!("sqrt of {}", describe(argument.dimension)));
+    }
+
+    let x = argument.value;
+    let (value, derivative) = match name {
+        "exp" => (x.exp(), x.exp()),
+        "ln" => (x.ln(), x.recip()),
+        "log10" => (x.log10(), (x * std::f64::consts::LN_10).recip()),
+        "sin" => (x.sin(), x.cos()),
+        "cos" => (x.cos(), -x.sin()),
+        "tan" => (x.tan(), x.cos().powi(-2)),
+        _ => return Err(format!("unknown function \"{}\"", name)),
+    };
+
+    if !argument.dimension.is_dimensionless() {
+        return Err(format!(
+            "{} needs a dimensionless argument, not {}",
+            name,
+            describe(argument.dimension)
+        ));
+    }
+
+    Ok(argument.map(value, derivative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Codata;
+
+    fn evaluate(text: &str) -> Calculation {
+        Calculator::new(Codata::dataset())
+            .evaluate(text)
+            .unwrap_or_else(|error| panic!("{:?}: {}", text, error))
+    }
+
+    fn error(text: &str) -> CalcError {
+        Calculator::new(Codata::dataset())
+            .evaluate(text)
+            .unwrap_err()
+    }
+
+    #[test]
+    fn electron_rest_energy() {
+        let calculation = evaluate("m_e*c^2 in MeV");
+
+        assert_eq!(calculation.to_string(), "0.510 998 950 69(16) MeV");
+        assert_eq!(calculation.quantity.components.len(), 1);
+        assert_eq!(calculation.quantity.components[0].name, "electron mass");
+    }
+
+    #[test]
+    fn arithmetic_and_precedence() {
+        assert_eq!(evaluate("1 + 2 * 3").value(), 7.0);
+        assert_eq!(evaluate("(1 + 2) * 3").value(), 9.0);
+        assert_eq!(evaluate("-2^2").value(), -4.0);
+        assert_eq!(evaluate("2^3^2").value(), 512.0);
+        assert_eq!(evaluate("2^-1").value(), 0.5);
+        assert_eq!(evaluate("1e3 m in km").value(), 1.0);
+    }
+
+    #[test]
+    fn correlated_inputs_cancel() {
+        assert!(evaluate("h/h").quantity.is_exact());
+        assert!(evaluate("G - G").quantity.is_exact());
+
+        // G enters l_P² = hbar G / c³ once, so its relative uncertainty carries over
+        let squared = evaluate("hbar*G/c^3");
+        let relative = squared.uncertainty() / squared.value();
+        let g = Codata::dataset()
+            .get("Newtonian constant of gravitation")
+            .unwrap();
+        assert!((relative / (g.uncertainty.unwrap() / g.value) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn quoted_names_and_variables() {
+        let mut calculator = Calculator::new(Codata::dataset());
+        calculator.set_variable("x", evaluate("500 nm").quantity);
+
+        assert_eq!(
+            calculator.evaluate("h*c/x in eV").unwrap().value(),
+            evaluate("h*c/(500 nm) in eV").value()
+        );
+        // The table gives F to ten digits
+        let faraday = evaluate("\"Avogadro constant\" * \"elementary charge\"").value();
+        assert!((faraday / evaluate("\"Faraday constant\"").value() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn dimension_errors() {
+        assert!(error("1 m + 1 s").message.contains("cannot add"));
+        assert!(error("c in eV").message.contains("cannot express"));
+        assert!(error("sqrt(m^3)").message.contains("sqrt of volume"));
+        assert!(error("ln(2 m)").message.contains("dimensionless"));
+    }
+
+    #[test]
+    fn dimension_exponents_stay_in_range() {
+        assert!(error("m^200").message.contains("outside"));
+        assert!(error("m^100*m^100").message.contains("outside"));
+        assert!(error("m^-128/m").message.contains("outside"));
+        assert_eq!(evaluate("m^127").quantity.dimension.length, 127);
+    }
+
+    #[test]
+    fn functions_reject_arguments_outside_their_domain() {
+        for text in ["ln(0)", "ln(-1)", "log10(-1)", "sqrt(-1)", "exp(1000)"] {
+            assert!(
+                error(text).message.contains("outside the domain"),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(evaluate("sqrt(4 m^2)").value(), 2.0);
+        assert_eq!(evaluate("ln(1)").value(), 0.0);
+    }
+
+    #[test]
+    fn results_that_are_not_finite_are_errors() {
+        let division = error("2 + 1/0");
+        assert_eq!(division.message, "division by zero");
+        assert_eq!(division.position, 5);
+        assert_eq!(error("0/0").position, 1);
+
+        // At the operator that overflows or leaves the real numbers
+        assert_eq!(error("10^400").position, 2);
+        assert_eq!(error("(-1)^0.5").position, 4);
+        assert_eq!(error("1e300*1e300").position, 5);
+        assert_eq!(error("1e308 + 1e308").position, 6);
+        assert_eq!(error("h*1e300*1e300").position, 7);
+        assert_eq!(error("1e200 1e200").position, 6);
+
+        // At a literal too large for an f64
+        let literal = error("2*1e400");
+        assert_eq!(literal.message, "\"1e400\" is too large");
+        assert_eq!(literal.position, 2);
+
+        assert_eq!(evaluate("0/1").value(), 0.0);
+        assert_eq!(evaluate("10^300").value(), 1e300);
+    }
+
+    #[test]
+    fn symbols_that_are_also_units_are_ambiguous() {
+        let ambiguous = error("1 F");
+        assert!(
+            ambiguous.message.contains("Faraday constant"),
+            "{}",
+            ambiguous
+        );
+        assert!(ambiguous.message.contains("capacitance"), "{}", ambiguous);
+        assert_eq!(ambiguous.position, 2);
+
+        // The unit after "in" is only ever a unit, and eV means the same either way
+        assert_eq!(evaluate("1 C/V in F").value(), 1.0);
+        assert_eq!(evaluate("eV in J").value(), 1.602176634e-19);
+    }
+
+    #[test]
+    fn syntax_errors_point_at_their_column() {
+        assert_eq!(error("1 +").position, 3);
+        assert_eq!(error("(1").position, 2);
+        assert!(error("nosuchthing").message.contains("unknown"));
+        assert!(error("\"no such constant\"")
+            .message
+            .contains("no constant"));
+    }
+}
@@ -0,0 +1,294 @@
+// Concise notation as used by NIST: the value is rounded to the last digit of the
+// two-significant-digit uncertainty, which follows in parentheses in units of that digit,
+// e.g. 6.644 657 3450(21) × 10⁻²⁷ kg. Exact constants are written without parentheses.
+use crate::decimal::{positional, Decimal};
+use crate::unit_format::{format_unit, UnitStyle};
+use crate::Constant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExponentStyle {
+    // No exponent: 0.000 000 000 000 000 000 000 000 006 644 657 3450(21)
+    Fixed,
+    // 6.644 657 3450(21)e-27
+    Scientific,
+    // 6.644 657 3450(21) × 10⁻²⁷
+    Times,
+    // Fixed for magnitudes from 0.001 up to 10⁹, Times otherwise, so that
+    // 7294.299 541 71(17) and 299 792 458 read as NIST prints them
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grouping {
+    None,
+    // NIST style: groups of three separated by spaces, no lone trailing digit,
+    // and integer parts of four digits or fewer left alone
+    Spaces,
+    // Strict groups of three joined by underscores, like the literals in initialize_constants()
+    Underscores,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitPlacement {
+    Omit,
+    After,
+    Bracketed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConciseFormat {
+    pub exponent: ExponentStyle,
+    pub grouping: Grouping,
+    pub unit: UnitPlacement,
+    pub units: UnitStyle,
+}
+
+impl Default for ConciseFormat {
+    fn default() -> Self {
+        ConciseFormat {
+            exponent: ExponentStyle::Auto,
+            grouping: Grouping::Spaces,
+            unit: UnitPlacement::After,
+            units: UnitStyle::Ascii,
+        }
+    }
+}
+
+impl Constant {
+    // Concise notation with the default (NIST-like) format
+    pub fn concise(&self) -> String {
+        self.format_concise(&ConciseFormat::default())
+    }
+
+    pub fn format_concise(&self, format: &ConciseFormat) -> String {
+        match self.published_decimal() {
+            Some(decimal) => {
+                format_concise_decimal(decimal, self.uncertainty, self.unit.as_str(), format)
+            }
+            None => format_concise(self.value, self.uncertainty, self.unit.as_str(), format),
+        }
+    }
+}
+
+// Formats any value/uncertainty pair; a missing, zero or non-finite uncertainty counts as exact
+pub fn format_concise(
+    value: f64,
+    uncertainty: Option<f64>,
+    unit: Option<&str>,
+    format: &ConciseFormat,
+) -> String {
+    match Decimal::from_f64(value) {
+        Some(decimal) => format_concise_decimal(decimal, uncertainty, unit, format),
+        None => place_unit(value.to_string(), unit, format),
+    }
+}
+
+// Like format_concise(), but starting from published digits so none are lost or invented
+pub fn format_concise_decimal(
+    decimal: Decimal,
+    uncertainty: Option<f64>,
+    unit: Option<&str>,
+    format: &ConciseFormat,
+) -> String {
+    let uncertainty = uncertainty.filter(|u| u.is_finite() && *u > 0.0);
+    let magnitude = decimal.to_f64().abs();
+    let style = match format.exponent {
+        ExponentStyle::Auto if magnitude == 0.0 || (1e-3..1e9).contains(&magnitude) => {
+            ExponentStyle::Fixed
+        }
+        ExponentStyle::Auto => ExponentStyle::Times,
+        style => style,
+    };
+
+    // The digits to print, the power of ten of the last one and the uncertainty in its units
+    let (digits, last_digit, uncertainty_digits) = match uncertainty {
+        Some(uncertainty) => {
+            // Two significant digits of the uncertainty; rounding may carry into a new decade
+            let (uncertainty_digits, uncertainty_exponent) =
+                split_exponent(&format!("{:.1e}", uncertainty));
+            let last_digit = uncertainty_exponent - 1;
+            let digits = digits_at(decimal, last_digit);
+
+            (
+                digits,
+                last_digit,
+                Some(uncertainty_digits.replace('.', "")),
+            )
+        }
+        None => (decimal.digits(), decimal.exponent, None),
+    };
+
+    let sign = if decimal.is_negative() && !digits.trim_start_matches('0').is_empty() {
+        "-"
+    } else {
+        ""
+    };
+
+    let (mantissa, exponent, uncertainty_digits) = match style {
+        // Whole numbers keep their zeros, and so does the uncertainty: 1230(150)
+        ExponentStyle::Fixed | ExponentStyle::Auto => {
+            let padding = "0".repeat(last_digit.max(0) as usize);
+            let uncertainty_digits = uncertainty_digits.map(|digits| digits + &padding);
+            (positional(&digits, last_digit), 0, uncertainty_digits)
+        }
+        ExponentStyle::Scientific | ExponentStyle::Times => {
+            let exponent = last_digit + digits.len() as i32 - 1;
+            let (first, rest) = digits.split_at(1);
+            let mantissa = match rest {
+                "" => first.to_string(),
+                _ => format!("{}.{}", first, rest),
+            };
+            (mantissa, exponent, uncertainty_digits)
+        }
+    };
+
+    let mut text = format!("{}{}", sign, group(&mantissa, format.grouping));
+
+    if let Some(digits) = uncertainty_digits {
+        text.push_str(&format!("({})", digits));
+    }
+
+    if exponent != 0 {
+        match style {
+            ExponentStyle::Scientific => text.push_str(&format!("e{}", exponent)),
+            ExponentStyle::Times => text.push_str(&format!(" × 10{}", superscript(exponent))),
+            ExponentStyle::Fixed | ExponentStyle::Auto => {}
+        }
+    }
+
+    place_unit(text, unit, format)
+}
+
+// The digits of `decimal` down to the power of ten `last_digit`. Zeros are added as text,
+// since Decimal::mantissa_at() saturates once they no longer fit an i128, as for 1e20 with
+// an uncertainty of 3e-40
+fn digits_at(decimal: Decimal, last_digit: i32) -> String {
+    match decimal.exponent.checked_sub(last_digit) {
+        Some(padding) if padding > 0 && decimal.mantissa != 0 => {
+            decimal.digits() + &"0".repeat(padding as usize)
+        }
+        _ => decimal.mantissa_at(last_digit).unsigned_abs().to_string(),
+    }
+}
+
+fn place_unit(text: String, unit: Option<&str>, format: &ConciseFormat) -> String {
+    let unit = unit
+        .filter(|unit| !unit.is_empty())
+        .map(|unit| format_unit(unit, format.units));
+
+    match (unit, format.unit) {
+        (Some(unit), UnitPlacement::After) => format!("{} {}", text, unit),
+        (Some(unit), UnitPlacement::Bracketed) => format!("{} [{}]", text, unit),
+        _ => text,
+    }
+}
+
+fn split_exponent(text: &str) -> (String, i32) {
+    match text.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa.to_string(), exponent.parse().unwrap_or(0)),
+        None => (text.to_string(), 0),
+    }
+}
+
+fn group(mantissa: &str, grouping: Grouping) -> String {
+    let (separator, nist) = match grouping {
+        Grouping::None => return mantissa.to_string(),
+        Grouping::Spaces => (' ', true),
+        Grouping::Underscores => ('_', false),
+    };
+
+    let (sign, unsigned) = match mantissa.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", mantissa),
+    };
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let mut text = String::from(sign);
+
+    if nist && integer.len() <= 4 {
+        text.push_str(integer);
+    } else {
+        let digits: Vec<char> = integer.chars().collect();
+        for (index, digit) in digits.iter().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                text.push(separator);
+            }
+            text.push(*digit);
+        }
+    }
+
+    if let Some(fraction) = fraction {
+        text.push('.');
+
+        let digits: Vec<char> = fraction.chars().collect();
+        for (index, digit) in digits.iter().enumerate() {
+            // NIST keeps a lone final digit with the group before it
+            let lone_last = nist && index + 1 == digits.len();
+            if index > 0 && index.is_multiple_of(3) && !lone_last {
+                text.push(separator);
+            }
+            text.push(*digit);
+        }
+    }
+
+    text
+}
+
+fn superscript(exponent: i32) -> String {
+    exponent
+        .to_string()
+        .chars()
+        .map(|character| match character {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_published_digits() {
+        let format = ConciseFormat::default();
+
+        assert_eq!(
+            format_concise(6.6446573450e-27, Some(2.1e-36), Some("kg"), &format),
+            "6.644 657 3450(21) × 10⁻²⁷ kg"
+        );
+        assert_eq!(
+            format_concise(299792458.0, None, None, &format),
+            "299 792 458"
+        );
+        assert_eq!(format_concise(f64::NAN, Some(1.0), None, &format), "NaN");
+    }
+
+    #[test]
+    fn tiny_uncertainties_of_large_values_pad_with_zeros() {
+        let text = format_concise(1e20, Some(2.8e-40), None, &ConciseFormat::default());
+
+        assert!(text.starts_with("1.000 000"), "{}", text);
+        assert!(text.ends_with("0000(28) × 10²⁰"), "{}", text);
+        // From 10^20 down to the 10^-41 of the uncertainty's last digit
+        let mantissa = text.split('(').next().unwrap();
+        assert_eq!(mantissa.chars().filter(char::is_ascii_digit).count(), 62);
+
+        let negative = format_concise(-1e30, Some(1e-30), None, &ConciseFormat::default());
+        assert!(negative.starts_with("-1.000"), "{}", negative);
+        assert!(negative.ends_with("0(10) × 10³⁰"), "{}", negative);
+    }
+}
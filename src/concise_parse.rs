@@ -0,0 +1,609 @@
+// Parser for values written in concise notation, the reverse of concise.rs.
+// Accepts forms such as "1.660 539 068 92(52) e-27", "6.674_30(15)e-11",
+// "1.054 571 817... e-34", "299 792 458 (exact)" and "6.644 657 3450(21) × 10⁻²⁷ kg".
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::decimal::Decimal;
+use crate::units::Unit;
+use crate::Constant;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConciseErrorKind {
+    Empty,
+    ExpectedDigit,
+    // More significant digits than Decimal holds (18)
+    TooManyDigits,
+    MisplacedSeparator,
+    UnterminatedUncertainty,
+    InvalidUncertainty,
+    InvalidExponent,
+    ConflictingExactness,
+    UnexpectedCharacter(char),
+    UnexpectedUnit,
+}
+
+// `position` counts characters, not bytes, from the start of the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConciseParseError {
+    pub kind: ConciseErrorKind,
+    pub position: usize,
+}
+
+impl fmt::Display for ConciseParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match &self.kind {
+            ConciseErrorKind::Empty => "no number found".to_string(),
+            ConciseErrorKind::ExpectedDigit => "expected a digit".to_string(),
+            ConciseErrorKind::TooManyDigits => {
+                "too many significant digits (at most 18)".to_string()
+            }
+            ConciseErrorKind::MisplacedSeparator => {
+                "digit group separator must sit between two digits".to_string()
+            }
+            ConciseErrorKind::UnterminatedUncertainty => {
+                "missing ')' after uncertainty".to_string()
+            }
+            ConciseErrorKind::InvalidUncertainty => "uncertainty must be digits".to_string(),
+            ConciseErrorKind::InvalidExponent => "malformed exponent".to_string(),
+            ConciseErrorKind::ConflictingExactness => {
+                "a value cannot be both exact and have an uncertainty".to_string()
+            }
+            ConciseErrorKind::UnexpectedCharacter(character) => {
+                format!("unexpected character '{}'", character)
+            }
+            ConciseErrorKind::UnexpectedUnit => "unexpected text after the number".to_string(),
+        };
+
+        write!(f, "{} at position {}", message, self.position)
+    }
+}
+
+impl std::error::Error for ConciseParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConciseValue {
+    pub value: f64,
+    // The digits exactly as written
+    pub decimal: Decimal,
+    // None for exact values, whether marked by "...", "(exact)" or simply no uncertainty
+    pub uncertainty: Option<f64>,
+    pub unit: Option<String>,
+}
+
+// Parses a value with optional uncertainty, exponent and trailing unit
+pub fn parse_concise(text: &str) -> Result<ConciseValue, ConciseParseError> {
+    Parser::new(text).parse()
+}
+
+// Parses a bare value/uncertainty pair; any trailing unit is an error
+pub fn parse_value_uncertainty(text: &str) -> Result<(f64, Option<f64>), ConciseParseError> {
+    let mut parser = Parser::new(text);
+    let parsed = parser.parse()?;
+
+    match parsed.unit {
+        Some(_) => Err(parser.error_at(ConciseErrorKind::UnexpectedUnit, parser.unit_start)),
+        None => Ok((parsed.value, parsed.uncertainty)),
+    }
+}
+
+// Whether the text starts with a number followed by an uncertainty in parentheses or by
+// "...", which only concise notation has; a formula such as "2*pi" does not
+pub fn looks_concise(text: &str) -> bool {
+    let mut parser = Parser::new(text);
+    parser.skip_spaces();
+    if let Some('-' | '+' | '−') = parser.peek() {
+        parser.position += 1;
+    }
+    if parser.digit_groups().is_err() {
+        return false;
+    }
+    if parser.peek() == Some('.') && parser.peek_at(1) != Some('.') {
+        parser.position += 1;
+        if parser.digit_groups().is_err() {
+            return false;
+        }
+    }
+
+    let ellipsis = parser.ellipsis();
+    parser.skip_spaces();
+    ellipsis || parser.peek() == Some('(')
+}
+
+impl Constant {
+    // Builds a constant from concise notation; a trailing unit becomes its unit
+    pub fn from_concise(
+        name: impl Into<Cow<'static, str>>,
+        text: &str,
+    ) -> Result<Constant, ConciseParseError> {
+        let parsed = parse_concise(text)?;
+
+        Ok(Constant {
+            name: name.into(),
+            value: parsed.value,
+            decimal: parsed.decimal,
+            unit: parsed.unit.map_or(Unit::Dimensionless, |unit| {
+                Unit::Published(Cow::Owned(unit))
+            }),
+            uncertainty: parsed.uncertainty,
+        })
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    unit_start: usize,
+}
+
+impl Parser {
+    fn new(text: &str) -> Self {
+        Parser {
+            chars: text.chars().collect(),
+            position: 0,
+            unit_start: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn error(&self, kind: ConciseErrorKind) -> ConciseParseError {
+        self.error_at(kind, self.position)
+    }
+
+    fn error_at(&self, kind: ConciseErrorKind, position: usize) -> ConciseParseError {
+        ConciseParseError { kind, position }
+    }
+
+    fn skip_spaces(&mut self) {
+        while let Some(' ' | '\t' | '\u{a0}' | '\u{2009}') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn parse(&mut self) -> Result<ConciseValue, ConciseParseError> {
+        self.skip_spaces();
+        if self.peek().is_none() {
+            return Err(self.error(ConciseErrorKind::Empty));
+        }
+
+        let mut mantissa = String::new();
+        if let Some(sign @ ('-' | '+' | '−')) = self.peek() {
+            mantissa.push(if sign == '+' { '+' } else { '-' });
+            self.position += 1;
+        }
+
+        let number_start = self.position;
+        let integer = self.digit_groups()?;
+        mantissa.push_str(&integer);
+
+        let mut decimals = 0;
+        if self.peek() == Some('.') && self.peek_at(1) != Some('.') {
+            self.position += 1;
+            let fraction = self.digit_groups()?;
+            decimals = fraction.len() as i32;
+            mantissa.push('.');
+            mantissa.push_str(&fraction);
+        }
+
+        let number_end = self.position;
+        let mut exact = self.ellipsis();
+        self.skip_spaces();
+
+        let uncertainty_digits = match self.peek() {
+            Some('(') => self.uncertainty()?,
+            _ => None,
+        };
+
+        match uncertainty_digits {
+            Some(UncertaintyText::Exact) => exact = true,
+            Some(UncertaintyText::Digits(..)) if exact => {
+                return Err(self.error(ConciseErrorKind::ConflictingExactness));
+            }
+            _ => {}
+        }
+
+        self.skip_spaces();
+        let exponent_start = self.position;
+        let exponent = self.exponent()?;
+        self.skip_spaces();
+
+        // A parenthesised uncertainty may also follow the exponent
+        let uncertainty_digits = match (uncertainty_digits, self.peek()) {
+            (None, Some('(')) => {
+                let late = self.uncertainty()?;
+                if let (Some(UncertaintyText::Digits(..)), true) = (&late, exact) {
+                    return Err(self.error(ConciseErrorKind::ConflictingExactness));
+                }
+                late
+            }
+            (digits, _) => digits,
+        };
+
+        self.skip_spaces();
+        self.unit_start = self.position;
+        let unit: String = self.chars[self.position..].iter().collect();
+        let unit = unit.trim();
+
+        // Units may start with '(' as in "(GeV/c^2)^-2", a second uncertainty may not
+        let mut leading = unit.chars();
+        if let Some(first) = leading.next() {
+            let second_uncertainty =
+                first == '(' && leading.next().is_some_and(|c| c.is_ascii_digit());
+            if first.is_ascii_digit() || first == '.' || first == '_' || second_uncertainty {
+                return Err(self.error(ConciseErrorKind::UnexpectedCharacter(first)));
+            }
+        }
+
+        // Decimal refuses more than 18 significant digits, or an exponent that leaves the
+        // i32 range once the decimals are taken off
+        let decimal =
+            Decimal::parse(&format!("{}e{}", mantissa, exponent)).map_err(|_| {
+                match self.nineteenth_digit(number_start, number_end) {
+                    Some(position) => self.error_at(ConciseErrorKind::TooManyDigits, position),
+                    None => self.error_at(ConciseErrorKind::InvalidExponent, exponent_start),
+                }
+            })?;
+        let value = decimal.to_f64();
+
+        let uncertainty = match uncertainty_digits {
+            Some(UncertaintyText::Digits(digits, false)) => {
+                // Digits count in units of the mantissa's last decimal place
+                format!("{}e{}", digits, exponent - decimals)
+                    .parse::<f64>()
+                    .ok()
+            }
+            Some(UncertaintyText::Digits(digits, true)) => {
+                format!("{}e{}", digits, exponent).parse::<f64>().ok()
+            }
+            Some(UncertaintyText::Exact) | None => None,
+        };
+
+        Ok(ConciseValue {
+            value,
+            decimal,
+            uncertainty,
+            unit: (!unit.is_empty()).then(|| unit.to_string()),
+        })
+    }
+
+    // Digits with optional single spaces or underscores between groups
+    fn digit_groups(&mut self) -> Result<String, ConciseParseError> {
+        let mut digits = String::new();
+
+        loop {
+            match self.peek() {
+                Some(digit) if digit.is_ascii_digit() => {
+                    digits.push(digit);
+                    self.position += 1;
+                }
+                Some('_') => {
+                    if digits.is_empty() || !self.peek_at(1).is_some_and(|c| c.is_ascii_digit()) {
+                        return Err(self.error(ConciseErrorKind::MisplacedSeparator));
+                    }
+                    self.position += 1;
+                }
+                // A space only separates groups when a digit follows; otherwise it ends the number
+                Some(' ' | '\u{a0}' | '\u{2009}')
+                    if !digits.is_empty()
+                        && self.peek_at(1).is_some_and(|c| c.is_ascii_digit()) =>
+                {
+                    self.position += 1;
+                }
+                _ => break,
+            }
+        }
+
+        if digits.is_empty() {
+            return Err(match self.peek() {
+                Some(character) if character != '.' => {
+                    self.error(ConciseErrorKind::UnexpectedCharacter(character))
+                }
+                _ => self.error(ConciseErrorKind::ExpectedDigit),
+            });
+        }
+
+        Ok(digits)
+    }
+
+    // Where the 19th significant digit of the mantissa is, if there is one that is not a
+    // trailing zero (Decimal moves those into the exponent)
+    fn nineteenth_digit(&self, start: usize, end: usize) -> Option<usize> {
+        let digits: Vec<(usize, char)> = (start..end)
+            .map(|position| (position, self.chars[position]))
+            .filter(|(_, c)| c.is_ascii_digit())
+            .skip_while(|(_, c)| *c == '0')
+            .collect();
+        let significant = digits
+            .iter()
+            .rposition(|(_, c)| *c != '0')
+            .map_or(0, |last| last + 1);
+
+        (significant > 18).then(|| digits[18].0)
+    }
+
+    fn ellipsis(&mut self) -> bool {
+        match (self.peek(), self.peek_at(1), self.peek_at(2)) {
+            (Some('.'), Some('.'), Some('.')) => {
+                self.position += 3;
+                true
+            }
+            (Some('…'), _, _) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn uncertainty(&mut self) -> Result<Option<UncertaintyText>, ConciseParseError> {
+        let open = self.position;
+        self.position += 1;
+
+        let close = self.chars[self.position..]
+            .iter()
+            .position(|&c| c == ')')
+            .map(|offset| self.position + offset)
+            .ok_or_else(|| self.error_at(ConciseErrorKind::UnterminatedUncertainty, open))?;
+
+        let inside: String = self.chars[self.position..close].iter().collect();
+        let inside = inside.trim();
+
+        let parsed = if inside.eq_ignore_ascii_case("exact") {
+            UncertaintyText::Exact
+        } else {
+            let digits: String = inside.chars().filter(|c| *c != ' ' && *c != '_').collect();
+            let valid = !digits.is_empty()
+                && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+                && digits.matches('.').count() <= 1
+                && !digits.starts_with('.')
+                && !digits.ends_with('.');
+
+            if !valid {
+                return Err(self.error_at(ConciseErrorKind::InvalidUncertainty, open + 1));
+            }
+
+            let has_point = digits.contains('.');
+            UncertaintyText::Digits(digits, has_point)
+        };
+
+        self.position = close + 1;
+        Ok(Some(parsed))
+    }
+
+    // "e-27", "E+3", "× 10⁻²⁷", "x 10^-27" or "*10^-27"; no exponent means 0
+    fn exponent(&mut self) -> Result<i32, ConciseParseError> {
+        match self.peek() {
+            // "eV" and "E_h" are units, not exponents
+            Some('e' | 'E') if self.exponent_digits_follow() => {
+                let start = self.position;
+                self.position += 1;
+                self.skip_spaces();
+                self.signed_integer(start)
+            }
+            Some('×' | 'x' | '*' | '·') if self.times_ten_follows() => {
+                let start = self.position;
+                self.position += 1;
+                self.skip_spaces();
+                self.position += 2;
+
+                match self.peek() {
+                    Some('^') => {
+                        self.position += 1;
+                        self.signed_integer(start)
+                    }
+                    Some(c) if superscript_digit(c).is_some() || c == '⁻' || c == '⁺' => {
+                        self.superscript_integer(start)
+                    }
+                    _ => Err(self.error_at(ConciseErrorKind::InvalidExponent, start)),
+                }
+            }
+            _ => Ok(0),
+        }
+    }
+
+    fn exponent_digits_follow(&self) -> bool {
+        let mut offset = 1;
+        while let Some(' ') = self.peek_at(offset) {
+            offset += 1;
+        }
+        if let Some('-' | '+' | '−') = self.peek_at(offset) {
+            offset += 1;
+        }
+        self.peek_at(offset).is_some_and(|c| c.is_ascii_digit())
+    }
+
+    fn times_ten_follows(&self) -> bool {
+        let mut offset = 1;
+        while let Some(' ') = self.peek_at(offset) {
+            offset += 1;
+        }
+        self.peek_at(offset) == Some('1') && self.peek_at(offset + 1) == Some('0')
+    }
+
+    fn signed_integer(&mut self, start: usize) -> Result<i32, ConciseParseError> {
+        let mut text = String::new();
+
+        if let Some(sign @ ('-' | '+' | '−')) = self.peek() {
+            text.push(if sign == '+' { '+' } else { '-' });
+            self.position += 1;
+        }
+
+        while let Some(digit) = self.peek().filter(char::is_ascii_digit) {
+            text.push(digit);
+            self.position += 1;
+        }
+
+        text.parse()
+            .map_err(|_| self.error_at(ConciseErrorKind::InvalidExponent, start))
+    }
+
+    fn superscript_integer(&mut self, start: usize) -> Result<i32, ConciseParseError> {
+        let mut text = String::new();
+
+        match self.peek() {
+            Some('⁻') => {
+                text.push('-');
+                self.position += 1;
+            }
+            Some('⁺') => self.position += 1,
+            _ => {}
+        }
+
+        while let Some(digit) = self.peek().and_then(superscript_digit) {
+            text.push(digit);
+            self.position += 1;
+        }
+
+        text.parse()
+            .map_err(|_| self.error_at(ConciseErrorKind::InvalidExponent, start))
+    }
+}
+
+enum UncertaintyText {
+    Exact,
+    // The digits, and whether they carry their own decimal point
+    Digits(String, bool),
+}
+
+fn superscript_digit(character: char) -> Option<char> {
+    let digit = match character {
+        '⁰' => '0',
+        '¹' => '1',
+        '²' => '2',
+        '³' => '3',
+        '⁴' => '4',
+        '⁵' => '5',
+        '⁶' => '6',
+        '⁷' => '7',
+        '⁸' => '8',
+        '⁹' => '9',
+        _ => return None,
+    };
+
+    Some(digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> ConciseValue {
+        parse_concise(text).unwrap_or_else(|error| panic!("{:?}: {}", text, error))
+    }
+
+    fn error(text: &str) -> ConciseParseError {
+        parse_concise(text).unwrap_err()
+    }
+
+    #[test]
+    fn value_uncertainty_and_exponent() {
+        let parsed = parse("1.660 539 068 92(52) e-27");
+
+        assert_eq!(parsed.value, 1.66053906892e-27);
+        assert_eq!(parsed.decimal, Decimal::new(166053906892, -38));
+        assert_eq!(parsed.uncertainty, Some(5.2e-37));
+        assert_eq!(parsed.unit, None);
+
+        let parsed = parse("6.674_30(15)e-11");
+        assert_eq!(parsed.value, 6.67430e-11);
+        assert_eq!(parsed.uncertainty, Some(1.5e-15));
+    }
+
+    #[test]
+    fn times_ten_superscripts_and_units() {
+        let parsed = parse("6.644 657 3450(21) × 10⁻²⁷ kg");
+
+        assert_eq!(parsed.value, 6.6446573450e-27);
+        assert_eq!(parsed.decimal, Decimal::new(66446573450, -37));
+        assert_eq!(parsed.uncertainty, Some(2.1e-36));
+        assert_eq!(parsed.unit.as_deref(), Some("kg"));
+    }
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(parse("299 792 458 (exact)").uncertainty, None);
+        assert_eq!(parse("1.054 571 817... e-34").value, 1.054571817e-34);
+        assert_eq!(parse("1.054 571 817... e-34").uncertainty, None);
+        assert_eq!(parse("6.626 070 15 e-34").uncertainty, None);
+    }
+
+    #[test]
+    fn value_and_uncertainty_without_unit() {
+        assert_eq!(
+            parse_value_uncertainty("9.109 383 7139(28) e-31"),
+            Ok((9.1093837139e-31, Some(2.8e-40)))
+        );
+        assert_eq!(
+            parse_value_uncertainty("1.5(2) m").unwrap_err().kind,
+            ConciseErrorKind::UnexpectedUnit
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_character() {
+        assert_eq!(error("").kind, ConciseErrorKind::Empty);
+        assert_eq!(error("   ").kind, ConciseErrorKind::Empty);
+        assert_eq!(
+            error("1.5(2").kind,
+            ConciseErrorKind::UnterminatedUncertainty
+        );
+        assert_eq!(error("1.5(x)").kind, ConciseErrorKind::InvalidUncertainty);
+        assert_eq!(
+            error("1.5... (2)").kind,
+            ConciseErrorKind::ConflictingExactness
+        );
+
+        let misplaced = error("1 _5");
+        assert_eq!(misplaced.kind, ConciseErrorKind::UnexpectedCharacter('_'));
+        assert_eq!(misplaced.position, 2);
+
+        let too_long = error("1.2345678901234567890123");
+        assert_eq!(too_long.kind, ConciseErrorKind::TooManyDigits);
+        assert_eq!(too_long.position, 19);
+    }
+
+    #[test]
+    fn positions_count_characters() {
+        let error = error("× 10⁻²⁷");
+        assert_eq!(error.position, 0);
+
+        let error = parse_concise("6.6(2) × 10⁻x").unwrap_err();
+        assert_eq!(error.kind, ConciseErrorKind::InvalidExponent);
+    }
+
+    #[test]
+    fn recognizes_uncertainties_and_ellipses() {
+        for text in [
+            "9.803 11(5) m s^-2",
+            "-1.2 (3)",
+            "299 792 458 (exact)",
+            "1.054...e-34",
+            "2(3+4)",
+        ] {
+            assert!(looks_concise(text), "{}", text);
+        }
+        for text in ["2*pi", "9.81 m s^-2", "m_e*c^2", "(1.5)", ".5(1)", ""] {
+            assert!(!looks_concise(text), "{}", text);
+        }
+    }
+
+    #[test]
+    fn builds_constants() {
+        let constant = Constant::from_concise("local g", "9.803 11(5) m s^-2").unwrap();
+
+        assert_eq!(constant.value, 9.80311);
+        assert_eq!(constant.uncertainty, Some(5e-5));
+        assert_eq!(constant.unit, Unit::Published(Cow::Borrowed("m s^-2")));
+        assert_eq!(
+            Constant::from_concise("ratio", "2.5(1)").unwrap().unit,
+            Unit::Dimensionless
+        );
+    }
+}
@@ -0,0 +1,210 @@
+// Does a measurement agree with the table? The difference to the constant is compared with
+// the combined standard uncertainty u = sqrt(u_m² + u_ref²), giving the z-score d / u, and
+// with the expanded uncertainties at coverage factor k, giving the normalized error
+// E_n = d / sqrt(U_m² + U_ref²) = z / k; the two agree at k when |E_n| <= 1. Exact
+// constants have u_ref = 0, so only the measurement's own uncertainty counts.
+use std::fmt;
+
+use crate::{Constant, Dataset};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsistencyError {
+    UnknownConstant(String),
+    NonFiniteValue,
+    InvalidUncertainty,
+    InvalidCoverageFactor,
+}
+
+impl fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConsistencyError::UnknownConstant(name) => {
+                write!(f, "no constant named \"{}\"", name)
+            }
+            ConsistencyError::NonFiniteValue => write!(f, "the measured value is not finite"),
+            ConsistencyError::InvalidUncertainty => {
+                write!(f, "the uncertainty must be a finite number of at least 0")
+            }
+            ConsistencyError::InvalidCoverageFactor => {
+                write!(f, "the coverage factor must be a finite number above 0")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConsistencyError {}
+
+// A measurement in the unit the constant is listed in, compared with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub constant: Constant,
+    pub measured: f64,
+    pub measured_uncertainty: f64,
+    pub coverage_factor: f64,
+    // measured - tabulated
+    pub difference: f64,
+    // sqrt(u_m² + u_ref²)
+    pub combined_uncertainty: f64,
+    // difference / combined_uncertainty; 0 for equal values and infinite for different
+    // values when neither has an uncertainty
+    pub z_score: f64,
+    // z_score / coverage_factor
+    pub normalized_error: f64,
+}
+
+impl Comparison {
+    // |E_n| <= 1
+    pub fn is_consistent(&self) -> bool {
+        self.normalized_error.abs() <= 1.0
+    }
+
+    pub fn verdict(&self) -> &'static str {
+        if self.is_consistent() {
+            "pass"
+        } else {
+            "fail"
+        }
+    }
+}
+
+impl Constant {
+    pub fn compare(
+        &self,
+        measured: f64,
+        uncertainty: f64,
+        coverage_factor: f64,
+    ) -> Result<Comparison, ConsistencyError> {
+        if !measured.is_finite() {
+            return Err(ConsistencyError::NonFiniteValue);
+        }
+        if !(uncertainty.is_finite() && uncertainty >= 0.0) {
+            return Err(ConsistencyError::InvalidUncertainty);
+        }
+        if !(coverage_factor.is_finite() && coverage_factor > 0.0) {
+            return Err(ConsistencyError::InvalidCoverageFactor);
+        }
+
+        let difference = measured - self.value;
+        let combined_uncertainty = uncertainty.hypot(self.uncertainty.unwrap_or(0.0));
+        let z_score = match (difference, combined_uncertainty) {
+            (0.0, _) => 0.0,
+            (difference, 0.0) => f64::INFINITY.copysign(difference),
+            (difference, uncertainty) => difference / uncertainty,
+        };
+
+        Ok(Comparison {
+            constant: self.clone(),
+            measured,
+            measured_uncertainty: uncertainty,
+            coverage_factor,
+            difference,
+            combined_uncertainty,
+            z_score,
+            normalized_error: z_score / coverage_factor,
+        })
+    }
+}
+
+impl Dataset {
+    // Compares a measurement with the constant of this exact name
+    pub fn compare(
+        &self,
+        name: &str,
+        measured: f64,
+        uncertainty: f64,
+        coverage_factor: f64,
+    ) -> Result<Comparison, ConsistencyError> {
+        self.get(name)
+            .ok_or_else(|| ConsistencyError::UnknownConstant(name.to_string()))?
+            .compare(measured, uncertainty, coverage_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(actual: f64, expected: f64) -> bool {
+        (actual - expected).abs() <= 1e-12 * expected.abs().max(1.0)
+    }
+
+    #[test]
+    fn z_score_and_normalized_error() {
+        let constant = Constant::from_concise("x", "10.0(3)").unwrap();
+
+        // d = 0.8 and u = sqrt(0.4² + 0.3²) = 0.5
+        let comparison = constant.compare(10.8, 0.4, 2.0).unwrap();
+        assert!(close(comparison.difference, 0.8));
+        assert!(close(comparison.combined_uncertainty, 0.5));
+        assert!(close(comparison.z_score, 1.6));
+        assert!(close(comparison.normalized_error, 0.8));
+        assert_eq!(comparison.verdict(), "pass");
+
+        let comparison = constant.compare(10.8, 0.4, 1.0).unwrap();
+        assert!(close(comparison.normalized_error, 1.6));
+        assert_eq!(comparison.verdict(), "fail");
+
+        let below = constant.compare(9.2, 0.4, 2.0).unwrap();
+        assert!(close(below.z_score, -1.6));
+        assert!(below.is_consistent());
+    }
+
+    #[test]
+    fn exact_constants_count_only_the_measurement() {
+        let dataset = Dataset::codata();
+        let c = "speed of light in vacuum";
+
+        let comparison = dataset.compare(c, 299_792_458.5, 0.25, 2.0).unwrap();
+        assert_eq!(comparison.combined_uncertainty, 0.25);
+        assert_eq!(comparison.z_score, 2.0);
+        // |E_n| = 1 still passes
+        assert_eq!(comparison.normalized_error, 1.0);
+        assert!(comparison.is_consistent());
+
+        // Without any uncertainty, equal values pass and any difference fails
+        let equal = dataset.compare(c, 299_792_458.0, 0.0, 2.0).unwrap();
+        assert_eq!((equal.z_score, equal.verdict()), (0.0, "pass"));
+        let off = dataset.compare(c, 299_792_457.0, 0.0, 2.0).unwrap();
+        assert_eq!((off.z_score, off.verdict()), (f64::NEG_INFINITY, "fail"));
+    }
+
+    #[test]
+    fn compares_with_the_table() {
+        // G = 6.674 30(15)e-11, so u = sqrt(1² + 1.5²)e-15
+        let comparison = Dataset::codata()
+            .compare(
+                "Newtonian constant of gravitation",
+                6.6740e-11,
+                0.0001e-11,
+                2.0,
+            )
+            .unwrap();
+        assert!(close(comparison.z_score, -3.0 / 3.25f64.sqrt()));
+        assert!(comparison.is_consistent());
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let dataset = Dataset::codata();
+        let c = "speed of light in vacuum";
+
+        assert_eq!(
+            dataset.compare("no such constant", 1.0, 0.1, 2.0),
+            Err(ConsistencyError::UnknownConstant(
+                "no such constant".to_string()
+            ))
+        );
+        assert_eq!(
+            dataset.compare(c, f64::NAN, 0.1, 2.0),
+            Err(ConsistencyError::NonFiniteValue)
+        );
+        assert_eq!(
+            dataset.compare(c, 1.0, -0.1, 2.0),
+            Err(ConsistencyError::InvalidUncertainty)
+        );
+        assert_eq!(
+            dataset.compare(c, 1.0, 0.1, 0.0),
+            Err(ConsistencyError::InvalidCoverageFactor)
+        );
+    }
+}
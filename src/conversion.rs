@@ -0,0 +1,105 @@
+// Conversions between the energy equivalents NIST relates to each other
+// (joule, electron volt, hartree, hertz, inverse meter, kelvin, kilogram, atomic mass unit)
+// using the "X-Y relationship" entries of the table.
+use std::fmt;
+
+use crate::provider::{Codata, ConstantProvider};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    UnknownUnit(String),
+    MissingRelationship(&'static str, &'static str),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionError::UnknownUnit(unit) => write!(f, "unknown unit \"{}\"", unit),
+            ConversionError::MissingRelationship(from, to) => {
+                write!(
+                    f,
+                    "no relationship between {} and {} in the table",
+                    from, to
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+// Units as NIST spells them in relationship names, with the symbols accepted for each
+const UNITS: [(&str, &[&str]); 8] = [
+    ("joule", &["J"]),
+    ("electron volt", &["eV", "electronvolt"]),
+    ("hartree", &["E_h", "Eh"]),
+    ("hertz", &["Hz"]),
+    ("inverse meter", &["m^-1", "1/m", "inverse metre"]),
+    ("kelvin", &["K"]),
+    ("kilogram", &["kg"]),
+    ("atomic mass unit", &["u", "Da", "dalton"]),
+];
+
+// Returns the NIST spelling of a unit given either its name or its symbol
+pub fn unit_name(unit: &str) -> Option<&'static str> {
+    let unit = unit.trim();
+
+    UNITS
+        .iter()
+        .find(|(name, symbols)| name.eq_ignore_ascii_case(unit) || symbols.contains(&unit))
+        .map(|(name, _)| *name)
+}
+
+pub fn unit_names() -> impl Iterator<Item = &'static str> {
+    UNITS.iter().map(|(name, _)| *name)
+}
+
+// Converts `value` from one energy equivalent to another, e.g. convert(1.0, "eV", "K")
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    convert_with_provider(&Codata, value, from, to)
+}
+
+// Like convert(), with the relationships of another edition or an edited dataset
+pub fn convert_with_provider(
+    provider: &(impl ConstantProvider + ?Sized),
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, ConversionError> {
+    let from = unit_name(from).ok_or_else(|| ConversionError::UnknownUnit(from.to_string()))?;
+    let to = unit_name(to).ok_or_else(|| ConversionError::UnknownUnit(to.to_string()))?;
+
+    if from == to {
+        return Ok(value);
+    }
+
+    Ok(value * relationship_with_provider(provider, from, to)?)
+}
+
+// Factor taking one `from` to `to`; falls back to the reciprocal of the reverse entry
+// because the table does not list every direction
+pub fn relationship(from: &'static str, to: &'static str) -> Result<f64, ConversionError> {
+    relationship_with_provider(&Codata, from, to)
+}
+
+pub fn relationship_with_provider(
+    provider: &(impl ConstantProvider + ?Sized),
+    from: &'static str,
+    to: &'static str,
+) -> Result<f64, ConversionError> {
+    if let Some(value) = find_relationship(provider, from, to) {
+        return Ok(value);
+    }
+
+    find_relationship(provider, to, from)
+        .map(|value| 1.0 / value)
+        .ok_or(ConversionError::MissingRelationship(from, to))
+}
+
+fn find_relationship(
+    provider: &(impl ConstantProvider + ?Sized),
+    from: &str,
+    to: &str,
+) -> Option<f64> {
+    provider.value(&format!("{}-{} relationship", from, to))
+}
@@ -0,0 +1,455 @@
+// CSV and TSV export with a selectable set of columns, and import of such tables.
+// Fields are quoted as described in RFC 4180 whenever they contain the delimiter,
+// a double quote or a line break; names such as "Loschmidt constant (273.15 K, 100 kPa)" need it.
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::decimal::Decimal;
+use crate::units::Unit;
+use crate::{Constant, Dataset};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Name,
+    Symbol,
+    Value,
+    Uncertainty,
+    RelativeUncertainty,
+    Unit,
+    Category,
+}
+
+impl Column {
+    pub const ALL: [Column; 7] = [
+        Column::Name,
+        Column::Symbol,
+        Column::Value,
+        Column::Uncertainty,
+        Column::RelativeUncertainty,
+        Column::Unit,
+        Column::Category,
+    ];
+
+    pub fn header(self) -> &'static str {
+        match self {
+            Column::Name => "name",
+            Column::Symbol => "symbol",
+            Column::Value => "value",
+            Column::Uncertainty => "uncertainty",
+            Column::RelativeUncertainty => "relative uncertainty",
+            Column::Unit => "unit",
+            Column::Category => "category",
+        }
+    }
+
+    // Accepts the header text, with "_" or "-" in place of the space
+    pub fn parse(text: &str) -> Option<Column> {
+        let text = text.trim().replace(['_', '-'], " ");
+        Column::ALL
+            .into_iter()
+            .find(|column| column.header().eq_ignore_ascii_case(&text))
+    }
+
+    // Parses a comma-separated column list such as "name,value,unit"
+    pub fn parse_list(text: &str) -> Result<Vec<Column>, UnknownColumn> {
+        text.split(',')
+            .map(|name| Column::parse(name).ok_or_else(|| UnknownColumn(name.trim().to_string())))
+            .collect()
+    }
+
+    // Exact constants leave the uncertainty cells empty, dimensionless ones the unit cell;
+    // a unit that is not known is written as "unknown"
+    fn cell(self, constant: &Constant) -> String {
+        match self {
+            Column::Name => constant.name.to_string(),
+            Column::Symbol => constant.symbol().unwrap_or("").to_string(),
+            Column::Value => constant.value_text(),
+            Column::Uncertainty => constant
+                .uncertainty
+                .map(|uncertainty| format!("{:e}", uncertainty))
+                .unwrap_or_default(),
+            Column::RelativeUncertainty => constant
+                .uncertainty
+                .map(|uncertainty| format!("{:.1e}", (uncertainty / constant.value).abs()))
+                .unwrap_or_default(),
+            Column::Unit => match &constant.unit {
+                Unit::Published(unit) => unit.to_string(),
+                Unit::Dimensionless => String::new(),
+                Unit::Unknown => "unknown".to_string(),
+            },
+            Column::Category => constant.category().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownColumn(pub String);
+
+impl fmt::Display for UnknownColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let known: Vec<&str> = Column::ALL.iter().map(|column| column.header()).collect();
+        write!(
+            f,
+            "unknown column \"{}\" (known columns: {})",
+            self.0,
+            known.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownColumn {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    Comma,
+    Tab,
+}
+
+impl Delimiter {
+    fn as_char(self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Tab => '\t',
+        }
+    }
+}
+
+// Writes a header row followed by one row per constant; rows end in CRLF as RFC 4180 asks
+pub fn write_table(constants: &[Constant], columns: &[Column], delimiter: Delimiter) -> String {
+    let mut table = String::new();
+
+    write_row(
+        &mut table,
+        columns.iter().map(|column| column.header().to_string()),
+        delimiter,
+    );
+
+    for constant in constants {
+        write_row(
+            &mut table,
+            columns.iter().map(|column| column.cell(constant)),
+            delimiter,
+        );
+    }
+
+    table
+}
+
+fn write_row(table: &mut String, fields: impl Iterator<Item = String>, delimiter: Delimiter) {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            table.push(delimiter.as_char());
+        }
+        table.push_str(&quote(&field, delimiter));
+    }
+
+    table.push_str("\r\n");
+}
+
+pub fn quote(field: &str, delimiter: Delimiter) -> String {
+    let needs_quotes = field.contains(delimiter.as_char())
+        || field.contains('"')
+        || field.contains('\n')
+        || field.contains('\r')
+        // read_table() trims unquoted fields
+        || field.trim() != field;
+
+    if needs_quotes {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// `line` counts from 1 and is where the record starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+// Reads a table as written by write_table: the header names the columns, of which name and
+// value are required and symbol, relative uncertainty and category are ignored. An empty
+// uncertainty is exact, an empty unit dimensionless, and a missing unit column unknown.
+pub fn read_table(text: &str, delimiter: Delimiter) -> Result<Vec<Constant>, CsvError> {
+    let mut records = parse_records(text, delimiter)?.into_iter();
+    let Some((header_line, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+
+    let columns: Vec<Option<Column>> = header.iter().map(|field| Column::parse(field)).collect();
+    let index = |wanted: Column| columns.iter().position(|column| *column == Some(wanted));
+    let missing = |column: Column| CsvError {
+        line: header_line,
+        message: format!("the header has no \"{}\" column", column.header()),
+    };
+    let name_index = index(Column::Name).ok_or_else(|| missing(Column::Name))?;
+    let value_index = index(Column::Value).ok_or_else(|| missing(Column::Value))?;
+    let uncertainty_index = index(Column::Uncertainty);
+    let unit_index = index(Column::Unit);
+
+    records
+        .map(|(line, fields)| {
+            let error = |message: String| CsvError { line, message };
+            let field = |index: usize| fields.get(index).map_or("", String::as_str);
+
+            let decimal = Decimal::parse(field(value_index)).map_err(|e| error(e.to_string()))?;
+            let uncertainty = match uncertainty_index.map(field) {
+                None | Some("") => None,
+                Some(text) => Some(
+                    Decimal::parse(text)
+                        .map_err(|e| error(e.to_string()))?
+                        .to_f64(),
+                ),
+            };
+            let unit = match unit_index.map(field) {
+                None | Some("unknown") => Unit::Unknown,
+                Some("") => Unit::Dimensionless,
+                Some(unit) => Unit::Published(Cow::Owned(unit.to_string())),
+            };
+
+            Ok(Constant {
+                name: Cow::Owned(field(name_index).to_string()),
+                value: decimal.to_f64(),
+                decimal,
+                unit,
+                uncertainty,
+            })
+        })
+        .collect()
+}
+
+// Splits RFC 4180 records, with quoted fields that may hold delimiters, quotes and line
+// breaks; blank lines are skipped
+fn parse_records(text: &str, delimiter: Delimiter) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    // Whether the current field was quoted, so its spaces are kept
+    let mut was_quoted = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut characters = text.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if quoted && characters.peek() == Some(&'"') => {
+                characters.next();
+                field.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                was_quoted = true;
+            }
+            '\n' if quoted => {
+                line += 1;
+                field.push('\n');
+            }
+            c if quoted => field.push(c),
+            c if c == delimiter.as_char() => fields.push(take_field(&mut field, &mut was_quoted)),
+            '\r' if characters.peek() == Some(&'\n') => {}
+            '\n' => {
+                fields.push(take_field(&mut field, &mut was_quoted));
+                if fields.iter().any(|field| !field.is_empty()) {
+                    records.push((record_line, std::mem::take(&mut fields)));
+                }
+                fields.clear();
+                line += 1;
+                record_line = line;
+            }
+            // Spaces between the closing quote and the delimiter
+            c if was_quoted && c.is_whitespace() => {}
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(CsvError {
+            line: record_line,
+            message: "unterminated quoted field".to_string(),
+        });
+    }
+    fields.push(take_field(&mut field, &mut was_quoted));
+    if fields.iter().any(|field| !field.is_empty()) {
+        records.push((record_line, fields));
+    }
+
+    Ok(records)
+}
+
+// Spaces around an unquoted field are padding; inside quotes they are part of the value
+fn take_field(field: &mut String, was_quoted: &mut bool) -> String {
+    let field = std::mem::take(field);
+    match std::mem::take(was_quoted) {
+        true => field,
+        false => field.trim().to_string(),
+    }
+}
+
+impl Dataset {
+    pub fn to_csv(&self, columns: &[Column]) -> String {
+        write_table(&self.constants, columns, Delimiter::Comma)
+    }
+
+    pub fn to_tsv(&self, columns: &[Column]) -> String {
+        write_table(&self.constants, columns, Delimiter::Tab)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant(name: &str, value: f64, uncertainty: Option<f64>, unit: Unit) -> Constant {
+        Constant {
+            name: Cow::Owned(name.to_string()),
+            value,
+            decimal: Decimal::from_f64(value).unwrap(),
+            unit,
+            uncertainty,
+        }
+    }
+
+    #[test]
+    fn quotes_only_when_needed() {
+        assert_eq!(quote("plain", Delimiter::Comma), "plain");
+        assert_eq!(quote("a, b", Delimiter::Comma), "\"a, b\"");
+        assert_eq!(quote("a, b", Delimiter::Tab), "a, b");
+        assert_eq!(quote("a\tb", Delimiter::Tab), "\"a\tb\"");
+        assert_eq!(quote("say \"hi\"", Delimiter::Tab), "\"say \"\"hi\"\"\"");
+        assert_eq!(quote("two\nlines", Delimiter::Comma), "\"two\nlines\"");
+        assert_eq!(quote("cr\r", Delimiter::Comma), "\"cr\r\"");
+        assert_eq!(quote("", Delimiter::Comma), "");
+    }
+
+    #[test]
+    fn writes_rfc_4180_rows() {
+        let constants = [constant(
+            "Loschmidt constant (273.15 K, 100 kPa)",
+            2.651645804e25,
+            None,
+            Unit::Published(Cow::Borrowed("m^-3")),
+        )];
+        let table = write_table(
+            &constants,
+            &[
+                Column::Name,
+                Column::Value,
+                Column::Uncertainty,
+                Column::Unit,
+            ],
+            Delimiter::Comma,
+        );
+
+        assert_eq!(
+            table,
+            "name,value,uncertainty,unit\r\n\
+             \"Loschmidt constant (273.15 K, 100 kPa)\",2.651645804e25,,m^-3\r\n"
+        );
+    }
+
+    #[test]
+    fn awkward_fields_round_trip() {
+        let constants = vec![
+            constant("a, \"quoted\"\nname", 1.5, Some(0.25), Unit::Dimensionless),
+            constant("tab\there", -2e-30, None, Unit::Unknown),
+            constant(
+                "plain",
+                6.02214076e23,
+                None,
+                Unit::Published(Cow::Borrowed("mol^-1")),
+            ),
+        ];
+
+        for delimiter in [Delimiter::Comma, Delimiter::Tab] {
+            let table = write_table(&constants, &Column::ALL, delimiter);
+            assert_eq!(read_table(&table, delimiter).unwrap(), constants);
+        }
+    }
+
+    #[test]
+    fn reads_columns_in_any_order() {
+        let table = "Unit,relative_uncertainty,Value,Name\n\
+                     kg,,9.1093837139e-31,electron mass\n\
+                     \n\
+                     ,,2,\"two\"\n";
+        let constants = read_table(table, Delimiter::Comma).unwrap();
+
+        assert_eq!(constants.len(), 2);
+        assert_eq!(constants[0].unit, Unit::Published(Cow::Borrowed("kg")));
+        assert_eq!(constants[0].uncertainty, None);
+        assert_eq!(constants[1].name, "two");
+        assert_eq!(constants[1].unit, Unit::Dimensionless);
+        assert_eq!(read_table("", Delimiter::Comma), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn spaces_are_kept_only_inside_quotes() {
+        let table = "name , value,unit\n  \" padded  name \" , 1.5 ,\"  \"\n plain name ,2, m\n";
+        let constants = read_table(table, Delimiter::Comma).unwrap();
+
+        assert_eq!(constants[0].name, " padded  name ");
+        assert_eq!(constants[0].value, 1.5);
+        assert_eq!(constants[0].unit, Unit::Published(Cow::Borrowed("  ")));
+        assert_eq!(constants[1].name, "plain name");
+        assert_eq!(constants[1].unit, Unit::Published(Cow::Borrowed("m")));
+
+        let padded = vec![constant(" padded ", 1.0, None, Unit::Dimensionless)];
+        let written = write_table(&padded, &[Column::Name, Column::Value], Delimiter::Comma);
+        assert_eq!(
+            read_table(&written, Delimiter::Comma).unwrap()[0].name,
+            " padded "
+        );
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        assert_eq!(
+            read_table("name,unit\nx,m\n", Delimiter::Comma),
+            Err(CsvError {
+                line: 1,
+                message: "the header has no \"value\" column".to_string(),
+            })
+        );
+        assert_eq!(
+            read_table("name,value\n\"two\nlines\",1\nbad,x\n", Delimiter::Comma)
+                .unwrap_err()
+                .line,
+            4
+        );
+        assert_eq!(
+            read_table("name,value\n\"open,1\n", Delimiter::Comma),
+            Err(CsvError {
+                line: 2,
+                message: "unterminated quoted field".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn column_lists() {
+        assert_eq!(
+            Column::parse_list("name, relative-uncertainty,UNIT"),
+            Ok(vec![
+                Column::Name,
+                Column::RelativeUncertainty,
+                Column::Unit
+            ])
+        );
+        assert_eq!(
+            Column::parse_list("name,colour"),
+            Err(UnknownColumn("colour".to_string()))
+        );
+    }
+}
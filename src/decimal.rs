@@ -0,0 +1,269 @@
+// The decimal digits a value was published with, kept next to its f64 so that
+// significant trailing zeros (6.644 657 3450) and the exact NIST text survive.
+use std::fmt;
+
+// mantissa × 10^exponent, e.g. 6.644 657 3450e-27 is Decimal::new(66446573450, -37)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    pub mantissa: i64,
+    pub exponent: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDecimalError(pub String);
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{}\" is not a decimal number", self.0)
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+impl Decimal {
+    pub const fn new(mantissa: i64, exponent: i32) -> Self {
+        Decimal { mantissa, exponent }
+    }
+
+    // The shortest digits that read back as `value`; used when no published text exists.
+    // None for an infinity or NaN, which have no digits
+    pub fn from_f64(value: f64) -> Option<Self> {
+        match value.is_finite() {
+            true => Decimal::parse(&format!("{:e}", value)).ok(),
+            false => None,
+        }
+    }
+
+    // Reads "6.644_657_3450e-27", "299 792 458" or "-1.5E3"; spaces and underscores are ignored
+    pub fn parse(text: &str) -> Result<Self, ParseDecimalError> {
+        let error = || ParseDecimalError(text.to_string());
+        let cleaned: String = text.chars().filter(|c| *c != '_' && *c != ' ').collect();
+
+        let (number, exponent) = match cleaned.split_once(['e', 'E']) {
+            Some((number, exponent)) => (number, exponent.parse::<i32>().map_err(|_| error())?),
+            None => (cleaned.as_str(), 0),
+        };
+        let (negative, number) = match number.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, number.strip_prefix('+').unwrap_or(number)),
+        };
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+        let digits = format!("{}{}", integer, fraction);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(error());
+        }
+
+        // Zeros that would overflow the mantissa move into the exponent: 602214076000000000000000
+        let mut digits = digits.trim_start_matches('0');
+        // Checked throughout, so "1.5e-2147483648" is an error rather than an overflow
+        let mut exponent = i32::try_from(fraction.len())
+            .ok()
+            .and_then(|places| exponent.checked_sub(places))
+            .ok_or_else(error)?;
+        while digits.len() > 18 && digits.ends_with('0') {
+            digits = &digits[..digits.len() - 1];
+            exponent = exponent.checked_add(1).ok_or_else(error)?;
+        }
+
+        let mantissa: i64 = match digits {
+            "" => 0,
+            digits => digits.parse().map_err(|_| error())?,
+        };
+
+        Ok(Decimal::new(
+            if negative { -mantissa } else { mantissa },
+            exponent,
+        ))
+    }
+
+    // Correctly rounded, since str::parse is
+    pub fn to_f64(self) -> f64 {
+        format!("{}e{}", self.mantissa, self.exponent)
+            .parse()
+            .unwrap_or(f64::NAN)
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.mantissa < 0
+    }
+
+    // Digits of the mantissa without sign, e.g. "66446573450"
+    pub fn digits(self) -> String {
+        self.mantissa.unsigned_abs().to_string()
+    }
+
+    pub fn significant_digits(self) -> usize {
+        self.digits().trim_start_matches('0').len().max(1)
+    }
+
+    // Power of ten of the first digit, e.g. -27 for 6.644 657 3450e-27
+    pub fn leading_exponent(self) -> i32 {
+        self.exponent.saturating_add(self.digits().len() as i32 - 1)
+    }
+
+    // The mantissa at a different last-digit exponent, rounding half away from zero
+    // when digits are dropped and padding with zeros when digits are added
+    pub fn mantissa_at(self, exponent: i32) -> i128 {
+        let mantissa = self.mantissa as i128;
+        let shift = self.exponent.saturating_sub(exponent);
+
+        if shift >= 0 {
+            match 10i128.checked_pow(shift as u32) {
+                Some(scale) => mantissa.saturating_mul(scale),
+                None if mantissa == 0 => 0,
+                None => i128::MAX * mantissa.signum(),
+            }
+        } else {
+            match 10i128.checked_pow(shift.unsigned_abs()) {
+                Some(scale) => {
+                    let quotient = mantissa / scale;
+                    let remainder = mantissa % scale;
+                    if 2 * remainder.abs() >= scale {
+                        quotient + mantissa.signum()
+                    } else {
+                        quotient
+                    }
+                }
+                None => 0,
+            }
+        }
+    }
+
+    // Scientific notation with exactly the stored digits: "6.6446573450e-27"
+    pub fn to_scientific_string(self) -> String {
+        let digits = self.digits();
+        let sign = if self.is_negative() { "-" } else { "" };
+        let (first, rest) = digits.split_at(1);
+
+        if rest.is_empty() {
+            format!("{}{}e{}", sign, first, self.leading_exponent())
+        } else {
+            format!("{}{}.{}e{}", sign, first, rest, self.leading_exponent())
+        }
+    }
+}
+
+// Plain positional notation with exactly the stored digits: "0.000 …" is never abbreviated
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        write!(f, "{}{}", sign, positional(&self.digits(), self.exponent))
+    }
+}
+
+// Places the decimal point in `digits` whose last digit has power `exponent`
+pub(crate) fn positional(digits: &str, exponent: i32) -> String {
+    if exponent >= 0 {
+        return format!("{}{}", digits, "0".repeat(exponent as usize));
+    }
+
+    let decimals = (-exponent) as usize;
+    let padded = if digits.len() <= decimals {
+        format!("{}{}", "0".repeat(decimals + 1 - digits.len()), digits)
+    } else {
+        digits.to_string()
+    };
+    let (integer, fraction) = padded.split_at(padded.len() - decimals);
+
+    format!("{}.{}", integer, fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Decimal {
+        Decimal::parse(text).unwrap()
+    }
+
+    #[test]
+    fn keeps_published_digits() {
+        assert_eq!(parse("6.644_657_3450e-27"), Decimal::new(66446573450, -37));
+        assert_eq!(parse("299 792 458"), Decimal::new(299792458, 0));
+        assert_eq!(parse("-1.5E3"), Decimal::new(-15, 2));
+        assert_eq!(parse("+0.0010"), Decimal::new(10, -4));
+        assert_eq!(parse(".5"), Decimal::new(5, -1));
+        assert_eq!(parse("5."), Decimal::new(5, 0));
+    }
+
+    #[test]
+    fn trailing_zeros_of_long_integers_move_into_the_exponent() {
+        assert_eq!(
+            parse("602214076000000000000000"),
+            Decimal::new(602214076000000000, 6)
+        );
+        assert_eq!(parse("0000000000000000000001"), Decimal::new(1, 0));
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        for text in [
+            "",
+            "-",
+            "e5",
+            "1e",
+            "1.2.3",
+            "1,5",
+            "0x10",
+            "1e5.5",
+            "12345678901234567891",
+        ] {
+            assert_eq!(
+                Decimal::parse(text),
+                Err(ParseDecimalError(text.to_string())),
+                "{:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_exponents_that_overflow() {
+        assert!(Decimal::parse("1.5e-2147483648").is_err());
+        assert!(Decimal::parse("1e99999999999").is_err());
+        assert!(Decimal::parse("1000000000000000000000e2147483647").is_err());
+        assert_eq!(parse("1e2147483647"), Decimal::new(1, i32::MAX));
+    }
+
+    #[test]
+    fn shortest_digits_of_an_f64() {
+        assert_eq!(Decimal::from_f64(0.1), Some(Decimal::new(1, -1)));
+        assert_eq!(
+            Decimal::from_f64(6.62607015e-34),
+            Some(Decimal::new(662607015, -42))
+        );
+        assert_eq!(Decimal::from_f64(0.1 + 0.2).unwrap().to_f64(), 0.1 + 0.2);
+    }
+
+    #[test]
+    fn infinities_and_nan_have_no_digits() {
+        assert_eq!(Decimal::from_f64(f64::INFINITY), None);
+        assert_eq!(Decimal::from_f64(f64::NEG_INFINITY), None);
+        assert_eq!(Decimal::from_f64(f64::NAN), None);
+        assert_eq!(Decimal::from_f64(f64::MAX).unwrap().to_f64(), f64::MAX);
+    }
+
+    #[test]
+    fn formats() {
+        let decimal = parse("6.6446573450e-27");
+
+        assert_eq!(decimal.to_scientific_string(), "6.6446573450e-27");
+        assert_eq!(decimal.significant_digits(), 11);
+        assert_eq!(decimal.leading_exponent(), -27);
+        assert_eq!(parse("-0.00120").to_string(), "-0.00120");
+        assert_eq!(parse("1.5e3").to_string(), "1500");
+    }
+
+    #[test]
+    fn rescales_the_mantissa() {
+        let decimal = Decimal::new(12345, -4);
+
+        assert_eq!(decimal.mantissa_at(-2), 123);
+        assert_eq!(decimal.mantissa_at(-1), 12);
+        assert_eq!(decimal.mantissa_at(-6), 1234500);
+        assert_eq!(Decimal::new(-15, -1).mantissa_at(0), -2);
+        assert_eq!(decimal.mantissa_at(i32::MAX), 0);
+        assert_eq!(Decimal::new(1, i32::MAX).mantissa_at(i32::MIN), i128::MAX);
+    }
+}
@@ -0,0 +1,341 @@
+// Exact values of the SI defining constants (fixed in 2019) and of the table entries that
+// are rational combinations of them, such as K_J = 2e/h and R_K = h/e².
+// Entries involving π (h-bar, Stefan-Boltzmann, ...) or the Wien roots are not rational.
+use crate::decimal::Decimal;
+use crate::provider::{Codata, ConstantProvider, MissingConstant};
+use crate::rational::Rational;
+use crate::Constant;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefiningConstants {
+    // Δν_Cs in Hz
+    pub caesium_frequency: Rational,
+    // c in m s^-1
+    pub speed_of_light: Rational,
+    // h in J Hz^-1
+    pub planck: Rational,
+    // e in C
+    pub elementary_charge: Rational,
+    // k_B in J K^-1
+    pub boltzmann: Rational,
+    // N_A in mol^-1
+    pub avogadro: Rational,
+    // K_cd in lm W^-1
+    pub luminous_efficacy: Rational,
+}
+
+impl DefiningConstants {
+    pub fn si_2019() -> Self {
+        DefiningConstants {
+            caesium_frequency: decimal(9_192_631_770, 0),
+            speed_of_light: decimal(299_792_458, 0),
+            planck: decimal(662_607_015, -42),
+            elementary_charge: decimal(1_602_176_634, -28),
+            boltzmann: decimal(1_380_649, -29),
+            avogadro: decimal(602_214_076, 15),
+            luminous_efficacy: decimal(683, 0),
+        }
+    }
+
+    // The exact value of a table entry, if it is a rational function of the defining constants
+    pub fn exact_value(&self, name: &str) -> Option<Rational> {
+        FORMULAS
+            .iter()
+            .find(|(formula_name, _)| *formula_name == name)
+            .map(|(_, formula)| formula(self))
+    }
+
+    // Every rational entry of the table, cut off at its last published digit, must give back
+    // exactly those digits
+    pub fn check_against_table(&self) -> Vec<ExactCheck> {
+        self.check_against_provider(&Codata)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // Like check_against_table(), against the entries of another edition or dataset
+    pub fn check_against_provider(
+        &self,
+        provider: &(impl ConstantProvider + ?Sized),
+    ) -> Result<Vec<ExactCheck>, MissingConstant> {
+        FORMULAS
+            .iter()
+            .map(|(name, formula)| {
+                let tabulated = provider.require(name)?;
+
+                Ok(ExactCheck {
+                    name,
+                    exact: formula(self),
+                    tabulated: tabulated.published_decimal(),
+                    tabulated_value: tabulated.value,
+                })
+            })
+            .collect()
+    }
+
+    fn h(&self) -> Rational {
+        self.planck.clone()
+    }
+
+    fn e(&self) -> Rational {
+        self.elementary_charge.clone()
+    }
+
+    fn k(&self) -> Rational {
+        self.boltzmann.clone()
+    }
+
+    fn c(&self) -> Rational {
+        self.speed_of_light.clone()
+    }
+
+    fn hc(&self) -> Rational {
+        self.h() * self.c()
+    }
+
+    fn c_squared(&self) -> Rational {
+        self.c() * self.c()
+    }
+
+    fn josephson(&self) -> Rational {
+        Rational::from(2) * self.e() / self.h()
+    }
+
+    fn von_klitzing(&self) -> Rational {
+        self.h() / (self.e() * self.e())
+    }
+}
+
+impl Default for DefiningConstants {
+    fn default() -> Self {
+        DefiningConstants::si_2019()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactCheck {
+    pub name: &'static str,
+    pub exact: Rational,
+    // None if the tabulated value is not finite
+    pub tabulated: Option<Decimal>,
+    pub tabulated_value: f64,
+}
+
+impl ExactCheck {
+    pub fn agrees(&self) -> bool {
+        self.tabulated
+            .is_some_and(|tabulated| self.exact.agrees_with(tabulated))
+    }
+
+    // The exact value to as many significant digits as the table prints, or to the 17 an
+    // f64 holds if it has no digits
+    pub fn rounded(&self) -> String {
+        self.exact
+            .to_scientific_string(self.tabulated.map_or(17, Decimal::significant_digits))
+    }
+}
+
+impl Constant {
+    // Some for the exact entries that are rational in the defining constants
+    pub fn exact_value(&self) -> Option<Rational> {
+        DefiningConstants::si_2019().exact_value(&self.name)
+    }
+}
+
+fn decimal(mantissa: i64, exponent: i32) -> Rational {
+    Rational::from(Decimal::new(mantissa, exponent))
+}
+
+// The 1990 conventional values K_J-90 and R_K-90
+fn josephson_90() -> Rational {
+    decimal(4_835_979, 8)
+}
+
+fn von_klitzing_90() -> Rational {
+    decimal(25_812_807, -3)
+}
+
+fn standard_temperature() -> Rational {
+    decimal(27_315, -2)
+}
+
+type Formula = fn(&DefiningConstants) -> Rational;
+
+// Names as they appear in initialize_constants(), typos included
+const FORMULAS: &[(&str, Formula)] = &[
+    ("atomic unit of charge", |d| d.e()),
+    ("Avogadro constant", |d| d.avogadro.clone()),
+    ("Boltzmann constant", |d| d.k()),
+    ("Boltzmann constant in eV/K", |d| d.k() / d.e()),
+    ("Boltzmann constant in Hz/K", |d| d.k() / d.h()),
+    ("Boltzmann constant in inverse meter per kelvin", |d| {
+        d.k() / d.hc()
+    }),
+    ("conductance quantum", |d| {
+        Rational::from(2) * d.e() * d.e() / d.h()
+    }),
+    ("conventional value of ampere-90", |d| {
+        josephson_90() * von_klitzing_90() / (d.josephson() * d.von_klitzing())
+    }),
+    ("conventional value of coulomb-90", |d| {
+        josephson_90() * von_klitzing_90() / (d.josephson() * d.von_klitzing())
+    }),
+    ("conventional value of farad-90", |d| {
+        von_klitzing_90() / d.von_klitzing()
+    }),
+    ("conventional value of henry-90", |d| {
+        d.von_klitzing() / von_klitzing_90()
+    }),
+    ("conventional value of Josephson constant", |_| {
+        josephson_90()
+    }),
+    ("conventional value of ohm-90", |d| {
+        d.von_klitzing() / von_klitzing_90()
+    }),
+    ("conventional value of volt-90", |d| {
+        josephson_90() / d.josephson()
+    }),
+    ("conventional value of von Klitzing constant", |_| {
+        von_klitzing_90()
+    }),
+    ("conventional value of watt-90", |d| {
+        josephson_90().powi(2) * von_klitzing_90() / (d.josephson().powi(2) * d.von_klitzing())
+    }),
+    ("electron volt", |d| d.e()),
+    ("electron volt-hertz relationship", |d| d.e() / d.h()),
+    ("electron volt-inverse meter relationship", |d| {
+        d.e() / d.hc()
+    }),
+    ("electron volt-joule relationship", |d| d.e()),
+    ("electron volt-kelvin relationship", |d| d.e() / d.k()),
+    ("electron volt-kilogram relationship", |d| {
+        d.e() / d.c_squared()
+    }),
+    ("elementary charge", |d| d.e()),
+    ("Faraday constant", |d| d.avogadro.clone() * d.e()),
+    ("first radiation constant for spectral radiance", |d| {
+        Rational::from(2) * d.h() * d.c_squared()
+    }),
+    ("hertz-electron volt relationship", |d| d.h() / d.e()),
+    ("hertz-inverse meter relationship", |d| d.c().recip()),
+    ("hertz-joule relationship", |d| d.h()),
+    ("hertz-kelvin relationship", |d| d.h() / d.k()),
+    ("hertz-kilogram relationship", |d| d.h() / d.c_squared()),
+    ("hyperfine transition frequency of Cs-133", |d| {
+        d.caesium_frequency.clone()
+    }),
+    ("inverse meter-electron volt relationship", |d| {
+        d.hc() / d.e()
+    }),
+    ("inverse meter-hertz relationship", |d| d.c()),
+    ("inverse meter-joule relationship", |d| d.hc()),
+    ("inverse meter-kelvin relationship", |d| d.hc() / d.k()),
+    ("inverse meter-kilogram relationship", |d| d.h() / d.c()),
+    ("inverse of conductance quantum", |d| {
+        d.h() / (Rational::from(2) * d.e() * d.e())
+    }),
+    ("Josephson constant", |d| d.josephson()),
+    ("joule-electron volt relationship", |d| d.e().recip()),
+    ("joule-hertz relationship", |d| d.h().recip()),
+    ("joule-inverse meter relationship", |d| d.hc().recip()),
+    ("joule-kelvin relationship", |d| d.k().recip()),
+    ("joule-kilogram relationship", |d| d.c_squared().recip()),
+    ("kelvin-electron volt relationship", |d| d.k() / d.e()),
+    ("kelvin-hertz relationship", |d| d.k() / d.h()),
+    ("kelvin-inverse meter relationship", |d| d.k() / d.hc()),
+    ("kelvin-joule relationship", |d| d.k()),
+    ("kelvin-kilogram relationship", |d| d.k() / d.c_squared()),
+    ("kilogram-electron volt relationship", |d| {
+        d.c_squared() / d.e()
+    }),
+    ("kilogram-hertz relationship", |d| d.c_squared() / d.h()),
+    ("kilogram-inverse meter relationship", |d| d.c() / d.h()),
+    ("kilogram-joule relationship", |d| d.c_squared()),
+    ("kilogram-kelvin relationship", |d| d.c_squared() / d.k()),
+    ("Loschmidt constant (273.15 K, 100 kPa)", |d| {
+        decimal(100_000, 0) / (d.k() * standard_temperature())
+    }),
+    ("Loschmidt constant (273.15 K, 101.325 kPa)", |d| {
+        decimal(101_325, 0) / (d.k() * standard_temperature())
+    }),
+    ("luminous efficacy", |d| d.luminous_efficacy.clone()),
+    ("mag. flux quantum", |d| d.h() / (Rational::from(2) * d.e())),
+    ("molar gas constant", |d| d.avogadro.clone() * d.k()),
+    ("molar Planck constant", |d| d.avogadro.clone() * d.h()),
+    ("molar volume of ideal gas (273.15 K, 100 kPa)", |d| {
+        d.avogadro.clone() * d.k() * standard_temperature() / decimal(100_000, 0)
+    }),
+    // The name says 273.13 K, the value is for 273.15 K like its neighbour
+    ("molar volume of ideal gas (273.15 K, 101.325 kPa)", |d| {
+        d.avogadro.clone() * d.k() * standard_temperature() / decimal(101_325, 0)
+    }),
+    ("natural unit of velocity", |d| d.c()),
+    ("Planck constant", |d| d.h()),
+    ("Planck constant in eV/Hz", |d| d.h() / d.e()),
+    ("second radiation constant", |d| d.hc() / d.k()),
+    ("speed of light in vacuum", |d| d.c()),
+    ("standard acceleration of gravity", |_| decimal(980_665, -5)),
+    ("standard atmosphere", |_| decimal(101_325, 0)),
+    ("standard-state pressure", |_| decimal(100_000, 0)),
+    ("von Klitzing constant", |d| d.von_klitzing()),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Codata;
+
+    #[test]
+    fn rational_entries_match_the_table() {
+        let checks = DefiningConstants::si_2019().check_against_table();
+
+        assert_eq!(checks.len(), FORMULAS.len());
+        for check in &checks {
+            assert!(
+                check.agrees(),
+                "{}: exact {} but the table has {}",
+                check.name,
+                check.rounded(),
+                check.tabulated_value
+            );
+        }
+    }
+
+    #[test]
+    fn josephson_constant_is_two_e_over_h() {
+        let defining = DefiningConstants::si_2019();
+        let josephson = defining.exact_value("Josephson constant").unwrap();
+
+        assert_eq!(
+            josephson * defining.planck.clone() / defining.elementary_charge.clone(),
+            Rational::from(2)
+        );
+    }
+
+    #[test]
+    fn measured_entries_have_no_exact_value() {
+        let table = Codata::dataset();
+
+        assert!(table
+            .get("Newtonian constant of gravitation")
+            .unwrap()
+            .exact_value()
+            .is_none());
+        assert_eq!(
+            table.get("elementary charge").unwrap().exact_value(),
+            Some(decimal(1_602_176_634, -28))
+        );
+    }
+
+    #[test]
+    fn missing_entries_are_reported() {
+        let mut dataset = Codata.to_dataset();
+        dataset
+            .constants
+            .retain(|constant| constant.name != "Faraday constant");
+
+        let error = DefiningConstants::si_2019()
+            .check_against_provider(&dataset)
+            .unwrap_err();
+        assert_eq!(error.name, "Faraday constant");
+    }
+}
@@ -0,0 +1,208 @@
+// Reverse lookup of a number found in the wild: which entry, or which simple power of an
+// entry (1/α is 137.036), is it? Hits are ranked by how many standard uncertainties of
+// the candidate they are away, so a rounded copy of a measured value still ranks first.
+use std::cmp::Ordering;
+
+use crate::decimal::Decimal;
+use crate::{Constant, Dataset};
+
+// Powers of each entry that are tried, in the order ties are listed
+const POWERS: [i32; 6] = [1, -1, 2, -2, 3, -3];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identification<'a> {
+    pub constant: &'a Constant,
+    // The number is close to constant^power
+    pub power: i32,
+    pub candidate: f64,
+    // Standard uncertainty of constant^power; zero for exact constants
+    pub uncertainty: f64,
+    // value - candidate
+    pub difference: f64,
+    pub relative_difference: f64,
+    // |difference| / uncertainty; 0 for a match to f64 precision and infinite for an
+    // exact constant that differs at all
+    pub standard_uncertainties: f64,
+}
+
+impl Identification<'_> {
+    // "fine-structure constant", "1/fine-structure constant" or "(Planck constant)^2"
+    pub fn description(&self) -> String {
+        match self.power {
+            1 => self.constant.name.to_string(),
+            -1 => format!("1/{}", self.constant.name),
+            power if power < 0 => format!("1/({})^{}", self.constant.name, -power),
+            power => format!("({})^{}", self.constant.name, power),
+        }
+    }
+
+    // The constant's unit raised to the power, e.g. "(J s)^-1"; None when dimensionless
+    pub fn unit(&self) -> Option<String> {
+        let unit = self.constant.unit.as_str()?;
+
+        match self.power {
+            1 => Some(unit.to_string()),
+            power if !unit.contains(' ') && !unit.contains('^') => {
+                Some(format!("{}^{}", unit, power))
+            }
+            power => Some(format!("({})^{}", unit, power)),
+        }
+    }
+
+    fn rank(&self, other: &Self) -> Ordering {
+        self.standard_uncertainties
+            .total_cmp(&other.standard_uncertainties)
+            .then(
+                self.relative_difference
+                    .abs()
+                    .total_cmp(&other.relative_difference.abs()),
+            )
+    }
+}
+
+impl Dataset {
+    // Entries and their reciprocals, squares and cubes within `tolerance` (relative) of
+    // `value`, best first
+    pub fn identify(&self, value: f64, tolerance: f64) -> Vec<Identification<'_>> {
+        let mut hits: Vec<Identification> = self
+            .constants
+            .iter()
+            .flat_map(|constant| {
+                POWERS
+                    .iter()
+                    .filter_map(move |power| identify_power(constant, *power, value, tolerance))
+            })
+            .collect();
+
+        // sort_by is stable, so ties keep table order
+        hits.sort_by(|a, b| a.rank(b));
+        hits
+    }
+}
+
+// The relative tolerance for a number rounded at its last digit, half a unit in that digit:
+// 1.602e-19 stands for anything within 0.0005e-19
+pub fn last_digit_tolerance(decimal: Decimal) -> f64 {
+    0.5 * Decimal::new(1, decimal.exponent).to_f64() / decimal.to_f64().abs()
+}
+
+fn identify_power(
+    constant: &Constant,
+    power: i32,
+    value: f64,
+    tolerance: f64,
+) -> Option<Identification<'_>> {
+    let candidate = constant.value.powi(power);
+    if candidate == 0.0 || !candidate.is_finite() {
+        return None;
+    }
+
+    let difference = value - candidate;
+    let relative_difference = difference / candidate;
+    // A NaN value or tolerance compares as None and so matches nothing
+    if !matches!(
+        relative_difference.abs().partial_cmp(&tolerance.abs()),
+        Some(Ordering::Less | Ordering::Equal)
+    ) {
+        return None;
+    }
+
+    // u(x^n) / x^n = |n| u(x) / x
+    let uncertainty = constant.uncertainty.map_or(0.0, |u| {
+        (power.abs() as f64 * u / constant.value * candidate).abs()
+    });
+    // Powers of exact constants are only exact up to f64 rounding
+    let matches_exactly = relative_difference.abs() <= 4.0 * f64::EPSILON * power.abs() as f64;
+    let standard_uncertainties = match (matches_exactly, uncertainty == 0.0) {
+        (true, _) => 0.0,
+        (false, true) => f64::INFINITY,
+        (false, false) => difference.abs() / uncertainty,
+    };
+
+    Some(Identification {
+        constant,
+        power,
+        candidate,
+        uncertainty,
+        difference,
+        relative_difference,
+        standard_uncertainties,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identify(text: &str) -> Vec<(String, i32, f64)> {
+        let decimal = Decimal::parse(text).unwrap();
+        Dataset::codata()
+            .identify(decimal.to_f64(), last_digit_tolerance(decimal))
+            .iter()
+            .map(|hit| {
+                (
+                    hit.constant.name.to_string(),
+                    hit.power,
+                    hit.standard_uncertainties,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn default_tolerance_is_half_a_unit_in_the_last_digit() {
+        let tolerance = last_digit_tolerance(Decimal::parse("1.602e-19").unwrap());
+        assert!((tolerance - 0.0005 / 1.602).abs() < 1e-15);
+        assert_eq!(last_digit_tolerance(Decimal::parse("-2.5").unwrap()), 0.02);
+
+        // e = 1.602 176 634e-19 rounds to 1.602e-19, not to 1.603e-19
+        let hits = identify("1.602e-19");
+        assert!(hits
+            .iter()
+            .any(|(name, power, _)| name == "elementary charge" && *power == 1));
+        assert!(identify("1.603e-19").is_empty());
+
+        // One more digit, and G = 6.674 30(15)e-11 is the only entry left
+        let hits = identify("6.674e-11");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0, "Newtonian constant of gravitation");
+    }
+
+    #[test]
+    fn ranks_by_standard_uncertainties() {
+        let hits = identify("1.0e-3");
+
+        assert_eq!(hits[0].0, "molar mass constant");
+        assert!(hits.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+
+        // 1/α is listed, and also found as the reciprocal of α, a little further away
+        let hits = identify("137.036");
+        assert_eq!(hits[0].0, "inverse fine-structure constant");
+        assert_eq!(
+            (hits[1].0.as_str(), hits[1].1),
+            ("fine-structure constant", -1)
+        );
+        assert!(hits[0].2 < hits[1].2);
+    }
+
+    #[test]
+    fn exact_constants_rank_first_when_matched_and_last_otherwise() {
+        let dataset = Dataset::codata();
+
+        let hits = dataset.identify(1.602176634e-19, 1e-6);
+        assert_eq!(hits[0].standard_uncertainties, 0.0);
+        assert_eq!(hits[0].constant.name, "atomic unit of charge");
+
+        // Off the exact value by more than rounding: infinitely many uncertainties away,
+        // so the relative difference decides
+        let hits = dataset.identify(1.6022e-19, 1e-4);
+        assert!(hits
+            .iter()
+            .all(|hit| hit.standard_uncertainties.is_infinite()));
+        assert!(hits.windows(2).all(|pair| {
+            pair[0].relative_difference.abs() <= pair[1].relative_difference.abs()
+        }));
+        assert_eq!(hits[0].description(), "1/joule-electron volt relationship");
+        assert_eq!(hits[0].unit().as_deref(), Some("eV^-1"));
+    }
+}
@@ -0,0 +1,663 @@
+// Hand-written JSON export and import of constants and datasets.
+// Values are written with their published digits and uncertainties with Rust's shortest
+// round-trip formatting; both are read back with str::parse, so every f64 survives a
+// round trip bit for bit.
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::decimal::Decimal;
+use crate::units::Unit;
+use crate::{Constant, Dataset};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError {
+    pub message: String,
+    pub offset: usize,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl Constant {
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        write_constant(&mut json, self);
+        json
+    }
+
+    pub fn from_json(text: &str) -> Result<Constant, JsonError> {
+        let value = parse_document(text)?;
+        constant_from_value(&value)
+    }
+}
+
+impl Dataset {
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n  \"edition\": ");
+        write_string(&mut json, &self.edition);
+        json.push_str(",\n  \"constants\": [\n");
+
+        for (index, constant) in self.constants.iter().enumerate() {
+            json.push_str("    ");
+            write_constant(&mut json, constant);
+
+            if index + 1 < self.constants.len() {
+                json.push(',');
+            }
+            json.push('\n');
+        }
+
+        json.push_str("  ]\n}\n");
+        json
+    }
+
+    pub fn from_json(text: &str) -> Result<Dataset, JsonError> {
+        let value = parse_document(text)?;
+        let fields = value.as_object("dataset")?;
+
+        let edition = required(fields, "edition", value.offset)?.as_str("edition")?;
+        let constants = required(fields, "constants", value.offset)?
+            .as_array("constants")?
+            .iter()
+            .map(constant_from_value)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Dataset {
+            edition: Cow::Owned(edition.to_string()),
+            constants,
+            sources: Vec::new(),
+        })
+    }
+}
+
+fn write_constant(json: &mut String, constant: &Constant) {
+    json.push_str("{\"name\": ");
+    write_string(json, &constant.name);
+    json.push_str(", \"value\": ");
+
+    match constant.published_decimal() {
+        // A Decimal has no negative zero, so its sign is written separately
+        Some(_) if constant.value == 0.0 && constant.value.is_sign_negative() => {
+            json.push_str("-0e0")
+        }
+        Some(decimal) => json.push_str(&decimal.to_scientific_string()),
+        None => json.push_str("null"),
+    }
+
+    // Dimensionless is null; a unit nobody recorded leaves the key out
+    match &constant.unit {
+        Unit::Published(unit) => {
+            json.push_str(", \"unit\": ");
+            write_string(json, unit);
+        }
+        Unit::Dimensionless => json.push_str(", \"unit\": null"),
+        Unit::Unknown => {}
+    }
+
+    json.push_str(", \"uncertainty\": ");
+
+    match constant.uncertainty {
+        Some(uncertainty) => write_number(json, uncertainty),
+        None => json.push_str("null"),
+    }
+
+    json.push_str(", \"exact\": ");
+    json.push_str(if constant.is_exact() { "true" } else { "false" });
+    json.push('}');
+}
+
+// JSON has no NaN or infinity; those are written as null and rejected on import
+fn write_number(json: &mut String, number: f64) {
+    if number.is_finite() {
+        json.push_str(&format!("{:e}", number));
+    } else {
+        json.push_str("null");
+    }
+}
+
+fn write_string(json: &mut String, text: &str) {
+    json.push('"');
+
+    for character in text.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+}
+
+fn constant_from_value(value: &JsonValue) -> Result<Constant, JsonError> {
+    let fields = value.as_object("constant")?;
+
+    let name = required(fields, "name", value.offset)?.as_str("name")?;
+    let number = required(fields, "value", value.offset)?;
+    let decimal = number.as_decimal("value")?;
+    let unit = unit_from_fields(fields)?;
+    let uncertainty = optional(fields, "uncertainty")
+        .map(|uncertainty| uncertainty.as_number("uncertainty"))
+        .transpose()?;
+
+    if let Some(exact) = optional(fields, "exact") {
+        if exact.as_bool("exact")? != uncertainty.is_none() {
+            return Err(JsonError {
+                message: format!("\"exact\" disagrees with \"uncertainty\" for \"{}\"", name),
+                offset: exact.offset,
+            });
+        }
+    }
+
+    Ok(Constant {
+        name: Cow::Owned(name.to_string()),
+        value: number.as_number("value")?,
+        decimal,
+        unit,
+        uncertainty,
+    })
+}
+
+// Unlike the other optional fields, a missing unit and a null unit differ: null is
+// dimensionless, missing is unknown. "" is neither and is rejected.
+fn unit_from_fields(fields: &[(String, JsonValue)]) -> Result<Unit, JsonError> {
+    let Some((_, unit)) = fields.iter().find(|(name, _)| name == "unit") else {
+        return Ok(Unit::Unknown);
+    };
+
+    match &unit.kind {
+        JsonKind::Null => Ok(Unit::Dimensionless),
+        JsonKind::String(text) if text.trim().is_empty() => Err(JsonError {
+            message: "empty \"unit\"; use null for dimensionless constants".to_string(),
+            offset: unit.offset,
+        }),
+        _ => Ok(Unit::Published(Cow::Owned(
+            unit.as_str("unit")?.to_string(),
+        ))),
+    }
+}
+
+fn required<'a>(
+    fields: &'a [(String, JsonValue)],
+    key: &str,
+    offset: usize,
+) -> Result<&'a JsonValue, JsonError> {
+    fields
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value)
+        .ok_or_else(|| JsonError {
+            message: format!("missing field \"{}\"", key),
+            offset,
+        })
+}
+
+// A missing field and an explicit null both mean "absent"
+fn optional<'a>(fields: &'a [(String, JsonValue)], key: &str) -> Option<&'a JsonValue> {
+    fields
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value)
+        .filter(|value| value.kind != JsonKind::Null)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct JsonValue {
+    kind: JsonKind,
+    offset: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum JsonKind {
+    Null,
+    Bool(bool),
+    // Kept as written so the published digits can be recovered
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    fn expected(&self, what: &str, field: &str) -> JsonError {
+        JsonError {
+            message: format!("expected {} for \"{}\"", what, field),
+            offset: self.offset,
+        }
+    }
+
+    fn as_object(&self, field: &str) -> Result<&[(String, JsonValue)], JsonError> {
+        match &self.kind {
+            JsonKind::Object(fields) => Ok(fields),
+            _ => Err(self.expected("an object", field)),
+        }
+    }
+
+    fn as_array(&self, field: &str) -> Result<&[JsonValue], JsonError> {
+        match &self.kind {
+            JsonKind::Array(items) => Ok(items),
+            _ => Err(self.expected("an array", field)),
+        }
+    }
+
+    fn as_str(&self, field: &str) -> Result<&str, JsonError> {
+        match &self.kind {
+            JsonKind::String(text) => Ok(text),
+            _ => Err(self.expected("a string", field)),
+        }
+    }
+
+    fn as_number(&self, field: &str) -> Result<f64, JsonError> {
+        match &self.kind {
+            JsonKind::Number(number) => {
+                number.parse().map_err(|_| self.expected("a number", field))
+            }
+            _ => Err(self.expected("a number", field)),
+        }
+    }
+
+    fn as_decimal(&self, field: &str) -> Result<Decimal, JsonError> {
+        match &self.kind {
+            // More digits than an i64 holds: fall back to the digits of the f64
+            JsonKind::Number(number) => Decimal::parse(number).or_else(|_| {
+                let value = self.as_number(field)?;
+                Decimal::from_f64(value).ok_or_else(|| self.expected("a finite number", field))
+            }),
+            _ => Err(self.expected("a number", field)),
+        }
+    }
+
+    fn as_bool(&self, field: &str) -> Result<bool, JsonError> {
+        match self.kind {
+            JsonKind::Bool(value) => Ok(value),
+            _ => Err(self.expected("true or false", field)),
+        }
+    }
+}
+
+fn parse_document(text: &str) -> Result<JsonValue, JsonError> {
+    let mut parser = Parser {
+        bytes: text.as_bytes(),
+        text,
+        position: 0,
+    };
+    let value = parser.parse_value()?;
+
+    parser.skip_whitespace();
+    if parser.position < parser.bytes.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+
+    Ok(value)
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> JsonError {
+        JsonError {
+            message: message.to_string(),
+            offset: self.position,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", byte as char)))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, JsonError> {
+        self.skip_whitespace();
+        let offset = self.position;
+
+        let kind = match self.peek() {
+            Some(b'{') => self.parse_object()?,
+            Some(b'[') => self.parse_array()?,
+            Some(b'"') => JsonKind::String(self.parse_string()?),
+            Some(b't') => self.parse_literal("true", JsonKind::Bool(true))?,
+            Some(b'f') => self.parse_literal("false", JsonKind::Bool(false))?,
+            Some(b'n') => self.parse_literal("null", JsonKind::Null)?,
+            Some(b'-' | b'0'..=b'9') => JsonKind::Number(self.parse_number()?.to_string()),
+            Some(_) => return Err(self.error("unexpected character")),
+            None => return Err(self.error("unexpected end of input")),
+        };
+
+        Ok(JsonValue { kind, offset })
+    }
+
+    fn parse_literal(&mut self, literal: &str, kind: JsonKind) -> Result<JsonKind, JsonError> {
+        if self.text[self.position..].starts_with(literal) {
+            self.position += literal.len();
+            Ok(kind)
+        } else {
+            Err(self.error("invalid literal"))
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonKind, JsonError> {
+        self.position += 1;
+        let mut fields = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(JsonKind::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected a field name"));
+            }
+
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            let value = self.parse_value()?;
+            fields.push((key, value));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(JsonKind::Object(fields));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonKind, JsonError> {
+        self.position += 1;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(JsonKind::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value()?);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(JsonKind::Array(items));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.position += 1;
+        let mut text = String::new();
+
+        loop {
+            let start = self.position;
+            while let Some(byte) = self.peek() {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+                self.position += 1;
+            }
+            text.push_str(&self.text[start..self.position]);
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    text.push(self.parse_escape()?);
+                }
+                Some(_) => return Err(self.error("control character in string")),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char, JsonError> {
+        let escaped = self
+            .peek()
+            .ok_or_else(|| self.error("unterminated string"))?;
+        self.position += 1;
+
+        match escaped {
+            b'"' => Ok('"'),
+            b'\\' => Ok('\\'),
+            b'/' => Ok('/'),
+            b'b' => Ok('\u{8}'),
+            b'f' => Ok('\u{c}'),
+            b'n' => Ok('\n'),
+            b'r' => Ok('\r'),
+            b't' => Ok('\t'),
+            b'u' => {
+                let high = self.parse_hex4()?;
+
+                // Characters outside the basic plane arrive as a surrogate pair
+                if (0xd800..0xdc00).contains(&high) {
+                    if !self.text[self.position..].starts_with("\\u") {
+                        return Err(self.error("unpaired surrogate"));
+                    }
+                    self.position += 2;
+                    let low = self.parse_hex4()?;
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return Err(self.error("unpaired surrogate"));
+                    }
+                    let code = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                    return char::from_u32(code).ok_or_else(|| self.error("invalid escape"));
+                }
+
+                char::from_u32(high).ok_or_else(|| self.error("unpaired surrogate"))
+            }
+            _ => Err(self.error("invalid escape")),
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, JsonError> {
+        let digits = self
+            .text
+            .get(self.position..self.position + 4)
+            .ok_or_else(|| self.error("truncated \\u escape"))?;
+        // from_str_radix() would also take a sign, as in "\u+041"
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(self.error("invalid \\u escape"));
+        }
+        let code = u32::from_str_radix(digits, 16).map_err(|_| self.error("invalid \\u escape"))?;
+        self.position += 4;
+        Ok(code)
+    }
+
+    // Checks the JSON number grammar; conversion happens when the field is read
+    fn parse_number(&mut self) -> Result<&str, JsonError> {
+        let start = self.position;
+
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.error("invalid number")),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.skip_digits();
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.skip_digits();
+        }
+
+        Ok(&self.text[start..self.position])
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant(name: &str, value: f64, uncertainty: Option<f64>, unit: Unit) -> Constant {
+        Constant {
+            name: Cow::Owned(name.to_string()),
+            value,
+            decimal: Decimal::from_f64(value).unwrap(),
+            unit,
+            uncertainty,
+        }
+    }
+
+    fn assert_same(read: &Constant, written: &Constant) {
+        assert_eq!(read.name, written.name);
+        assert_eq!(
+            read.value.to_bits(),
+            written.value.to_bits(),
+            "{}",
+            written.name
+        );
+        assert_eq!(
+            read.uncertainty.map(f64::to_bits),
+            written.uncertainty.map(f64::to_bits),
+            "{}",
+            written.name
+        );
+        assert_eq!(read.unit, written.unit);
+    }
+
+    #[test]
+    fn table_round_trips_bit_for_bit() {
+        let dataset = Dataset::codata();
+        let read = Dataset::from_json(&dataset.to_json()).unwrap();
+
+        assert_eq!(read.edition, dataset.edition);
+        assert_eq!(read.constants.len(), dataset.constants.len());
+        for (read, written) in read.constants.iter().zip(&dataset.constants) {
+            assert_same(read, written);
+            assert_eq!(read.decimal, written.decimal, "{}", written.name);
+        }
+    }
+
+    #[test]
+    fn awkward_values_round_trip_bit_for_bit() {
+        let values = [
+            -0.0,
+            0.0,
+            0.1 + 0.2,
+            f64::MIN_POSITIVE,
+            5e-324,
+            f64::MAX,
+            -1.7976931348623157e308,
+            1.0 / 3.0,
+        ];
+
+        for value in values {
+            let written = constant("x", value, Some(value.abs() / 7.0), Unit::Dimensionless);
+            assert_same(&Constant::from_json(&written.to_json()).unwrap(), &written);
+        }
+    }
+
+    #[test]
+    fn names_and_units_are_escaped() {
+        let written = constant(
+            "a \"quoted\" \\ name\twith\ncontrols \u{1} and ü",
+            1.5,
+            None,
+            Unit::Published(Cow::Borrowed("kg m^2 s^-2")),
+        );
+        assert_same(&Constant::from_json(&written.to_json()).unwrap(), &written);
+
+        let unknown = constant("no unit", 2.0, Some(0.1), Unit::Unknown);
+        assert_same(&Constant::from_json(&unknown.to_json()).unwrap(), &unknown);
+    }
+
+    #[test]
+    fn unicode_escapes_need_four_hex_digits() {
+        let read = Constant::from_json(r#"{"name": "\u0041\u00fc", "value": 1, "unit": null}"#);
+        assert_eq!(read.unwrap().name, "Aü");
+
+        for escape in [r"\u+041", r"\u-041", r"\u 041", r"\u04g1", r"\u041"] {
+            let text = format!(r#"{{"name": "{}", "value": 1, "unit": null}}"#, escape);
+            let error = Constant::from_json(&text).unwrap_err();
+            assert!(
+                error.message.contains("\\u escape"),
+                "{}: {}",
+                escape,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn exact_must_agree_with_uncertainty() {
+        let error =
+            Constant::from_json(r#"{"name": "x", "value": 1, "uncertainty": 0.5, "exact": true}"#)
+                .unwrap_err();
+        assert!(error.message.contains("disagrees"), "{}", error);
+    }
+
+    #[test]
+    fn empty_unit_is_rejected() {
+        let error = Constant::from_json(r#"{"name": "x", "value": 1, "unit": " "}"#).unwrap_err();
+        assert!(error.message.contains("empty \"unit\""), "{}", error);
+    }
+
+    #[test]
+    fn extreme_exponents_do_not_panic() {
+        let constant =
+            Constant::from_json(r#"{"name": "x", "value": 1.5e-2147483648, "unit": null}"#)
+                .unwrap();
+        assert_eq!(constant.value, 0.0);
+    }
+}
@@ -0,0 +1,191 @@
+// LaTeX export for the siunitx package: single constants as \qty{6.6446573450(21)e-27}{\kilogram}
+// (or \SI for siunitx 2) and whole datasets as a .sty file with one \newcommand per constant.
+use crate::concise::{ConciseFormat, ExponentStyle, Grouping, UnitPlacement};
+use crate::unit_format::UnitStyle;
+use crate::{Constant, Dataset};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SiunitxCommand {
+    // \SI{…}{…} from siunitx 2, still accepted by version 3
+    Si,
+    // \qty{…}{…} from siunitx 3
+    #[default]
+    Qty,
+}
+
+impl SiunitxCommand {
+    pub fn name(self) -> &'static str {
+        match self {
+            SiunitxCommand::Si => "SI",
+            SiunitxCommand::Qty => "qty",
+        }
+    }
+}
+
+// Unit symbols of the table and their siunitx macros, prefixes included
+const UNIT_MACROS: [(&str, &str); 30] = [
+    ("A", r"\ampere"),
+    ("C", r"\coulomb"),
+    ("E_h", r"\hartree"),
+    ("F", r"\farad"),
+    ("GeV", r"\giga\electronvolt"),
+    ("H", r"\henry"),
+    ("Hz", r"\hertz"),
+    ("J", r"\joule"),
+    ("K", r"\kelvin"),
+    ("MHz", r"\mega\hertz"),
+    ("MeV", r"\mega\electronvolt"),
+    ("N", r"\newton"),
+    ("Pa", r"\pascal"),
+    ("S", r"\siemens"),
+    ("T", r"\tesla"),
+    ("V", r"\volt"),
+    ("W", r"\watt"),
+    ("Wb", r"\weber"),
+    ("cd", r"\candela"),
+    ("eV", r"\electronvolt"),
+    ("fm", r"\femto\metre"),
+    ("g", r"\gram"),
+    ("kg", r"\kilogram"),
+    ("lm", r"\lumen"),
+    ("m", r"\metre"),
+    ("mol", r"\mole"),
+    ("ohm", r"\ohm"),
+    ("s", r"\second"),
+    ("sr", r"\steradian"),
+    ("u", r"\atomicmassunit"),
+];
+
+// Translates space-separated units such as "W m^-2 K^-4" into
+// \watt\per\metre\squared\per\kelvin\tothe{4}; None if a symbol has no macro
+pub fn siunitx_unit(unit: &str) -> Option<String> {
+    let mut text = String::new();
+
+    for token in unit.split_whitespace() {
+        let (symbol, power) = match token.split_once('^') {
+            Some((symbol, power)) => (symbol, power.parse::<i32>().ok()?),
+            None => (token, 1),
+        };
+        let (_, name) = UNIT_MACROS.iter().find(|(known, _)| *known == symbol)?;
+
+        if power < 0 {
+            text.push_str(r"\per");
+        }
+        text.push_str(name);
+        match power.abs() {
+            1 => {}
+            2 => text.push_str(r"\squared"),
+            3 => text.push_str(r"\cubed"),
+            power => text.push_str(&format!(r"\tothe{{{}}}", power)),
+        }
+    }
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+// Units without macros, like "(GeV/c^2)^-2", go in literally with braced exponents
+fn unit_argument(unit: &str) -> String {
+    if let Some(text) = siunitx_unit(unit) {
+        return text;
+    }
+
+    let mut text = String::new();
+    let mut characters = unit.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        text.push(character);
+        if character == '^' {
+            text.push('{');
+            while let Some(next) = characters.next_if(|c| *c == '-' || c.is_ascii_digit()) {
+                text.push(next);
+            }
+            text.push('}');
+        }
+    }
+
+    text
+}
+
+impl Constant {
+    // \qty{6.6446573450(21)e-27}{\kilogram}, or \num{…} for dimensionless constants
+    pub fn to_siunitx(&self, command: SiunitxCommand) -> String {
+        let number = self.format_concise(&ConciseFormat {
+            exponent: ExponentStyle::Scientific,
+            grouping: Grouping::None,
+            unit: UnitPlacement::Omit,
+            units: UnitStyle::Ascii,
+        });
+
+        match self.unit.as_str() {
+            Some(unit) => format!(
+                r"\{}{{{}}}{{{}}}",
+                command.name(),
+                number,
+                unit_argument(unit)
+            ),
+            None => format!(r"\num{{{}}}", number),
+        }
+    }
+
+    // A macro name made of letters only, as TeX requires: "Planck constant" becomes
+    // \constPlanckConstant and the digits of "Cs-133" are spelled out
+    pub fn latex_macro_name(&self) -> String {
+        let mut name = String::from("const");
+
+        for word in self.name.split(|c: char| !c.is_ascii_alphanumeric()) {
+            let mut characters = word.chars();
+            if let Some(first) = characters.next() {
+                name.push_str(
+                    &spell(first).unwrap_or_else(|| first.to_ascii_uppercase().to_string()),
+                );
+            }
+            for character in characters {
+                match spell(character) {
+                    Some(digit) => name.push_str(&digit),
+                    None => name.push(character),
+                }
+            }
+        }
+
+        name
+    }
+}
+
+fn spell(character: char) -> Option<String> {
+    const DIGITS: [&str; 10] = [
+        "Zero", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine",
+    ];
+
+    let digit = character.to_digit(10)?;
+    Some(DIGITS[digit as usize].to_string())
+}
+
+impl Dataset {
+    // A LaTeX package defining one macro per constant, e.g.
+    // \newcommand{\constPlanckConstant}{\qty{6.62607015e-34}{\joule\per\hertz}}
+    pub fn to_sty(&self, command: SiunitxCommand) -> String {
+        let mut sty = format!(
+            "% {} physical constants as siunitx quantities\n\
+             % Source: https://pml.nist.gov/cuu/Constants/Table/allascii.txt\n\
+             \\NeedsTeXFormat{{LaTeX2e}}\n\
+             \\ProvidesPackage{{universalconstants}}[{} physical constants]\n\
+             \\RequirePackage{{siunitx}}\n",
+            self.edition, self.edition
+        );
+
+        for constant in &self.constants {
+            sty.push_str(&format!(
+                "\n% {}\n\\newcommand{{\\{}}}{{{}}}\n",
+                constant.name,
+                constant.latex_macro_name(),
+                constant.to_siunitx(command)
+            ));
+        }
+
+        sty
+    }
+}
@@ -0,0 +1,3081 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use decimal::Decimal;
+use merge::Source;
+use unit_format::{format_unit, UnitStyle};
+use units::Unit;
+
+pub mod allascii;
+pub mod budget;
+pub mod calc;
+pub mod concise;
+pub mod concise_parse;
+pub mod consistency;
+pub mod conversion;
+pub mod csv;
+pub mod decimal;
+pub mod exact;
+pub mod identify;
+pub mod json;
+pub mod latex;
+pub mod merge;
+pub mod metadata;
+pub mod natural_units;
+pub mod planck;
+pub mod precision;
+pub mod provider;
+pub mod rational;
+pub mod registry;
+pub mod sensitivity;
+pub mod unit_format;
+pub mod units;
+pub mod validation;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constant {
+    pub name: Cow<'static, str>,
+    pub value: f64,
+    pub decimal: Decimal,
+    pub unit: Unit,
+    pub uncertainty: Option<f64>,
+}
+
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_block(f, UnitStyle::Ascii)
+    }
+}
+
+impl Constant {
+    // The Display text, shared with display_with() for other unit styles
+    fn write_block(&self, f: &mut fmt::Formatter, units: UnitStyle) -> fmt::Result {
+        writeln!(f, "Constant: {}\n", self.name)?;
+
+        writeln!(f, "Value: {}\n", self.value)?;
+
+        match self.formatted_unit(units) {
+            Some(unit) => writeln!(f, "Unit: {}\n", unit)?,
+            None if self.unit.is_dimensionless() => writeln!(f, "Unit: Dimensionless\n")?,
+            None => writeln!(f, "Unit: Unknown\n")?,
+        }
+
+        // Worth a line only when it differs from the unit, as for "MeV" or "J T^-1"
+        if let (Some(unit), Some(si_unit)) = (self.unit.as_str(), self.si_unit()) {
+            let si_unit = si_unit.to_string();
+            if si_unit != unit {
+                writeln!(f, "SI base units: {}\n", format_unit(&si_unit, units))?;
+            }
+        }
+
+        if let Some(uncertainty) = self.uncertainty {
+            writeln!(f, "Uncertainty: {}\n", uncertainty)?;
+        } else {
+            writeln!(f, "Uncertainty: Exact\n")?; //write Exact
+        }
+
+        Ok(())
+    }
+}
+
+// Edition of the CODATA adjustment the NIST table below comes from
+pub const CODATA_EDITION: &str = "CODATA 2022";
+
+// A set of constants together with the edition they were published in
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dataset {
+    pub edition: Cow<'static, str>,
+    pub constants: Vec<Constant>,
+    // Where entries that are not part of the dataset as created came from, by name;
+    // see Dataset::source()
+    pub sources: Vec<(String, Source)>,
+}
+
+impl Dataset {
+    // The built-in NIST table
+    pub fn codata() -> Self {
+        Dataset {
+            edition: Cow::Borrowed(CODATA_EDITION),
+            constants: initialize_constants(),
+            sources: Vec::new(),
+        }
+    }
+
+    // Also finds an entry by a name listed in RENAMED
+    pub fn get(&self, name: &str) -> Option<&Constant> {
+        let current = current_name(name);
+        self.constants
+            .iter()
+            .find(|constant| constant.name == name)
+            .or_else(|| {
+                self.constants
+                    .iter()
+                    .find(|constant| constant.name == current)
+            })
+    }
+
+    // Constants whose name contains `text`, ignoring case
+    pub fn search(&self, text: &str) -> Vec<&Constant> {
+        let text = text.to_lowercase();
+        self.constants
+            .iter()
+            .filter(|constant| constant.name.to_lowercase().contains(&text))
+            .collect()
+    }
+}
+
+impl Constant {
+    // Exact constants carry no uncertainty
+    pub fn is_exact(&self) -> bool {
+        self.uncertainty.is_none()
+    }
+
+    // The published digits, or the shortest digits of `value` if the two disagree
+    // (for example after `value` was edited by hand); None if `value` is not finite
+    pub fn published_decimal(&self) -> Option<Decimal> {
+        if self.decimal.to_f64().to_bits() == self.value.to_bits() {
+            Some(self.decimal)
+        } else {
+            Decimal::from_f64(self.value)
+        }
+    }
+
+    // The published digits in scientific notation, or "inf" or "NaN"
+    pub fn value_text(&self) -> String {
+        self.published_decimal()
+            .map_or_else(|| self.value.to_string(), Decimal::to_scientific_string)
+    }
+}
+
+// Looks up a constant by its name exactly as listed by NIST, or by a name listed in RENAMED
+pub fn find_constant(name: &str) -> Option<Constant> {
+    let name = current_name(name);
+    initialize_constants()
+        .into_iter()
+        .find(|constant| constant.name == name)
+}
+
+// Names earlier versions of the table misspelled, with NIST's spelling; lookups by the old
+// name keep working
+#[rustfmt::skip]
+pub const RENAMED: &[(&str, &str)] = &[
+    ("alpha particle rms charge raduis", "alpha particle rms charge radius"),
+    ("atomic unit electrical field gradient", "atomic unit of electric field gradient"),
+    ("atomic unit of electic dipole mom.", "atomic unit of electric dipole mom."),
+    ("atomic unit of permittivitty", "atomic unit of permittivity"),
+    ("Boltzmann constant in meter per kelvin", "Boltzmann constant in inverse meter per kelvin"),
+    ("characteristic of impedance of vacuum", "characteristic impedance of vacuum"),
+    ("convention value of farad-90", "conventional value of farad-90"),
+    ("convention value of von Klitzing constant", "conventional value of von Klitzing constant"),
+    ("convention value of watt-90", "conventional value of watt-90"),
+    ("deutron g factor", "deuteron g factor"),
+    ("electron-proton mag. mom ratio", "electron-proton mag. mom. ratio"),
+    ("hartree inverse meter relationship", "hartree-inverse meter relationship"),
+    ("helion to mass energy equivalent", "helion mass energy equivalent"),
+    ("inverse meter-kelvin relatinship", "inverse meter-kelvin relationship"),
+    ("molar Plank constant", "molar Planck constant"),
+    ("molar volume ideal gas (273.15 K, 100 kPa)", "molar volume of ideal gas (273.15 K, 100 kPa)"),
+    ("molar volume of ideal gas (273.13 K, 101.325 kPa)", "molar volume of ideal gas (273.15 K, 101.325 kPa)"),
+    ("muon mag. mom. anomly", "muon mag. mom. anomaly"),
+    ("natural unit momentum in MeV/c", "natural unit of momentum in MeV/c"),
+    ("quantum of circluation times 2", "quantum of circulation times 2"),
+    ("Sackur-Tetrode constant (1 K, 100kPa)", "Sackur-Tetrode constant (1 K, 100 kPa)"),
+    ("shielded helion mag. mom to Bohr magneton ratio", "shielded helion mag. mom. to Bohr magneton ratio"),
+    ("shielding difference to d and p in HD", "shielding difference of d and p in HD"),
+    ("Thomas cross section", "Thomson cross section"),
+    ("vacuum electricy permittivity", "vacuum electric permittivity"),
+];
+
+// NIST's spelling of a name listed in RENAMED, or the name itself
+pub fn current_name(name: &str) -> &str {
+    RENAMED
+        .iter()
+        .find(|(old, _)| *old == name)
+        .map_or(name, |(_, current)| *current)
+}
+
+// START OF ALPHABETICAL DATA FROM NIST https://pml.nist.gov/cuu/Constants/Table/allascii.txt
+#[rustfmt::skip]
+pub fn initialize_constants() -> Vec<Constant> { 
+vec![
+    Constant {
+        name: Cow::Borrowed("alpha particle-electron mass ratio"),
+        value: 7_294.299_541_71,
+        decimal: Decimal::new(729429954171, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_17),
+    },   
+
+    Constant {
+        name: Cow::Borrowed("alpha particle mass"),
+        value: 6.644_657_345_0e-27,
+        decimal: Decimal::new(66446573450, -37),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_002_1e-27),
+    },   
+
+    Constant {
+        name: Cow::Borrowed("alpha particle mass energy equivalent"),
+        value: 5.971_920_199_7e-10,
+        decimal: Decimal::new(59719201997, -20),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_001_9e-10),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("alpha particle mass energy equivalent in MeV"),
+        value: 3_727.379_411_8,
+        decimal: Decimal::new(37273794118, -7),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_001_2),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("alpha particle mass in u"),
+        value: 4.001_506_179_129,
+        decimal: Decimal::new(4001506179129, -12),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_062),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("alpha particle molar mass"),
+        value: 4.001_506_183_3e-3,
+        decimal: Decimal::new(40015061833, -13),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_001_2e-3),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("alpha particle-proton mass ratio"),
+        value: 3.972_599_690_252,
+        decimal: Decimal::new(3972599690252, -12),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_07),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("alpha particle relative atomic mass"),
+        value: 4.001_506_179_129,
+        decimal: Decimal::new(4001506179129, -12),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_062),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("alpha particle rms charge radius"),
+        value: 1.678_5e-15,
+        decimal: Decimal::new(16785, -19),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.002_1e-15),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("Angstrom star"), // A is capitalized on nist
+        value: 1.000_014_95e-10,
+        decimal: Decimal::new(100001495, -18),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_9e-10),
+    },  
+
+    Constant {
+        name: Cow::Borrowed("atomic mass constant"),
+        value: 1.660_539_068_92e-27,
+        decimal: Decimal::new(166053906892, -38),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_000_52e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass constant energy equivalent"),
+        value: 1.492_418_087_68e-10,
+        decimal: Decimal::new(149241808768, -21),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_46e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass constant energy equivalent in MeV"),
+        value: 931.494_103_72,
+        decimal: Decimal::new(93149410372, -8),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_29),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass unit-electron volt relationship"),
+        value: 9.314_941_037_2e8,
+        decimal: Decimal::new(93149410372, -2),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: Some(0.000_000_002_9e8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass unit-hartree relationship"),
+        value: 3.423_177_692_2e7,
+        decimal: Decimal::new(34231776922, -3),
+        unit: Unit::Published(Cow::Borrowed("E_h")),
+        uncertainty: Some(0.000_000_001_1e7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass unit-hertz relationship"),
+        value: 2.252_342_721_85e23,
+        decimal: Decimal::new(225234272185, 12),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: Some(0.000_000_000_7e23),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass unit-inverse meter relationship"),
+        value: 7.513_006_620_9e14,
+        decimal: Decimal::new(75130066209, 4),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: Some(0.000_000_002_3e14),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass unit-joule relationship"),
+        value: 1.492_418_087_68e-10,
+        decimal: Decimal::new(149241808768, -21),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_46e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass unit-kelvin relationship"),
+        value: 1.080_954_020_67e13,
+        decimal: Decimal::new(108095402067, 2),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: Some(0.000_000_000_34e13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic mass unit-kilogram relationship"),
+        value: 1.660_539_068_92e-27,
+        decimal: Decimal::new(166053906892, -38),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_000_52e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of 1st hyperpolarizability"),
+        value: 3.206_361_299_6e-53,
+        decimal: Decimal::new(32063612996, -63),
+        unit: Unit::Published(Cow::Borrowed("C^3 m^3 J^-2")),
+        uncertainty: Some(0.000_000_001_5e-53),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of 2nd hyperpolarizability"),
+        value: 6.235_379_973_5e-65,
+        decimal: Decimal::new(62353799735, -75),
+        unit: Unit::Published(Cow::Borrowed("C^4 m^4 J^-3")),
+        uncertainty: Some(0.000_000_003_9e-65),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of action"),
+        value: 1.054_571_817e-34,//...
+        decimal: Decimal::new(1054571817, -43),
+        unit: Unit::Published(Cow::Borrowed("J s")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of charge"),
+        value: 1.602_176_634e-19,
+        decimal: Decimal::new(1602176634, -28),
+        unit: Unit::Published(Cow::Borrowed("C")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of charge density"),
+        value: 1.081_202_386_77e12,
+        decimal: Decimal::new(108120238677, 1),
+        unit: Unit::Published(Cow::Borrowed("C m^-3")),
+        uncertainty: Some(0.000_000_000_51e12),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of current"),
+        value: 6.623_618_237_508_2e-3,
+        decimal: Decimal::new(66236182375082, -16),
+        unit: Unit::Published(Cow::Borrowed("A")),
+        uncertainty: Some(0.000_000_000_007_2e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of electric dipole mom."), //mom. is short for moment : to be decided if should use moment or follow nist mom.
+        value: 8.478_353_619_8e-30,
+        decimal: Decimal::new(84783536198, -40),
+        unit: Unit::Published(Cow::Borrowed("C m")),
+        uncertainty: Some(0.000_000_001_3e-30),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of electric field"),
+        value: 5.142_206_751_12e11,
+        decimal: Decimal::new(514220675112, 0),
+        unit: Unit::Published(Cow::Borrowed("V m^-1")),
+        uncertainty: Some(0.000_000_000_80e11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of electric field gradient"),
+        value: 9.717_362_442_4e21,
+        decimal: Decimal::new(97173624424, 11),
+        unit: Unit::Published(Cow::Borrowed("V m^-2")),
+        uncertainty: Some(0.000_000_003e21),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of electric polarizability"),
+        value: 1.648_777_272_12e-41,
+        decimal: Decimal::new(164877727212, -52),
+        unit: Unit::Published(Cow::Borrowed("C^2 m^2 J^-1")),
+        uncertainty: Some(0.000_000_000_51e-41),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of electric potential"),
+        value: 27.211_386_245_981,
+        decimal: Decimal::new(27211386245981, -12),
+        unit: Unit::Published(Cow::Borrowed("V")),
+        uncertainty: Some(0.000_000_000_03),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of electric quadrupole mom."), //
+        value: 4.486_551_518_5e-40,
+        decimal: Decimal::new(44865515185, -50),
+        unit: Unit::Published(Cow::Borrowed("C m^2")),
+        uncertainty: Some(0.000_000_001_4e-40),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of energy"),
+        value: 4.359_744_722_206e-18,
+        decimal: Decimal::new(4359744722206, -30),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_004_8e-18),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of force"),
+        value: 8.238_723_503_8e-8,
+        decimal: Decimal::new(82387235038, -18),
+        unit: Unit::Published(Cow::Borrowed("N")),
+        uncertainty: Some(0.000_000_001_3e-8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of length"),
+        value: 5.291_722_105_44e-11,
+        decimal: Decimal::new(529172210544, -22),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_000_82e-11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of mag. dipole mom."),
+        value: 1.854_802_013_15e-23,
+        decimal: Decimal::new(185480201315, -34),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_000_58e-23),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of mag. flux density"),
+        value: 2.350_517_570_77e5,
+        decimal: Decimal::new(235051757077, -6),
+        unit: Unit::Published(Cow::Borrowed("T")),
+        uncertainty: Some(0.000_000_000_73e5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of magnetizability"),
+        value: 2.350_517_570_77_e5,
+        decimal: Decimal::new(235051757077, -6),
+        unit: Unit::Published(Cow::Borrowed("T")),
+        uncertainty: Some(0.000_000_000_73e5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of mass"),
+        value: 9.109_383_713_9e-31,
+        decimal: Decimal::new(91093837139, -41),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_002_8e-31),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of momentum"),
+        value: 1.992_851_915_45e-24,
+        decimal: Decimal::new(199285191545, -35),
+        unit: Unit::Published(Cow::Borrowed("kg m s^-1")),
+        uncertainty: Some(0.000_000_000_31e-24),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of permittivity"),
+        value: 1.112_650_056_20e-10,
+        decimal: Decimal::new(111265005620, -21),
+        unit: Unit::Published(Cow::Borrowed("F m^-1")),
+        uncertainty: Some(0.000_000_000_17e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of time"),
+        value: 2.418_884_326_586_4e-17,
+        decimal: Decimal::new(24188843265864, -30),
+        unit: Unit::Published(Cow::Borrowed("s")),
+        uncertainty: Some(0.000_000_000_002_6e-17),
+    },
+
+    Constant {
+        name: Cow::Borrowed("atomic unit of velocity"),
+        value: 2.187_691_262_16e6,
+        decimal: Decimal::new(218769126216, -5),
+        unit: Unit::Published(Cow::Borrowed("m s^-1")),
+        uncertainty: Some(0.000_000_000_34e6),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Avogadro constant"), //
+        value: 6.022_140_76e23,
+        decimal: Decimal::new(602214076, 15),
+        unit: Unit::Published(Cow::Borrowed("mol^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Bohr magneton"),
+        value: 9.274_010_065_7e-24,
+        decimal: Decimal::new(92740100657, -34),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_002_9e-24),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Bohr magneton in eV/T"),
+        value: 5.788_381_798_2e-5,
+        decimal: Decimal::new(57883817982, -15),
+        unit: Unit::Published(Cow::Borrowed("eV T^-1")),
+        uncertainty: Some(0.000_000_001_8e-5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Bohr magneton in Hz/T"),
+        value: 1.399_624_491_71e10,
+        decimal: Decimal::new(139962449171, -1),
+        unit: Unit::Published(Cow::Borrowed("Hz T^-1")),
+        uncertainty: Some(0.000_000_000_44e10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Bohr magneton in inverse meter per tesla"),
+        value: 46.686_447_719,
+        decimal: Decimal::new(46686447719, -9),
+        unit: Unit::Published(Cow::Borrowed("m^-1 T^-1")),
+        uncertainty: Some(0.000_000_015),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Bohr magneton in K/T"),
+        value: 0.671_713_814_72,
+        decimal: Decimal::new(67171381472, -11),
+        unit: Unit::Published(Cow::Borrowed("K T^-1")),
+        uncertainty: Some(0.000_000_000_21),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Bohr radius"),
+        value: 5.291_772_105_44e-11,
+        decimal: Decimal::new(529177210544, -22),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_000_82e-11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Boltzmann constant"),
+        value: 1.380_649e-23,
+        decimal: Decimal::new(1380649, -29),
+        unit: Unit::Published(Cow::Borrowed("J K^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Boltzmann constant in eV/K"),
+        value: 8.617_333_262e-5,//...
+        decimal: Decimal::new(8617333262, -14),
+        unit: Unit::Published(Cow::Borrowed("eV K^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Boltzmann constant in Hz/K"),
+        value: 2.083_661_912e10,//...
+        decimal: Decimal::new(2083661912, 1),
+        unit: Unit::Published(Cow::Borrowed("Hz K^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Boltzmann constant in inverse meter per kelvin"),
+        value: 69.503_480_04,//...
+        decimal: Decimal::new(6950348004, -8),
+        unit: Unit::Published(Cow::Borrowed("m^-1 K^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("characteristic impedance of vacuum"),
+        value: 376.730_313_412,
+        decimal: Decimal::new(376730313412, -9),
+        unit: Unit::Published(Cow::Borrowed("ohm")),
+        uncertainty: Some(0.000_000_059),
+    },
+
+    Constant {
+        name: Cow::Borrowed("classical electron radius"),
+        value: 2.817_940_320_5e-15,
+        decimal: Decimal::new(28179403205, -25),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_001_3e-15),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Compton wavelength"),
+        value: 2.426_310_235_38e-12,
+        decimal: Decimal::new(242631023538, -23),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_000_76e-12),
+    },
+
+    Constant {
+        name: Cow::Borrowed("conductance quantum"),
+        value: 7.748_091_729e-5,//...
+        decimal: Decimal::new(7748091729, -14),
+        unit: Unit::Published(Cow::Borrowed("S")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of ampere-90"),
+        value: 1.000_000_088_87,//...
+        decimal: Decimal::new(100000008887, -11),
+        unit: Unit::Published(Cow::Borrowed("A")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of coulomb-90"),
+        value: 1.000_000_088_87,//...
+        decimal: Decimal::new(100000008887, -11),
+        unit: Unit::Published(Cow::Borrowed("C")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of farad-90"),
+        value: 0.999_999_982_20,//...
+        decimal: Decimal::new(99999998220, -11),
+        unit: Unit::Published(Cow::Borrowed("F")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of henry-90"),
+        value: 1.000_000_017_79,//...
+        decimal: Decimal::new(100000001779, -11),
+        unit: Unit::Published(Cow::Borrowed("H")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of Josephson constant"),
+        value: 483_597.9e9,
+        decimal: Decimal::new(4835979, 8),
+        unit: Unit::Published(Cow::Borrowed("Hz V^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of ohm-90"),
+        value: 1.000_000_017_79,//...
+        decimal: Decimal::new(100000001779, -11),
+        unit: Unit::Published(Cow::Borrowed("ohm")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of volt-90"),
+        value: 1.000_000_106_66,//...
+        decimal: Decimal::new(100000010666, -11),
+        unit: Unit::Published(Cow::Borrowed("V")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of von Klitzing constant"),
+        value: 25_812.807,
+        decimal: Decimal::new(25812807, -3),
+        unit: Unit::Published(Cow::Borrowed("ohm")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("conventional value of watt-90"),
+        value: 1.000_000_195_53,//...
+        decimal: Decimal::new(100000019553, -11),
+        unit: Unit::Published(Cow::Borrowed("W")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Copper x unit"),
+        value: 1.002_076_97e-13,
+        decimal: Decimal::new(100207697, -21),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_28e-13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron-electron mag. mom. ratio"),
+        value: -4.664_345_550e-4,
+        decimal: Decimal::new(-4664345550, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_012e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron-electron mass ratio"),
+        value: 3_670.482_967_655,
+        decimal: Decimal::new(3670482967655, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_063),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron g factor"),
+        value: 0.857_438_233_5,
+        decimal: Decimal::new(8574382335, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_002_2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron mag. mom."),
+        value: 4.330_735_087e-27,
+        decimal: Decimal::new(4330735087, -36),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_011e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron mag. mom. to Bohr magneton ratio"),
+        value: 4.669_754_568e-4,
+        decimal: Decimal::new(4669754568, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_012e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron mag. mom. to nuclear magneton ratio"),
+        value: 0.857_438_233_5,
+        decimal: Decimal::new(8574382335, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_002_2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron mass"),
+        value: 3.343_583_776_8e-27,
+        decimal: Decimal::new(33435837768, -37),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_001e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron mass energy equivalent"),
+        value: 3.005_063_234_91e-10,
+        decimal: Decimal::new(300506323491, -21),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_94e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron mass energy equivalent in MeV"),
+        value: 1_875.612_945,
+        decimal: Decimal::new(1875612945, -6),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_58),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron mass in u"),
+        value: 2.013_553_212_544,
+        decimal: Decimal::new(2013553212544, -12),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_015),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron molar mass"),
+        value: 2.013_553_214_66e-3,
+        decimal: Decimal::new(201355321466, -14),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_000_63e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron-neutron mag. mom. ratio"),
+        value: -0.448_206_52,
+        decimal: Decimal::new(-44820652, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron-proton mag. mom. ratio"),
+        value: 0.307_012_209_3,
+        decimal: Decimal::new(3070122093, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_79),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron-proton mass ratio"),
+        value: 1.999_007_501_269_9,
+        decimal: Decimal::new(19990075012699, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_008_4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron relative atomic mass"),
+        value: 2.015_533_212_544,
+        decimal: Decimal::new(2015533212544, -12),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_015),
+    },
+
+    Constant {
+        name: Cow::Borrowed("deuteron rms charge radius"),
+        value: 2.127_78e-15,
+        decimal: Decimal::new(212778, -20),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_27e-15),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron charge to mass quotient"),
+        value: -1.758_820_008_38e11,
+        decimal: Decimal::new(-175882000838, 0),
+        unit: Unit::Published(Cow::Borrowed("C kg^-1")),
+        uncertainty: Some(0.000_000_000_55e11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-deuteron mag. mom. ratio"),
+        value: -2_143.923_492_1,
+        decimal: Decimal::new(-21439234921, -7),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_005_6),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-deuteron mass ratio"),
+        value: 2.724_437_107_629e-4,
+        decimal: Decimal::new(2724437107629, -16),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_047e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron g factor"),
+        value: -2.002_319_304_360_92,
+        decimal: Decimal::new(-200231930436092, -14),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_000_36),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron gyromag. ratio"),
+        value: 1.760_859_627_84e11,
+        decimal: Decimal::new(176085962784, 0),
+        unit: Unit::Published(Cow::Borrowed("s^-1 T^-1")),
+        uncertainty: Some(0.000_000_000_55e11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron gyromag. ratio in MHz/T"),
+        value: 28_024.951_386_1,
+        decimal: Decimal::new(280249513861, -7),
+        unit: Unit::Published(Cow::Borrowed("MHz T^-1")),
+        uncertainty: Some(0.000_008_7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-helion mass ratio"),
+        value: 1.819_543_074_649e-4,
+        decimal: Decimal::new(1819543074649, -16),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_053e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron mag. mom."),
+        value: -9.284_764_691_7e-24,
+        decimal: Decimal::new(-92847646917, -34),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_002_9e-24),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron mag. mom. anomaly"),
+        value: 1.159_652_180_46e-3,
+        decimal: Decimal::new(115965218046, -14),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_18e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron mag. mom. to Bohr magneton ratio"),
+        value: -1.001_159_662_180_46,
+        decimal: Decimal::new(-100115966218046, -14),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_000_18),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron mag. mom. to nuclear magneton ratio"),
+        value: -1_838.281_971_877,
+        decimal: Decimal::new(-1838281971877, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_032),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron mass"),
+        value: 9.109_383_713_9e-31,
+        decimal: Decimal::new(91093837139, -41),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_002_8e-31),
+    }, 
+
+    Constant {
+        name: Cow::Borrowed("electron mass energy equivalent"),
+        value: 8.187_105_788e-14,
+        decimal: Decimal::new(8187105788, -23),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_002_6e-14),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron mass energy equivalent in MeV"),
+        value: 0.510_988_950_69,
+        decimal: Decimal::new(51098895069, -11),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_000_16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron mass in u"),
+        value: 5.484_799_090_441e-4,
+        decimal: Decimal::new(5484799090441, -16),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_097e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron molar mass"),
+        value: 5.485_799_096_2e-7,
+        decimal: Decimal::new(54857990962, -17),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_001_7e-7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-muon mag. mom. ratio"),
+        value: 206.766_988_1,
+        decimal: Decimal::new(2067669881, -7),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_004_6),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-neutron mag. mom. ratio"),
+        value: 960.920_48,
+        decimal: Decimal::new(96092048, -5),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_23),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-neutron mass ratio"),
+        value: 5.438_673_441_6e-4,
+        decimal: Decimal::new(54386734416, -14),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_002_2e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-proton mag. mom. ratio"),
+        value: -658.210_687_89,
+        decimal: Decimal::new(-65821068789, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_19),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-proton mass ratio"),
+        value: 5.446_170_214_889e-4,
+        decimal: Decimal::new(5446170214889, -16),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_094e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron relative atomic mass"),
+        value: 5.486_799_090_411e-4,
+        decimal: Decimal::new(5486799090411, -16),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_097e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-tau mass ratio"),
+        value: 2.875_85e-4,
+        decimal: Decimal::new(287585, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_19e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron to alpha particle mass ratio"),
+        value: 1.370_933_554_733e-4,
+        decimal: Decimal::new(1370933554733, -16),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_032e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron to shielded helion mag. mom. ratio"),
+        value: 864.058_239_86,
+        decimal: Decimal::new(86405823986, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron to shielded proton mag. mom. ratio"),
+        value: -658.227_585_6,
+        decimal: Decimal::new(-6582275856, -7),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_002_7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron-triton mass ratio"),
+        value: 1.819_200_062_327e-4,
+        decimal: Decimal::new(1819200062327, -16),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_068e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt"),
+        value: 1.602_176_634e-19,
+        decimal: Decimal::new(1602176634, -28),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt-atomic mass unit relationship"),
+        value: 1.073_544_100_83e-9,
+        decimal: Decimal::new(107354410083, -20),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_33e-9),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt-hartree relationship"),
+        value: 3.674_932_217_566_5e-2,
+        decimal: Decimal::new(36749322175665, -15),
+        unit: Unit::Published(Cow::Borrowed("E_h")),
+        uncertainty: Some(0.000_000_000_004e-2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt-hertz relationship"),
+        value: 2.417_989_242e14,//...
+        decimal: Decimal::new(2417989242, 5),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt-inverse meter relationship"),
+        value: 8.065_543_937e5,//...
+        decimal: Decimal::new(8065543937, -4),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt-joule relationship"),
+        value: 1.602_176_634e-19,
+        decimal: Decimal::new(1602176634, -28),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt-kelvin relationship"),
+        value: 1.160_451_812e4,//...
+        decimal: Decimal::new(1160451812, -5),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("electron volt-kilogram relationship"),
+        value: 1.782_661_921e-36,//...
+        decimal: Decimal::new(1782661921, -45),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("elementary charge"),
+        value: 1.602_176_634e-19,
+        decimal: Decimal::new(1602176634, -28),
+        unit: Unit::Published(Cow::Borrowed("C")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("elementary charge over h-bar"),
+        value: 1.519_267_447e15,//...
+        decimal: Decimal::new(1519267447, 6),
+        unit: Unit::Published(Cow::Borrowed("A J^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Faraday constant"),
+        value: 96_485.332_12,//...
+        decimal: Decimal::new(9648533212, -5),
+        unit: Unit::Published(Cow::Borrowed("C mol^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Fermi coupling constant"),
+        value: 1.166_378_7e-5,
+        decimal: Decimal::new(11663787, -12),
+        unit: Unit::Published(Cow::Borrowed("GeV^-2")),
+        uncertainty: Some(0.000_000_6e-5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("fine-structure constant"),
+        value: 7.297_352_564_3e-3,
+        decimal: Decimal::new(72973525643, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_001_1e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("first radiation constant"),
+        value: 3.741_771_852e-16,//...
+        decimal: Decimal::new(3741771852, -25),
+        unit: Unit::Published(Cow::Borrowed("W m^2")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("first radiation constant for spectral radiance"),
+        value: 1.191_042_972e-16,//...
+        decimal: Decimal::new(1191042972, -25),
+        unit: Unit::Published(Cow::Borrowed("W m^2 sr^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("hartree-atomic mass unit relationship"),
+        value: 2.921_262_317_97e-8,
+        decimal: Decimal::new(292126231797, -19),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_91e-8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hartree-electron volt relationship"),
+        value: 27.211_386_245_981,
+        decimal: Decimal::new(27211386245981, -12),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: Some(0.000_000_000_03),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Hartree energy"),
+        value: 4.359_744_722_206e-18,
+        decimal: Decimal::new(4359744722206, -30),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_004_8e-18),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Hartree energy in eV"),
+        value: 27.211_386_245_981,
+        decimal: Decimal::new(27211386245981, -12),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: Some(0.000_000_000_03),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hartree-hertz relationship"),
+        value: 6.579_683_920_499_9e15,
+        decimal: Decimal::new(65796839204999, 2),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: Some(0.000_000_000_007_2e15),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hartree-inverse meter relationship"),
+        value: 2.194_746_313_631_4e7,
+        decimal: Decimal::new(21947463136314, -6),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: Some(0.000_000_000_002_4e7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hartree-joule relationship"),
+        value: 4.359_744_722_206e-18,
+        decimal: Decimal::new(4359744722206, -30),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_004_8e-18),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hartree-kelvin relationship"),
+        value: 3.157_750_248_039_8e5,
+        decimal: Decimal::new(31577502480398, -8),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: Some(0.000_000_000_003_4e5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hartree-kilogram relationship"),
+        value: 4.850_870_209_541_9e-35,
+        decimal: Decimal::new(48508702095419, -48),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_000_005_3e-35),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion-electron mass ratio"),
+        value: 5_495.885_279_84,
+        decimal: Decimal::new(549588527984, -8),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion g factor"),
+        value: -4.255_250_699_5,
+        decimal: Decimal::new(-42552506995, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_003_4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion mag. mom."),
+        value: -1.074_617_551_98e-26,
+        decimal: Decimal::new(-107461755198, -37),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_000_93e-26),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion mag. mom. to Bohr magneton ratio"),
+        value: -1.158_740_980_83e-3,
+        decimal: Decimal::new(-115874098083, -14),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_94e-3),
+    },
+    
+    Constant {
+        name: Cow::Borrowed("helion mag. mom. to nuclear magneton ratio"),
+        value: -2.127_625_349_8,
+        decimal: Decimal::new(-21276253498, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_001_7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion mass"),
+        value: 5.006_412_786_2e-27,
+        decimal: Decimal::new(50064127862, -37),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_001_6e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion mass energy equivalent"),
+        value: 4.499_539_418_5e-10,
+        decimal: Decimal::new(44995394185, -20),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_001_4e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion mass energy equivalent in MeV"),
+        value: 2_808.391_611_12,
+        decimal: Decimal::new(280839161112, -8),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_88),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion mass in u"),
+        value: 3.014_932_246_932,
+        decimal: Decimal::new(3014932246932, -12),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_074),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion molar mass"),
+        value: 3.014_932_250_1e-3,
+        decimal: Decimal::new(30149322501, -13),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_000_94e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion-proton mass ratio"),
+        value: 2.993_152_617_552,
+        decimal: Decimal::new(2993152617552, -12),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_07),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion relative atomic mass"),
+        value: 3.014_932_246_932,
+        decimal: Decimal::new(3014932246932, -12),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_074),
+    },
+
+    Constant {
+        name: Cow::Borrowed("helion shielding shift"),
+        value: 5.996_702_9e-5,
+        decimal: Decimal::new(59967029, -12),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_002_3e-5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hertz-atomic mass unit relationship"),
+        value: 4.439_821_659e-24,
+        decimal: Decimal::new(4439821659, -33),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_001_4e-24),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hertz-electron volt relationship"),
+        value: 4.135_667_696e-15,
+        decimal: Decimal::new(4135667696, -24),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("hertz-hartree relationship"),
+        value: 1.519_829_846_057_4e-16,
+        decimal: Decimal::new(15198298460574, -29),
+        unit: Unit::Published(Cow::Borrowed("E_h")),
+        uncertainty: Some(0.000_000_000_001_7e-16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("hertz-inverse meter relationship"),
+        value: 3.335_640_951e-9,
+        decimal: Decimal::new(3335640951, -18),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("hertz-joule relationship"),
+        value: 6.626_070_15e-34,
+        decimal: Decimal::new(662607015, -42),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("hertz-kelvin relationship"),
+        value: 4.799_243_073e-11,//...
+        decimal: Decimal::new(4799243073, -20),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("hertz-kilogram relationship"),
+        value: 7.372_497_323e-51,
+        decimal: Decimal::new(7372497323, -60),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("hyperfine transition frequency of Cs-133"),
+        value: 9_192_631_770.0,
+        decimal: Decimal::new(9192631770, 0),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse fine-structure constant"),
+        value: 137.035_999_177,
+        decimal: Decimal::new(137035999177, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_021),
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse meter-atomic mass unit relationship"),
+        value: 1.331_025_048_24e-15,
+        decimal: Decimal::new(133102504824, -26),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_41e-15),
+    },
+//... check check check
+    Constant {
+        name: Cow::Borrowed("inverse meter-electron volt relationship"),
+        value: 1.239_841_984e-6,//...
+        decimal: Decimal::new(1239841984, -15),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse meter-hartree relationship"),
+        value: 4.556_335_252_913_2e-8,//...
+        decimal: Decimal::new(45563352529132, -21),
+        unit: Unit::Published(Cow::Borrowed("E_h")),
+        uncertainty: Some(0.000_000_000_005e-8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse meter-hertz relationship"),
+        value: 299_792_458.0,
+        decimal: Decimal::new(299792458, 0),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty:   None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse meter-joule relationship"),
+        value: 1.986_445_857e-25,//...
+        decimal: Decimal::new(1986445857, -34),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse meter-kelvin relationship"),
+        value: 1.438_776_877e-2,//...
+        decimal: Decimal::new(1438776877, -11),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse meter-kilogram relationship"),
+        value: 2.210_219_094e-42,
+        decimal: Decimal::new(2210219094, -51),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("inverse of conductance quantum"),
+        value: 12_906.403_72,//...
+        decimal: Decimal::new(1290640372, -5),
+        unit: Unit::Published(Cow::Borrowed("ohm")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Josephson constant"),
+        value: 483_597.848_4e9,//...
+        decimal: Decimal::new(4835978484, 5),
+        unit: Unit::Published(Cow::Borrowed("Hz V^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("joule-atomic mass unit relationship"),
+        value: 6.700_535_247_1e9,
+        decimal: Decimal::new(67005352471, -1),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_002_1e9),
+    },
+
+    Constant {
+        name: Cow::Borrowed("joule-electron volt relationship"),
+        value: 6.241_509_074e18,//..
+        decimal: Decimal::new(6241509074, 9),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("joule-hartree relationship"),
+        value: 2.293_712_278_396_9e17,
+        decimal: Decimal::new(22937122783969, 4),
+        unit: Unit::Published(Cow::Borrowed("E_h")),
+        uncertainty: Some(0.000_000_000_002_5e17),
+    },
+
+    Constant {
+        name: Cow::Borrowed("joule-hertz relationship"),
+        value: 1.509_190_179e33,//..
+        decimal: Decimal::new(1509190179, 24),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("joule-inverse meter relationship"),
+        value: 5.034_116_567e24,//...
+        decimal: Decimal::new(5034116567, 15),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("joule-kelvin relationship"),
+        value: 7.242_970_516e22,//...
+        decimal: Decimal::new(7242970516, 13),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("joule-kilogram relationship"),
+        value: 1.112_650_056e-17,
+        decimal: Decimal::new(1112650056, -26),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kelvin-atomic mass unit relationship"),
+        value: 9.251_087_288_4e-14,
+        decimal: Decimal::new(92510872884, -24),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_002_9e-14),
+    },
+
+    Constant {
+        name: Cow::Borrowed("kelvin-electron volt relationship"),
+        value: 8.617_333_262e-5,
+        decimal: Decimal::new(8617333262, -14),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kelvin-hartree relationship"),
+        value: 3.166_811_563_456_4e-6,
+        decimal: Decimal::new(31668115634564, -19),
+        unit: Unit::Published(Cow::Borrowed("E_h")),
+        uncertainty: Some(0.000_000_000_003_5e-6),
+    },
+
+    Constant {
+        name: Cow::Borrowed("kelvin-hertz relationship"),
+        value: 2.083_661_912e10,//...
+        decimal: Decimal::new(2083661912, 1),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kelvin-inverse meter relationship"),
+        value: 69.503_480_04,//...
+        decimal: Decimal::new(6950348004, -8),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kelvin-joule relationship"),
+        value: 1.380_649e-23,
+        decimal: Decimal::new(1380649, -29),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kelvin-kilogram relationship"),
+        value: 1.536_179_187e-40,//...
+        decimal: Decimal::new(1536179187, -49),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kilogram-atomic mass unit relationship"),
+        value: 6.002_140_757_7e26,
+        decimal: Decimal::new(60021407577, 16),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_001_9e26),
+    },
+
+    Constant {
+        name: Cow::Borrowed("kilogram-electron volt relationship"),
+        value: 5.609_588_603e35,//...
+        decimal: Decimal::new(5609588603, 26),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kilogram-hartree relationship"),
+        value: 2.061_458_788_741_5e34,
+        decimal: Decimal::new(20614587887415, 21),
+        unit: Unit::Published(Cow::Borrowed("E_h")),
+        uncertainty: Some(0.000_000_000_002_2e34),
+    },
+
+    Constant {
+        name: Cow::Borrowed("kilogram-hertz relationship"),
+        value: 1.356_392_489e50,//...
+        decimal: Decimal::new(1356392489, 41),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kilogram-inverse meter relationship"),
+        value: 4.524_438_335e41,//...
+        decimal: Decimal::new(4524438335, 32),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kilogram-joule relationship"),
+        value: 8.987_551_787e16,//...
+        decimal: Decimal::new(8987551787, 7),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("kilogram-kelvin relationship"),
+        value: 6.509_657_26e39,//...
+        decimal: Decimal::new(650965726, 31),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("lattice parameter of silicon"),
+        value: 6.431_020_511e-10,
+        decimal: Decimal::new(6431020511, -19),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_089e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("lattice spacing of ideal Si (220)"),
+        value: 1.920_155_716e-10,
+        decimal: Decimal::new(1920155716, -19),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_032e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Loschmidt constant (273.15 K, 100 kPa)"),
+        value: 2.651_645_804e25,//...
+        decimal: Decimal::new(2651645804, 16),
+        unit: Unit::Published(Cow::Borrowed("m^-3")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Loschmidt constant (273.15 K, 101.325 kPa)"),
+        value: 2.686_780_111e25,//...
+        decimal: Decimal::new(2686780111, 16),
+        unit: Unit::Published(Cow::Borrowed("m^-3")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("luminous efficacy"),
+        value: 683.0,
+        decimal: Decimal::new(683, 0),
+        unit: Unit::Published(Cow::Borrowed("lm W^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("mag. flux quantum"),
+        value: 2.067_833_848e-15,//...
+        decimal: Decimal::new(2067833848, -24),
+        unit: Unit::Published(Cow::Borrowed("Wb")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("molar gas constant"),
+        value: 8.314_462_618,//...
+        decimal: Decimal::new(8314462618, -9),
+        unit: Unit::Published(Cow::Borrowed("J mol^-1 K^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("molar mass constant"),
+        value: 1.000_000_001_05e-3,
+        decimal: Decimal::new(100000000105, -14),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_000_31e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("molar mass of carbon-12"),
+        value: 12.000_000_012_6e-3,
+        decimal: Decimal::new(120000000126, -13),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_003_7e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("molar Planck constant"),
+        value: 3.990_312_712e-10,//...
+        decimal: Decimal::new(3990312712, -19),
+        unit: Unit::Published(Cow::Borrowed("J Hz^-1 mol^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("molar volume of ideal gas (273.15 K, 100 kPa)"),
+        value: 22.710_954_64e-3,
+        decimal: Decimal::new(2271095464, -11),
+        unit: Unit::Published(Cow::Borrowed("m^3 mol^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("molar volume of ideal gas (273.15 K, 101.325 kPa)"),
+        value: 22.413_969_54e-3,
+        decimal: Decimal::new(2241396954, -11),
+        unit: Unit::Published(Cow::Borrowed("m^3 mol^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("molar volume of silicon"),
+        value: 1.205_883_199e-5,
+        decimal: Decimal::new(1205883199, -14),
+        unit: Unit::Published(Cow::Borrowed("m^3 mol^-1")),
+        uncertainty: Some(0.000_000_06e-5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Molybdenum x unit"),
+        value: 1.002_009_52e-13,
+        decimal: Decimal::new(100200952, -21),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_53e-13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon Compton wavelength"),
+        value: 1.173_444_110e-14,
+        decimal: Decimal::new(1173444110, -23),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_026e-14),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon-electron mass ratio"),
+        value: 206.768_287_7,
+        decimal: Decimal::new(2067682877, -7),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_004_6),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon g factor"),
+        value: -2.002_331_841_23,
+        decimal: Decimal::new(-200233184123, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_82),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon mag. mom."),
+        value: -4.490_448_3e-26,
+        decimal: Decimal::new(-44904483, -33),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_1e-26),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon mag. mom. anomaly"),
+        value: 1.165_920_62e-3,
+        decimal: Decimal::new(116592062, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_41e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon mag. mom. to Bohr magneton ratio"),
+        value: -4.841_970_48e-3,
+        decimal: Decimal::new(-484197048, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_11e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon mag. mom. to nuclear magneton ratio"),
+        value: -8.890_597_04,
+        decimal: Decimal::new(-889059704, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon mass"),
+        value: 1.883_531_627e-28,
+        decimal: Decimal::new(1883531627, -37),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_042e-28),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon mass energy equivalent"),
+        value: 1.692_833_804e-11,
+        decimal: Decimal::new(1692833804, -20),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_038e-11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon mass in u"),
+        value: 0.113_428_925_7,
+        decimal: Decimal::new(1134289257, -10),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_002_5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon molar mass"),
+        value: 1.134_289_258e-4,
+        decimal: Decimal::new(1134289258, -13),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_025e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon-neutron mass ratio"),
+        value: 0.112_454_516_8,
+        decimal: Decimal::new(1124545168, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_002_5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon-proton mag. mom. ratio"),
+        value: -3.183_345_146,
+        decimal: Decimal::new(-3183345146, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_071),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon-proton mass ratio"),
+        value: 0.112_609_526_2,
+        decimal: Decimal::new(1126095262, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_002_5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("muon-tau mass ratio"),
+        value: 5.946_35e-2,
+        decimal: Decimal::new(594635, -7),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_4e-2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of action"),
+        value: 1.054_571_817e-34,//...
+        decimal: Decimal::new(1054571817, -43),
+        unit: Unit::Published(Cow::Borrowed("J s")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of action in eV s"),
+        value: 6.582_119_569e-16,
+        decimal: Decimal::new(6582119569, -25),
+        unit: Unit::Published(Cow::Borrowed("eV s")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of energy"),
+        value: 8.187_105_788e-14,
+        decimal: Decimal::new(8187105788, -23),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_002_6e-14),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of energy in MeV"),
+        value: 0.510_988_950_69,
+        decimal: Decimal::new(51098895069, -11),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_000_16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of length"),
+        value: 3.861_592_674_4e-13,
+        decimal: Decimal::new(38615926744, -23),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_001_2e-13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of mass"),
+        value: 9.109_383_713_9e-31,
+        decimal: Decimal::new(91093837139, -41),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_002_8e-31),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of momentum"),
+        value: 2.730_924_534_46e-22,
+        decimal: Decimal::new(273092453446, -33),
+        unit: Unit::Published(Cow::Borrowed("kg m s^-1")),
+        uncertainty: Some(0.000_000_000_85e-22),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of momentum in MeV/c"),
+        value: 0.510_998_950_69,
+        decimal: Decimal::new(51099895069, -11),
+        unit: Unit::Published(Cow::Borrowed("MeV/c")),
+        uncertainty: Some(0.000_000_000_16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of time"),
+        value: 1.288_088_666_44e-21,
+        decimal: Decimal::new(128808866644, -32),
+        unit: Unit::Published(Cow::Borrowed("s")),
+        uncertainty: Some(0.000_000_000_4e-21),
+    },
+
+    Constant {
+        name: Cow::Borrowed("natural unit of velocity"),
+        value: 299_792_458.0,
+        decimal: Decimal::new(299792458, 0),
+        unit: Unit::Published(Cow::Borrowed("m s^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron Compton wavelength"),
+        value: 1.319_590_903_82e-15,
+        decimal: Decimal::new(131959090382, -26),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_000_67e-15),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-electron mag. mom. ratio"),
+        value: 1.040_668_84e-3,
+        decimal: Decimal::new(104066884, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_24e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-electron mass ratio"),
+        value: 1_838.683_662,
+        decimal: Decimal::new(1838683662, -6),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_74),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron g factor"),
+        value: -3.826_085_52,
+        decimal: Decimal::new(-382608552, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_9),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron gyromag. ratio"),
+        value: 1.832_471_74e8,
+        decimal: Decimal::new(183247174, 0),
+        unit: Unit::Published(Cow::Borrowed("s^-1 T^-1")),
+        uncertainty: Some(0.000_000_43e8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron gyromag. ratio in MHz/T"),
+        value: 29.164_693_5,
+        decimal: Decimal::new(291646935, -7),
+        unit: Unit::Published(Cow::Borrowed("MHz T^-1")),
+        uncertainty: Some(0.000_006_9),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron mag. mom."),
+        value: -9.662_365_3e-27,
+        decimal: Decimal::new(-96623653, -34),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_002_3e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron mag. mom. to Bohr magneton ratio"),
+        value: -1.041_875_65e-3,
+        decimal: Decimal::new(-104187565, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_25e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron mag. mom. to nuclear magneton ratio"),
+        value: -1.913_042_76,
+        decimal: Decimal::new(-191304276, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_45),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron mass"),
+        value: 1.674_927_500_56e-27,
+        decimal: Decimal::new(167492750056, -38),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_000_85e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron mass energy equivalent"),
+        value: 1.505_349_765_14e-10,
+        decimal: Decimal::new(150534976514, -21),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_76e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron mass energy equivalent in MeV"),
+        value: 939.565_421_94,
+        decimal: Decimal::new(93956542194, -8),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_48),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron mass in u"),
+        value: 1.008_664_916_06,
+        decimal: Decimal::new(100866491606, -11),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron molar mass"),
+        value: 1.008_664_917_12e-3,
+        decimal: Decimal::new(100866491712, -14),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_000_51e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-muon mass ratio"),
+        value: 8.892_484_08,
+        decimal: Decimal::new(889248408, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-proton mag. mom. ratio"),
+        value: -0.684_979_35,
+        decimal: Decimal::new(-68497935, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-proton mass difference"),
+        value: 2.305_574_61e-30,
+        decimal: Decimal::new(230557461, -38),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_67e-30),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-proton mass difference energy equivalent"),
+        value: 2.072_147_12e-13,
+        decimal: Decimal::new(207214712, -21),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_6e-13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-proton mass difference energy equivalent in MeV"),
+        value: 1.293_332_51,
+        decimal: Decimal::new(129333251, -8),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_38),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-proton mass difference in u"),
+        value: 1.388_449_48e-3,
+        decimal: Decimal::new(138844948, -11),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_4e-3)
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-proton mass ratio"),
+        value: 1.001_378_419_46,
+        decimal: Decimal::new(100137841946, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron relative atomic mass"),
+        value: 1.008_664_916_06,
+        decimal: Decimal::new(100866491606, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron-tau mass ratio"),
+        value: 0.528_799,
+        decimal: Decimal::new(528799, -6),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_036),
+    },
+
+    Constant {
+        name: Cow::Borrowed("neutron to shielded proton mag. mom. ratio"),
+        value: -0.684_996_94,
+        decimal: Decimal::new(-68499694, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Newtonian constant of gravitation"),
+        value: 6.674_3e-11,
+        decimal: Decimal::new(66743, -15),
+        unit: Unit::Published(Cow::Borrowed("m^3 kg^-1 s^-2")),
+        uncertainty: Some(0.000_15e-11),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Newtonian constant of gravitation over h-bar c"),
+        value: 6.708_83e-39,
+        decimal: Decimal::new(670883, -44),
+        unit: Unit::Published(Cow::Borrowed("(GeV/c^2)^-2")),
+        uncertainty: Some(0.000_15e-39),
+    },
+
+    Constant {
+        name: Cow::Borrowed("nuclear magneton"),
+        value: 5.050_783_739_3e-27,
+        decimal: Decimal::new(50507837393, -37),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_001_6e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("nuclear magneton in eV/T"),
+        value: 3.152_451_254_17e-8,
+        decimal: Decimal::new(315245125417, -19),
+        unit: Unit::Published(Cow::Borrowed("eV T^-1")),
+        uncertainty: Some(0.000_000_000_98e-8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("nuclear magneton in inverse meter per tesla"),
+        value: 2.542_623_410_09e-2,
+        decimal: Decimal::new(254262341009, -13),
+        unit: Unit::Published(Cow::Borrowed("m^-1 T^-1")),
+        uncertainty: Some(0.000_000_000_79e-2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("nuclear magneton in K/T"),
+        value: 3.658_267_770_6e-4,
+        decimal: Decimal::new(36582677706, -14),
+        unit: Unit::Published(Cow::Borrowed("K T^-1")),
+        uncertainty: Some(0.000_000_001_1e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("nuclear magneton in MHz/T"),
+        value: 7.622_593_218_8,
+        decimal: Decimal::new(76225932188, -10),
+        unit: Unit::Published(Cow::Borrowed("MHz T^-1")),
+        uncertainty: Some(0.000_000_002_4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Planck constant"),
+        value: 6.626_070_15e-34,
+        decimal: Decimal::new(662607015, -42),
+        unit: Unit::Published(Cow::Borrowed("J Hz^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Planck constant in eV/Hz"),
+        value: 4.135_667_696e-15,//...
+        decimal: Decimal::new(4135667696, -24),
+        unit: Unit::Published(Cow::Borrowed("eV Hz^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Planck length"),
+        value: 1.616_255e-35,
+        decimal: Decimal::new(1616255, -41),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_018e-35),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Planck mass"),
+        value: 2.176_434e-8,
+        decimal: Decimal::new(2176434, -14),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_024e-8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Planck mass energy equivalent in GeV"),
+        value: 1.220_890e19,
+        decimal: Decimal::new(1220890, 13),
+        unit: Unit::Published(Cow::Borrowed("GeV")),
+        uncertainty: Some(0.000_014e19),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Planck temperature"),
+        value: 1.416_784e32,
+        decimal: Decimal::new(1416784, 26),
+        unit: Unit::Published(Cow::Borrowed("K")),
+        uncertainty: Some(0.000_016e32),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Planck time"),
+        value: 5.391_247e-44,
+        decimal: Decimal::new(5391247, -50),
+        unit: Unit::Published(Cow::Borrowed("s")),
+        uncertainty: Some(0.000_06e-44),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton charge to mass quotient"),
+        value: 9.578_833_143e7,
+        decimal: Decimal::new(9578833143, -2),
+        unit: Unit::Published(Cow::Borrowed("C kg^-1")),
+        uncertainty: Some(0.000_000_003e7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton Compton wavelength"),
+        value: 1.321_409_853_6e-15,
+        decimal: Decimal::new(13214098536, -25),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_000_41e-15),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton-electron mass ratio"),
+        value: 1_836.152_673_426,
+        decimal: Decimal::new(1836152673426, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_032),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton g factor"),
+        value: 5.585_694_689_3,
+        decimal: Decimal::new(55856946893, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_001_6),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton gyromag. ratio"),
+        value: 2.675_221_870_8e8,
+        decimal: Decimal::new(26752218708, -2),
+        unit: Unit::Published(Cow::Borrowed("s^-1 T^-1")),
+        uncertainty: Some(0.000_000_001_1e8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton gyromag. ratio in MHz/T"),
+        value: 42.577_478_461,
+        decimal: Decimal::new(42577478461, -9),
+        unit: Unit::Published(Cow::Borrowed("MHz T^-1")),
+        uncertainty: Some(0.000_000_018),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mag. mom."),
+        value: 1.410_606_795_45e-26,
+        decimal: Decimal::new(141060679545, -37),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_000_6e-26),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mag. mom. to Bohr magneton ratio"),
+        value: 1.521_032_202_3e-3,
+        decimal: Decimal::new(15210322023, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_45e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mag. mom. to nuclear magneton ratio"),
+        value: 2.792_847_344_63,
+        decimal: Decimal::new(279284734463, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_82),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mag. shielding correction"),
+        value: 2.567_15e-5,
+        decimal: Decimal::new(256715, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_41e-5),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mass"),
+        value: 1.672_621_925_95e-27,
+        decimal: Decimal::new(167262192595, -38),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_000_52e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mass energy equivalent"),
+        value: 1.503_277_618_02e-10,
+        decimal: Decimal::new(150327761802, -21),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_47e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mass energy equivalent in MeV"),
+        value: 938.272_089_43,
+        decimal: Decimal::new(93827208943, -8),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_29),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton mass in u"),
+        value: 1.007_276_466_578_9,
+        decimal: Decimal::new(10072764665789, -13),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_008_3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton molar mass"),
+        value: 1.007_276_467_64e-3,
+        decimal: Decimal::new(100727646764, -14),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_000_31e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton-muon mass ratio"),
+        value: 8.880_243_38,
+        decimal: Decimal::new(888024338, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_2),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton-neutron mag. mom. ratio"),
+        value: -1.459_898_02,
+        decimal: Decimal::new(-145989802, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_34),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton-neutron mass ratio"),
+        value: 0.998_623_477_97,
+        decimal: Decimal::new(99862347797, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton relative atomic mass"),
+        value: 1.007_276_466_578_9,
+        decimal: Decimal::new(10072764665789, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_008_3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton rms charge radius"),
+        value: 8.407_5e-16,
+        decimal: Decimal::new(84075, -20),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.006_4e-16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("proton-tau mass ratio"),
+        value: 0.528_051,
+        decimal: Decimal::new(528051, -6),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_036),
+    },
+
+    Constant {
+        name: Cow::Borrowed("quantum of circulation"),
+        value: 3.636_947_546_7e-4,
+        decimal: Decimal::new(36369475467, -14),
+        unit: Unit::Published(Cow::Borrowed("m^2 s^-1")),
+        uncertainty: Some(0.000_000_001_1e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("quantum of circulation times 2"),
+        value: 7.273_895_093_4e-4,
+        decimal: Decimal::new(72738950934, -14),
+        unit: Unit::Published(Cow::Borrowed("m^2 s^-1")),
+        uncertainty: Some(0.000_000_002_3e-4),
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced Compton wavelength"),
+        value: 3.861_592_674_4e-13,
+        decimal: Decimal::new(38615926744, -23),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_001_2e-13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced muon Compton wavelength"),
+        value: 1.867_594_306e-15,
+        decimal: Decimal::new(1867594306, -24),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_042e-15),
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced neutron Compton wavelength"),
+        value: 2.100_194_152e-16,
+        decimal: Decimal::new(2100194152, -25),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_001_1e-16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced Planck constant"),
+        value: 1.054_571_817e-34,//...
+        decimal: Decimal::new(1054571817, -43),
+        unit: Unit::Published(Cow::Borrowed("J s")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced Planck constant in eV s"),
+        value: 6.582_119_569e-16,//...
+        decimal: Decimal::new(6582119569, -25),
+        unit: Unit::Published(Cow::Borrowed("eV s")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced Planck constant times c in MeV fm"),
+        value: 197.326_980_4,
+        decimal: Decimal::new(1973269804, -7),
+        unit: Unit::Published(Cow::Borrowed("MeV fm")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced proton Compton wavelength"),
+        value: 2.103_089_100_51e-16,
+        decimal: Decimal::new(210308910051, -27),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_000_000_66e-16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("reduced tau Compton wavelength"),
+        value: 1.110_538e-16,
+        decimal: Decimal::new(1110538, -22),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_075e-16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Rydberg constant"),
+        value: 10_973_731.568_157,
+        decimal: Decimal::new(10973731568157, -6),
+        unit: Unit::Published(Cow::Borrowed("m^-1")),
+        uncertainty: Some(0.000_012),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Rydberg constant times c in Hz"),
+        value: 3.289_841_960_25e15,
+        decimal: Decimal::new(328984196025, 4),
+        unit: Unit::Published(Cow::Borrowed("Hz")),
+        uncertainty: Some(0.000_000_000_003_6e15),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Rydberg constant times hc in eV"),
+        value: 13.605_693_122_99,
+        decimal: Decimal::new(1360569312299, -11),
+        unit: Unit::Published(Cow::Borrowed("eV")),
+        uncertainty: Some(0.000_000_000_015),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Rydberg constant times hc in J"),
+        value: 2.179_872_361_103e-18,
+        decimal: Decimal::new(2179872361103, -30),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_000_002_4e-18),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Sackur-Tetrode constant (1 K, 100 kPa)"),
+        value: -1.151_707_534_96,
+        decimal: Decimal::new(-115170753496, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_47),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Sackur-Tetrode constant (1 K, 101.325 kPa)"),
+        value: -1.164_870_521_49,
+        decimal: Decimal::new(-116487052149, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_47),
+    },
+
+    Constant {
+        name: Cow::Borrowed("second radiation constant"),
+        value: 1.438_776_877e-2,//...
+        decimal: Decimal::new(1438776877, -11),
+        unit: Unit::Published(Cow::Borrowed("m K")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded helion gyromag. ratio"),
+        value: 2.037_894_607_8e8,
+        decimal: Decimal::new(20378946078, -2),
+        unit: Unit::Published(Cow::Borrowed("s^-1 T^-1")),
+        uncertainty: Some(0.000_000_001_8e8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded helion gyromag. ratio in MHz/T"),
+        value: 32.434_100_033,
+        decimal: Decimal::new(32434100033, -9),
+        unit: Unit::Published(Cow::Borrowed("MHz T^-1")),
+        uncertainty: Some(0.000_000_028),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded helion mag. mom."),
+        value: -1.074_533_110_35e-26,
+        decimal: Decimal::new(-107453311035, -37),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_000_93e-26),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded helion mag. mom. to Bohr magneton ratio"),
+        value: -1.158_671_494_57e-3,
+        decimal: Decimal::new(-115867149457, -14),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_94e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded helion mag. mom. to nuclear magneton ratio"),
+        value: -2.117_497_762_4,
+        decimal: Decimal::new(-21174977624, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_001_7),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded helion to proton mag. mom. ratio"),
+        value: -0.761_766_577_21,
+        decimal: Decimal::new(-76176657721, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_66),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded helion to shielded proton mag. mom. ratio"),
+        value: -0.761_786_133_4,
+        decimal: Decimal::new(-7617861334, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_003_1),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded proton gyromag. ratio"),
+        value: 2.675_153_194e8,
+        decimal: Decimal::new(2675153194, -1),
+        unit: Unit::Published(Cow::Borrowed("s^-1 T^-1")),
+        uncertainty: Some(0.000_000_011e8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded proton gyromag. ratio in MHz/T"),
+        value: 42.576_385_43,
+        decimal: Decimal::new(4257638543, -8),
+        unit: Unit::Published(Cow::Borrowed("MHz T^-1")),
+        uncertainty: Some(0.000_000_17),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded proton mag. mom."),
+        value: 1.410_570_583e-26,
+        decimal: Decimal::new(1410570583, -35),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_005_8e-26),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded proton mag. mom. to Bohr magneton ratio"),
+        value: 1.520_993_155_1e-3,
+        decimal: Decimal::new(15209931551, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_006_2e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielded proton mag. mom. to nuclear magneton ratio"),
+        value: 2.792_755_648,
+        decimal: Decimal::new(2792755648, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_011),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielding difference of d and p in HD"),
+        value: 1.987_70e-8,
+        decimal: Decimal::new(198770, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_1e-8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("shielding difference of t and p in HT"),
+        value: 2.394_5e-8,
+        decimal: Decimal::new(23945, -12),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_2e-8),
+    },
+
+    Constant {
+        name: Cow::Borrowed("speed of light in vacuum"),
+        value: 299_792_458.0,
+        decimal: Decimal::new(299792458, 0),
+        unit: Unit::Published(Cow::Borrowed("m s^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("standard acceleration of gravity"),
+        value: 9.806_65,
+        decimal: Decimal::new(980665, -5),
+        unit: Unit::Published(Cow::Borrowed("m s^-2")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("standard atmosphere"),
+        value: 101_325.0,
+        decimal: Decimal::new(101325, 0),
+        unit: Unit::Published(Cow::Borrowed("Pa")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("standard-state pressure"),
+        value: 100_000.0,
+        decimal: Decimal::new(100000, 0),
+        unit: Unit::Published(Cow::Borrowed("Pa")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Stefan-Boltzmann constant"),
+        value: 5.670_374_419e-8,
+        decimal: Decimal::new(5670374419, -17),
+        unit: Unit::Published(Cow::Borrowed("W m^-2 K^-4")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau Compton wavelength"),
+        value: 6.977_71e-16,
+        decimal: Decimal::new(697771, -21),
+        unit: Unit::Published(Cow::Borrowed("m")),
+        uncertainty: Some(0.000_47e-16),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau-electron mass ratio"),
+        value: 3_477.23,
+        decimal: Decimal::new(347723, -2),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.23),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau energy equivalent"),
+        value: 1_776.86,
+        decimal: Decimal::new(177686, -2),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.12),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau mass"),
+        value: 3.167_54e-27,
+        decimal: Decimal::new(316754, -32),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_21e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau mass energy equivalent"),
+        value: 2.846_84e-10,
+        decimal: Decimal::new(284684, -15),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_19e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau mass in u"),
+        value: 1.907_54,
+        decimal: Decimal::new(190754, -5),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau molar mass"),
+        value: 1.907_54e-3,
+        decimal: Decimal::new(190754, -8),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_13e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau-muon mass ratio"),
+        value: 16.817,
+        decimal: Decimal::new(16817, -3),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.001_1),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau-neutron mass ratio"),
+        value: 1.891_15,
+        decimal: Decimal::new(189115, -5),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("tau-proton mass ratio"),
+        value: 1.893_76,
+        decimal: Decimal::new(189376, -5),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_13),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Thomson cross section"),
+        value: 6.652_458_705_1e-29,
+        decimal: Decimal::new(66524587051, -39),
+        unit: Unit::Published(Cow::Borrowed("m^2")),
+        uncertainty: Some(0.000_000_006_2e-29),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton-electron mass ratio"),
+        value: 5_469.921_535_51,
+        decimal: Decimal::new(546992153551, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_21),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton g factor"),
+        value: 5.957_924_93,
+        decimal: Decimal::new(595792493, -8),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_012),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton mag. mom."),
+        value: 1.504_609_517_8e-26,
+        decimal: Decimal::new(15046095178, -36),
+        unit: Unit::Published(Cow::Borrowed("J T^-1")),
+        uncertainty: Some(0.000_000_003e-26),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton mag. mom. to Bohr magneton ratio"),
+        value: 1.622_393_664_8e-3,
+        decimal: Decimal::new(16223936648, -13),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_003_2e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton mag. mom. to nuclear magneton ratio"),
+        value: 2.978_962_465,
+        decimal: Decimal::new(2978962465, -9),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_005_9),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton mass"),
+        value: 5.007_356_751_2e-27,
+        decimal: Decimal::new(50073567512, -37),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_001_6e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton mass energy equivalent"),
+        value: 4.500_387_811_9e-10,
+        decimal: Decimal::new(45003878119, -20),
+        unit: Unit::Published(Cow::Borrowed("J")),
+        uncertainty: Some(0.000_000_001_4e-10),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton mass energy equivalent in MeV"),
+        value: 2_808.921_136_68,
+        decimal: Decimal::new(280892113668, -8),
+        unit: Unit::Published(Cow::Borrowed("MeV")),
+        uncertainty: Some(0.000_000_88),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton mass in u"),
+        value: 3.015_500_715_97,
+        decimal: Decimal::new(301550071597, -11),
+        unit: Unit::Published(Cow::Borrowed("u")),
+        uncertainty: Some(0.000_000_000_1),
+    },
+    Constant {
+        name: Cow::Borrowed("triton molar mass"),
+        value: 3.015_500_719_13e-3,
+        decimal: Decimal::new(301550071913, -14),
+        unit: Unit::Published(Cow::Borrowed("kg mol^-1")),
+        uncertainty: Some(0.000_000_000_94e-3),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton-proton mass ratio"),
+        value: 2.993_717_034_03,
+        decimal: Decimal::new(299371703403, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_1),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton relative atomic mass"),
+        value: 3.015_500_715_97,
+        decimal: Decimal::new(301550071597, -11),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_000_1),
+    },
+
+    Constant {
+        name: Cow::Borrowed("triton to proton mag. mom. ratio"),
+        value: 1.066_639_918_9,
+        decimal: Decimal::new(10666399189, -10),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_000_002_1),
+    },
+
+    Constant {
+        name: Cow::Borrowed("unified atomic mass unit"),
+        value: 1.660_539_068_92e-27,
+        decimal: Decimal::new(166053906892, -38),
+        unit: Unit::Published(Cow::Borrowed("kg")),
+        uncertainty: Some(0.000_000_000_52e-27),
+    },
+
+    Constant {
+        name: Cow::Borrowed("vacuum electric permittivity"),
+        value: 8.854_187_818_8e-12,
+        decimal: Decimal::new(88541878188, -22),
+        unit: Unit::Published(Cow::Borrowed("F m^-1")),
+        uncertainty: Some(0.000_000_001_4e-12),
+    },
+
+    Constant {
+        name: Cow::Borrowed("vacuum mag. permeability"),
+        value: 1.256_637_061_27e-6,
+        decimal: Decimal::new(125663706127, -17),
+        unit: Unit::Published(Cow::Borrowed("N A^-2")),
+        uncertainty: Some(0.000_000_000_2e-6),
+    },
+
+    Constant {
+        name: Cow::Borrowed("von Klitzing constant"),
+        value: 25_812.807_45,
+        decimal: Decimal::new(2581280745, -5),
+        unit: Unit::Published(Cow::Borrowed("ohm")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("weak mixing angle"),
+        value: 0.223_05,
+        decimal: Decimal::new(22305, -5),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_23),
+    },
+
+    Constant {
+        name: Cow::Borrowed("Wien frequency displacement law constant"),
+        value: 5.878_925_757e10,
+        decimal: Decimal::new(5878925757, 1),
+        unit: Unit::Published(Cow::Borrowed("Hz K^-1")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("Wien wavelength displacement law constant"),
+        value: 2.897_771_955e-3,
+        decimal: Decimal::new(2897771955, -12),
+        unit: Unit::Published(Cow::Borrowed("m K")),
+        uncertainty: None,
+    },
+
+    Constant {
+        name: Cow::Borrowed("W to Z mass ratio"),
+        value: 0.881_45,
+        decimal: Decimal::new(88145, -5),
+        unit: Unit::Dimensionless,
+        uncertainty: Some(0.000_13),
+    },
+
+]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renamed_entries_are_found_by_either_name() {
+        let dataset = Dataset::codata();
+
+        for (old, current) in RENAMED {
+            let entry = dataset.get(current).expect(current);
+            assert_eq!(dataset.get(old), Some(entry));
+            assert_eq!(find_constant(old).as_ref(), Some(entry));
+        }
+        assert_eq!(current_name("Planck constant"), "Planck constant");
+    }
+
+    #[test]
+    fn an_entry_of_the_old_name_takes_precedence() {
+        let mut dataset = Dataset::codata();
+        let mut entry = dataset.get("vacuum electric permittivity").unwrap().clone();
+        entry.name = Cow::Borrowed("vacuum electricy permittivity");
+        entry.value = 1.0;
+        dataset.constants.push(entry);
+
+        assert_eq!(
+            dataset.get("vacuum electricy permittivity").unwrap().value,
+            1.0
+        );
+        assert_ne!(
+            dataset.get("vacuum electric permittivity").unwrap().value,
+            1.0
+        );
+    }
+
+    #[test]
+    fn values_without_digits_are_not_printed_as_zero() {
+        let mut constant = find_constant("Planck constant").unwrap();
+        constant.value = f64::INFINITY;
+
+        assert_eq!(constant.published_decimal(), None);
+        assert_eq!(constant.value_text(), "inf");
+        assert_eq!(constant.concise(), "inf J Hz^-1");
+
+        constant.value = 2.5;
+        assert_eq!(constant.published_decimal(), Some(Decimal::new(25, -1)));
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let names: Vec<_> = initialize_constants().into_iter().map(|c| c.name).collect();
+        for (index, name) in names.iter().enumerate() {
+            assert!(!names[..index].contains(name), "{} is listed twice", name);
+        }
+    }
+}
@@ -1,2557 +1,1030 @@
+use std::env;
 use std::fmt;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use universalconstants::budget::{BudgetFormat, UncertaintyBudget};
+use universalconstants::calc::{Calculation, Calculator};
+use universalconstants::concise::ConciseFormat;
+use universalconstants::consistency::{Comparison, ConsistencyError};
+use universalconstants::conversion::{self, convert_with_provider};
+use universalconstants::csv::Column;
+use universalconstants::decimal::Decimal;
+use universalconstants::identify::{last_digit_tolerance, Identification};
+use universalconstants::latex::SiunitxCommand;
+use universalconstants::merge::Source;
+use universalconstants::unit_format::{format_unit, UnitStyle};
+use universalconstants::units::Dimension;
+use universalconstants::{Constant, Dataset};
+
+const USAGE: &str = "\
+Usage: universalconstants [--color=auto|always|never] [--units=ascii|unicode|dot]
+                          [--data FILE]... [--define 'NAME=DEFINITION']... [COMMAND]
+
+Commands:
+  list [--exact] [--measured] [--dimensionless] [--unit UNIT] [--dimension DIM]
+       [--name TEXT]         List constants, optionally filtered; DIM is a quantity
+                             such as energy or length, or a unit such as J/K
+  get NAME                   Print one constant as tab-separated key/value lines
+  search TEXT                List constants whose name contains TEXT (case-insensitive)
+  convert VALUE FROM TO      Convert between energy equivalents
+  export [--format FORMAT] [--columns LIST]
+                             Write the whole table (formats: text, json, csv, tsv,
+                             sty for a LaTeX package using siunitx 3, sty2 for siunitx 2);
+                             LIST picks csv/tsv columns, e.g. name,value,uncertainty
+  identify VALUE [--tolerance RELATIVE]
+                             Name the constants, or reciprocals, squares and cubes
+                             of constants, a number could be, closest first; the
+                             default tolerance is half a unit in VALUE's last digit
+  calc EXPRESSION            Evaluate e.g. 'h*c/(k_B*500 nm)' or 'm_e*c^2 in MeV' with
+                             constant symbols, \"quoted names\" and units, giving the
+                             propagated uncertainty
+  budget EXPRESSION [--k K] [--format text|markdown|csv]
+                             Uncertainty budget of a calc expression in the GUM
+                             layout, with the expanded uncertainty for coverage
+                             factor K (default 2)
+  compare VALUE UNCERTAINTY NAME [--k K]
+                             Test a measurement (in the constant's unit) against
+                             a constant: difference, z-score and E_n, passing when
+                             |E_n| <= 1 at coverage factor K (default 2)
+  dump                       Print every constant
+  help                       Show this message
+
+Without a command an interactive shell starts (type help there for its commands).
+
+Color is used only when stdout is a terminal and NO_COLOR is unset, unless
+--color=always or --color=never says otherwise.
+
+--data merges constants from a file into the table for every command: NIST's
+allascii.txt format (.txt), CSV or TSV as exported (.csv, .tsv) or JSON (.json).
+Later files override earlier ones, with a warning for each entry they replace;
+get shows where an entry came from.
+
+--define adds a constant of your own to every command, given in concise notation
+(--define 'local g=9.803 11(5) m s^-2') or as a calc formula over symbols, names
+and earlier definitions (--define 'Larmor per tesla=gamma_p/(2*pi) in MHz T^-1').
+
+Units are printed as listed (m^3 kg^-1 s^-2) unless --units=unicode (m³ kg⁻¹ s⁻²)
+or --units=dot (m³·kg⁻¹·s⁻²) is given; exports other than text stay ASCII.
+
+Exit codes: 0 success, 1 nothing found, conversion or comparison failed, 2 usage error";
+
 #[derive(Debug)]
-pub struct Constant {
-    pub name: &'static str,
-    pub value: f64,
-    pub si_base_units: Option<&'static str>,
-    pub uncertainty: Option<f64>,
+enum Command {
+    Repl,
+    Dump,
+    List(ListFilter),
+    Get(String),
+    Search(String),
+    Convert {
+        value: f64,
+        from: String,
+        to: String,
+    },
+    Export {
+        format: String,
+        columns: Vec<Column>,
+    },
+    Identify {
+        value: f64,
+        tolerance: f64,
+    },
+    Calc(String),
+    Budget {
+        expression: String,
+        format: BudgetFormat,
+        coverage_factor: f64,
+    },
+    Compare {
+        value: f64,
+        uncertainty: f64,
+        name: String,
+        coverage_factor: f64,
+    },
+    Help,
 }
 
-impl fmt::Display for Constant {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-
-        let green = "\x1b[32m";
-        let reset = "\x1b[0m";
+#[derive(Debug, Default)]
+struct ListFilter {
+    exact: bool,
+    measured: bool,
+    dimensionless: bool,
+    unit: Option<String>,
+    dimension: Option<Dimension>,
+    name: Option<String>,
+}
 
-        writeln!(f, "{}Constant: {}{}\n", green, self.name, reset)?;
+impl ListFilter {
+    fn matches(&self, constant: &Constant) -> bool {
+        (!self.exact || constant.uncertainty.is_none())
+            && (!self.measured || constant.uncertainty.is_some())
+            && (!self.dimensionless || constant.unit.is_dimensionless())
+            && self
+                .unit
+                .as_deref()
+                .is_none_or(|unit| constant.unit.as_str() == Some(unit))
+            && self
+                .dimension
+                .is_none_or(|dimension| constant.dimension() == Some(dimension))
+            && self
+                .name
+                .as_deref()
+                .is_none_or(|text| name_contains(constant, text))
+    }
+}
 
-        writeln!(f, "Value: {}\n", self.value)?;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
 
-        if let Some(si_base_units) = self.si_base_units {
-            writeln!(f, "SI base units: {}\n", si_base_units )?;
-        } else {
-            writeln!(f, "SI base units: Dimensionless\n", )?;
+impl ColorChoice {
+    fn parse(text: &str) -> Result<Self, CliError> {
+        match text {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            other => Err(CliError::Usage(format!(
+                "--color must be auto, always or never, not \"{}\"",
+                other
+            ))),
         }
+    }
 
-        if let Some(uncertainty) = self.uncertainty {
-            writeln!(f, "Uncertainty: {}\n", uncertainty)?;
-        } else {
-            writeln!(f, "Uncertainty: Exact\n")?; //write Exact
+    // NO_COLOR (https://no-color.org) only applies when the user did not ask explicitly
+    fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                io::stdout().is_terminal()
+                    && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+            }
         }
-
-        //write!(f, "{}", reset);
-
-        Ok(())
     }
 }
 
-// START OF ALPHABETICAL DATA FROM NIST https://pml.nist.gov/cuu/Constants/Table/allascii.txt
-pub fn initialize_constants() -> Vec<Constant> { 
-vec![
-    Constant {
-        name: "alpha particle-electron mass ratio",
-        value: 7_294.299_541_71,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_17),
-    },   
-
-    Constant {
-        name: "alpha particle mass",
-        value: 6.644_657_345_0e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_002_1e-27),
-    },   
-
-    Constant {
-        name: "alpha particle mass energy equivalent",
-        value: 5.971_920_199_7e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_0019e-10),
-    },  
-
-    Constant {
-        name: "alpha particle mass energy equivalent in MeV",
-        value: 3_727.379_411_8,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_001_2),
-    },  
-
-    Constant {
-        name: "alpha particle mass in u",
-        value: 4.001_506_179_129,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_062),
-    },  
-
-    Constant {
-        name: "alpha particle molar mass",
-        value: 4.001_506_183_3e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_001_2e-3),
-    },  
-
-    Constant {
-        name: "alpha particle-proton mass ratio",
-        value: 3.972_599_690_252,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_07),
-    },  
-
-    Constant {
-        name: "alpha particle relative atomic mass",
-        value: 4.001_506_179_129,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_062),
-    },  
-
-    Constant {
-        name: "alpha particle rms charge raduis",
-        value: 1.678_5e-15,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.002_1e-15),
-    },  
-
-    Constant {
-        name: "Angstrom star", // A is capitalized on nist
-        value: 1.000_014_95e-10,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_9e-10),
-    },  
-
-    Constant {
-        name: "atomic mass constant",
-        value: 1.660_539_068_92e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_000_52e-27),
-    },
-
-    Constant {
-        name: "atomic mass constant energy equivalent",
-        value: 1.492_418_087_68e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_46e-10),
-    },
-
-    Constant {
-        name: "atomic mass constant energy equivalent",
-        value: 931.494_103_72,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_29),
-    },
-
-    Constant {
-        name: "atomic mass unit-electron volt relationship",
-        value: 9.314_941_037_2e8,
-        si_base_units: Some("eV"),
-        uncertainty: Some(0.000_000_002_9e8),
-    },
-
-    Constant {
-        name: "atomic mass unit-hartree relationship",
-        value: 3.423_177_692_2e7,
-        si_base_units: Some("E_h"),
-        uncertainty: Some(0.000_000_001_1e7),
-    },
-
-    Constant {
-        name: "atomic mass unit-hertz relationship",
-        value: 2.252_342_721_85e23,
-        si_base_units: Some("Hz"),
-        uncertainty: Some(0.000_000_000_7e23),
-    },
-
-    Constant {
-        name: "atomic mass unit-inverse meter relationship",
-        value: 7.513_006_620_9e14,
-        si_base_units: Some("m^-1"),
-        uncertainty: Some(0.000_000_002_3e14),
-    },
-
-    Constant {
-        name: "atomic mass unit-joule relationship",
-        value: 1.492_418_087_68e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_46e-10),
-    },
-
-    Constant {
-        name: "atomic mass unit-kelvin relationship",
-        value: 1.080_954_020_67e13,
-        si_base_units: Some("K"),
-        uncertainty: Some(0.000_000_000_34e13),
-    },
-
-    Constant {
-        name: "atomic mass unit-kilogram relationship",
-        value: 1.660_539_068_92e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_000_52e-27),
-    },
-
-    Constant {
-        name: "atomic unit of 1st hyperpolarizability",
-        value: 3.206_361_299_6e-53,
-        si_base_units: Some("C^3 m^3 J^-2"),
-        uncertainty: Some(0.000_000_001_5e-53),
-    },
-
-    Constant {
-        name: "atomic unit of 2nd hyperpolarizability",
-        value: 6.235_379_973_5e-65,
-        si_base_units: Some("C^4 m^4 J^-3"),
-        uncertainty: Some(0.000_000_003_9e-65),
-    },
-
-    Constant {
-        name: "atomic unit of action",
-        value: 1.054_571_817e-34,//...
-        si_base_units: Some("J s"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "atomic unit of charge",
-        value: 1.602_176_634e-19,
-        si_base_units: Some("C"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "atomic unit of charge density",
-        value: 1.081_202_386_77e12,
-        si_base_units: Some("C m^-3"),
-        uncertainty: Some(0.000_000_000_51e12),
-    },
-
-    Constant {
-        name: "atomic unit of current",
-        value: 6.623_618_237_508_2e-3,
-        si_base_units: Some("A"),
-        uncertainty: Some(0.000_000_000_007_2e-3),
-    },
-
-    Constant {
-        name: "atomic unit of electic dipole mom.", //mom. is short for moment : to be decided if should use moment or follow nist mom.
-        value: 8.478_353_619_8e-30,
-        si_base_units: Some("C m"),
-        uncertainty: Some(0.000_000_001_3e-30),
-    },
-
-    Constant {
-        name: "atomic unit of electric field",
-        value: 5.142_206_751_12e11,
-        si_base_units: Some("V m^-1"),
-        uncertainty: Some(0.000_000_000_80e11),
-    },
-
-    Constant {
-        name: "atomic unit electrical field gradient",
-        value: 9.717_362_442_4e21,
-        si_base_units: Some("V m^-2"),
-        uncertainty: Some(0.000_000_003e21),
-    },
-
-    Constant {
-        name: "atomic unit of electric polarizability",
-        value: 1.648_777_272_12e-41,
-        si_base_units: Some("C^2 m^2 J^-1"),
-        uncertainty: Some(0.000_000_000_51e-41),
-    },
-
-    Constant {
-        name: "atomic unit of electric potential",
-        value: 27.211_386_245_981,
-        si_base_units: Some("V"),
-        uncertainty: Some(0.000_000_000_03),
-    },
-
-    Constant {
-        name: "atomic unit of electric quadrupole mom.", //
-        value: 4.486_551_518_5e-40,
-        si_base_units: Some("C m^2"),
-        uncertainty: Some(0.000_000_001_4e-40),
-    },
-
-    Constant {
-        name: "atomic unit of energy",
-        value: 4.359_744_722_206e-18,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_004_8e-18),
-    },
-
-    Constant {
-        name: "atomic unit of force",
-        value: 8.238_723_503_8e-8,
-        si_base_units: Some("N"),
-        uncertainty: Some(0.000_000_001_3e-8),
-    },
-
-    Constant {
-        name: "atomic unit of length",
-        value: 5.291_722_105_44e-11,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_000_82e-11),
-    },
-
-    Constant {
-        name: "atomic unit of mag. dipole mom.",
-        value: 1.854_802_013_15e-23,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_000_58e-23),
-    },
-
-    Constant {
-        name: "atomic unit of mag. flux density",
-        value: 2.350_517_570_77e5,
-        si_base_units: Some("T"),
-        uncertainty: Some(0.000_000_000_73e5),
-    },
-
-    Constant {
-        name: "atomic unit of magnetizability",
-        value: 2.350_517_570_77_e5,
-        si_base_units: Some("T"),
-        uncertainty: Some(0.000_000_000_73e5),
-    },
-
-    Constant {
-        name: "atomic unit of mass",
-        value: 9.109_383_713_9e-31,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_002_8e-31),
-    },
-
-    Constant {
-        name: "atomic unit of momentum",
-        value: 1.992_851_915_45e-24,
-        si_base_units: Some("kg m s^-1"),
-        uncertainty: Some(0.000_000_000_31e-24),
-    },
-
-    Constant {
-        name: "atomic unit of permittivitty",
-        value: 1.112_650_056_20e-10,
-        si_base_units: Some("F m^-1"),
-        uncertainty: Some(0.000_000_000_17),
-    },
-
-    Constant {
-        name: "atomic unit of time",
-        value: 2.418_884_326_586_4e-17,
-        si_base_units: Some("s"),
-        uncertainty: Some(0.000_000_000_002_6e-17),
-    },
-
-    Constant {
-        name: "atomic unit of velocity",
-        value: 2.187_691_262_16e6,
-        si_base_units: Some("m s^-1"),
-        uncertainty: Some(0.000_000_000_34e6),
-    },
-
-    Constant {
-        name: "Avogadro constant", //
-        value: 6.022_140_76e23,
-        si_base_units: Some("mol^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Bohr magneton",
-        value: 9.274_010_065_7e-24,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_002_9e-24),
-    },
-
-    Constant {
-        name: "Bohr magneton in eV/T",
-        value: 5.788_381_798_2e-5,
-        si_base_units: Some("eV T^-1"),
-        uncertainty: Some(0.000_000_001_8e-5),
-    },
-
-    Constant {
-        name: "Bohr magneton in Hz/T",
-        value: 1.399_624_491_71e10,
-        si_base_units: Some("Hz T^-1"),
-        uncertainty: Some(0.000_000_000_44e10),
-    },
-
-    Constant {
-        name: "Bohr magneton in inverse meter per tesla",
-        value: 46.686_447_719,
-        si_base_units: Some("m^-1 T^-1"),
-        uncertainty: Some(0.000_000_015),
-    },
-
-    Constant {
-        name: "Bohr magneton in K/T",
-        value: 0.671_713_814_72,
-        si_base_units: Some("K T^-1"),
-        uncertainty: Some(0.000_000_000_21),
-    },
-
-    Constant {
-        name: "Bohr radius",
-        value: 5.291_772_105_44e-11,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_000_82e-11),
-    },
-
-    Constant {
-        name: "Boltzmann constant",
-        value: 1.380_649e-23,
-        si_base_units: Some("J K^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Boltzmann constant in eV/K",
-        value: 8.617_333_262e-5,//...
-        si_base_units: Some("eV K^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Boltzmann constant in Hz/K",
-        value: 2.083_661_912e10,//...
-        si_base_units: Some("Hz K^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Boltzmann constant in meter per kelvin",
-        value: 69.503_480_04,//...
-        si_base_units: Some("m^-1 K^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "characteristic of impedance of vacuum",
-        value: 376.730_313_412,
-        si_base_units: Some("ohm"),
-        uncertainty: Some(0.000_000_059),
-    },
-
-    Constant {
-        name: "classical electron radius",
-        value: 2.817_940_320_5e-15,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_001_3e-15),
-    },
-
-    Constant {
-        name: "Compton wavelength",
-        value: 2.426_310_235_38e-12,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_000_76e-12),
-    },
-
-    Constant {
-        name: "conductance quantum",
-        value: 7.748_091_729e-5,//...
-        si_base_units: Some("S"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "conventional value of ampere-90",
-        value: 1.000_000_088_87,//...
-        si_base_units: Some("A"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "conventional value of coulomb-90",
-        value: 1.000_000_088_87,//...
-        si_base_units: Some("C"),
-        uncertainty: None,
-    },
+#[derive(Debug, Clone, Copy)]
+struct Style {
+    color: bool,
+    units: UnitStyle,
+}
 
-    Constant {
-        name: "convention value of farad-90",
-        value: 0.999_999_982_20,//...
-        si_base_units: Some("F"),
-        uncertainty: None,
-    },
+impl Style {
+    fn heading(&self, text: &str) -> String {
+        if self.color {
+            format!("\x1b[32m{}\x1b[0m", text)
+        } else {
+            text.to_string()
+        }
+    }
+}
 
-    Constant {
-        name: "conventional value of henry-90",
-        value: 1.000_000_017_79,//...
-        si_base_units: Some("H"),
-        uncertainty: None,
-    },
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    NotFound(String),
+    Failed(String),
+    Io(io::Error),
+}
 
-    Constant {
-        name: "conventional value of Josephson constant",
-        value: 483_597.9e9,
-        si_base_units: Some("Hz V^-1"),
-        uncertainty: None,
-    },
+impl From<io::Error> for CliError {
+    fn from(error: io::Error) -> Self {
+        CliError::Io(error)
+    }
+}
 
-    Constant {
-        name: "conventional value of ohm-90",
-        value: 1.000_000_017_79,//...
-        si_base_units: Some("ohm"),
-        uncertainty: None,
-    },
+impl CliError {
+    fn exit_code(&self) -> ExitCode {
+        match self {
+            CliError::Usage(_) => ExitCode::from(2),
+            CliError::NotFound(_) | CliError::Failed(_) | CliError::Io(_) => ExitCode::from(1),
+        }
+    }
+}
 
-    Constant {
-        name: "conventional value of volt-90",
-        value: 1.000_000_106_66,//...
-        si_base_units: Some("V"),
-        uncertainty: None,
-    },
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::NotFound(message) | CliError::Failed(message) => write!(f, "{}", message),
+            CliError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
 
-    Constant {
-        name: "convention value of von Klitzing constant",
-        value: 25_812.807,
-        si_base_units: Some("ohm"),
-        uncertainty: None,
-    },
+// Pulls a global option such as --color out of the arguments, wherever it appears;
+// the last occurrence wins
+fn take_global_option(args: &mut Vec<String>, option: &str) -> Result<Option<String>, CliError> {
+    Ok(take_global_options(args, option)?.pop())
+}
 
-    Constant {
-        name: "convention value of watt-90",
-        value: 1.000_000_195_53,//...
-        si_base_units: Some("W"),
-        uncertainty: None,
-    },
+// Every occurrence of a repeatable global option such as --define, in order
+fn take_global_options(args: &mut Vec<String>, option: &str) -> Result<Vec<String>, CliError> {
+    let prefix = format!("{}=", option);
+    let mut values = Vec::new();
+
+    while let Some(index) = args
+        .iter()
+        .position(|arg| arg == option || arg.starts_with(&prefix))
+    {
+        let arg = args.remove(index);
+
+        values.push(match arg.strip_prefix(&prefix) {
+            Some(value) => value.to_string(),
+            None if index < args.len() => args.remove(index),
+            None => return Err(CliError::Usage(format!("{} needs a value", option))),
+        });
+    }
 
-    Constant {
-        name: "Copper x unit",
-        value: 1.002_076_97e-13,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_28e-13),
-    },
+    Ok(values)
+}
 
-    Constant {
-        name: "deuteron-electron mag. mom. ratio",
-        value: -4.664_345_550e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_012e-4),
-    },
+// The NIST table, then every --data FILE in order, later files overriding earlier ones,
+// then every --define 'NAME=VALUE OR FORMULA', each of which may use the ones before it
+fn load_dataset(args: &mut Vec<String>) -> Result<Dataset, CliError> {
+    let mut dataset = Dataset::codata();
 
-    Constant {
-        name: "deuteron-electron mass ratio",
-        value: 367_0.482_967_655,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_063),
-    },
+    for path in take_global_options(args, "--data")? {
+        let conflicts = dataset
+            .merge_file(&path)
+            .map_err(|error| CliError::Failed(error.to_string()))?;
 
-    Constant {
-        name: "deutron g factor",
-        value: 0.857_438_233_5,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_002_2),
-    },
+        for conflict in conflicts {
+            eprintln!("warning: {}", conflict);
+        }
+    }
 
-    Constant {
-        name: "deuteron mag. mom.",
-        value: 4.330_735_087e-27,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_011e-27),
-    },
+    for definition in take_global_options(args, "--define")? {
+        let (name, definition) = definition.split_once('=').ok_or_else(|| {
+            CliError::Usage(format!(
+                "--define needs NAME=DEFINITION, not \"{}\"",
+                definition
+            ))
+        })?;
+
+        dataset
+            .define(name.trim(), definition.trim())
+            .map_err(|error| CliError::Failed(error.to_string()))?;
+    }
 
-    Constant {
-        name: "deuteron mag. mom. to Bohr magneton ratio",
-        value: 4.669_754_568e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_012e-4),
-    },
+    Ok(dataset)
+}
 
-    Constant {
-        name: "deuteron mag. mom. to nuclear magneton ratio",
-        value: 0.857_438_233_5,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_002_2),
-    },
+fn parse_color(args: &mut Vec<String>) -> Result<ColorChoice, CliError> {
+    match take_global_option(args, "--color")? {
+        Some(value) => ColorChoice::parse(&value),
+        None => Ok(ColorChoice::Auto),
+    }
+}
 
-    Constant {
-        name: "deuteron mass",
-        value: 3.343_583_776_8e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_001e-27),
-    },
+fn parse_units(args: &mut Vec<String>) -> Result<UnitStyle, CliError> {
+    match take_global_option(args, "--units")?.as_deref() {
+        None | Some("ascii") => Ok(UnitStyle::Ascii),
+        Some("unicode") => Ok(UnitStyle::Unicode),
+        Some("dot") => Ok(UnitStyle::UnicodeDot),
+        Some(other) => Err(CliError::Usage(format!(
+            "--units must be ascii, unicode or dot, not \"{}\"",
+            other
+        ))),
+    }
+}
 
-    Constant {
-        name: "deuteron mass energy equivalent",
-        value: 3.005_063_234_91e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_94),
-    },
+fn parse_args(args: &[String]) -> Result<Command, CliError> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(Command::Repl);
+    };
+
+    match command.as_str() {
+        "dump" => Ok(Command::Dump),
+        "list" => parse_list(rest).map(Command::List),
+        "get" => required_text(rest, "get needs a constant name").map(Command::Get),
+        "search" => required_text(rest, "search needs a text to look for").map(Command::Search),
+        "convert" => match rest {
+            [value, from, to] => {
+                let value = value
+                    .parse()
+                    .map_err(|_| CliError::Usage(format!("\"{}\" is not a number", value)))?;
+
+                Ok(Command::Convert {
+                    value,
+                    from: from.clone(),
+                    to: to.clone(),
+                })
+            }
+            _ => Err(CliError::Usage("convert needs VALUE FROM TO".to_string())),
+        },
+        "export" => parse_export(rest),
+        "identify" => parse_identify(rest),
+        "calc" => required_text(rest, "calc needs an expression").map(Command::Calc),
+        "budget" => parse_budget(rest),
+        "compare" => parse_compare(rest),
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        other => Err(CliError::Usage(format!("unknown command \"{}\"", other))),
+    }
+}
 
-    Constant {
-        name: "deuteron mass energy equivalent in MeV",
-        value: 187_5.612_945,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_58),
-    },
+fn parse_list(args: &[String]) -> Result<ListFilter, CliError> {
+    let mut filter = ListFilter::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exact" => filter.exact = true,
+            "--measured" => filter.measured = true,
+            "--dimensionless" => filter.dimensionless = true,
+            "--unit" => filter.unit = Some(option_value(args.next(), "--unit")?),
+            "--dimension" => {
+                let text = option_value(args.next(), "--dimension")?;
+                let dimension = Dimension::parse(&text)
+                    .map_err(|error| CliError::Usage(format!("--dimension: {}", error)))?;
+                filter.dimension = Some(dimension);
+            }
+            "--name" => filter.name = Some(option_value(args.next(), "--name")?),
+            other => {
+                return Err(CliError::Usage(format!(
+                    "unknown list option \"{}\"",
+                    other
+                )))
+            }
+        }
+    }
 
-    Constant {
-        name: "deuteron mass in u",
-        value: 2.013_553_212_544,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_015),
-    },
+    Ok(filter)
+}
 
-    Constant {
-        name: "deuteron molar mass",
-        value: 2.013_553_214_66e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_000_63e-3),
-    },
+fn parse_export(args: &[String]) -> Result<Command, CliError> {
+    let mut format = "text".to_string();
+    let mut columns = Column::ALL.to_vec();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = option_value(args.next(), "--format")?,
+            "--columns" => columns = parse_columns(&option_value(args.next(), "--columns")?)?,
+            other => match (
+                other.strip_prefix("--format="),
+                other.strip_prefix("--columns="),
+            ) {
+                (Some(value), _) => format = value.to_string(),
+                (_, Some(value)) => columns = parse_columns(value)?,
+                _ => {
+                    return Err(CliError::Usage(format!(
+                        "unknown export option \"{}\"",
+                        other
+                    )))
+                }
+            },
+        }
+    }
 
-    Constant {
-        name: "deuteron-neutron mag. mom. ratio",
-        value: -0.448_206_52,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_11),
-    },
+    Ok(Command::Export { format, columns })
+}
 
-    Constant {
-        name: "deuteron-proton mag. mom. ratio",
-        value: 0.307_012_209_3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_79),
-    },
+// Without --tolerance, the number is taken to be rounded at its last digit
+fn parse_identify(args: &[String]) -> Result<Command, CliError> {
+    let (text, tolerance) = match args {
+        [text] => (text, None),
+        [text, option, tolerance] if option == "--tolerance" => (text, Some(tolerance.as_str())),
+        [text, option] if option.starts_with("--tolerance=") => {
+            (text, option.strip_prefix("--tolerance="))
+        }
+        _ => {
+            return Err(CliError::Usage(
+                "identify needs VALUE [--tolerance RELATIVE]".to_string(),
+            ))
+        }
+    };
+
+    let not_a_number = |text: &str| CliError::Usage(format!("\"{}\" is not a number", text));
+    let decimal = Decimal::parse(text).map_err(|_| not_a_number(text))?;
+    let value = decimal.to_f64();
+    // The tolerance is relative, so zero would match everything
+    if value == 0.0 || !value.is_finite() {
+        return Err(CliError::Usage(format!(
+            "identify needs a finite, non-zero VALUE, not \"{}\"",
+            text
+        )));
+    }
 
-    Constant {
-        name: "deuteron-proton mass ratio",
-        value: 1.999_007_501_269_9,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_008_4),
-    },
+    let tolerance = match tolerance {
+        Some(tolerance) => tolerance.parse().map_err(|_| not_a_number(tolerance))?,
+        None => last_digit_tolerance(decimal),
+    };
 
-    Constant {
-        name: "deuteron relative atomic mass",
-        value: 2.015_533_212_544,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_015),
-    },
+    Ok(Command::Identify { value, tolerance })
+}
 
-    Constant {
-        name: "deuteron rms charge radius",
-        value: 2.127_78e-15,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_27e-15),
-    },
+// The options may come anywhere; everything else is the expression
+fn parse_budget(args: &[String]) -> Result<Command, CliError> {
+    let mut format = BudgetFormat::Text;
+    let mut coverage_factor = 2.0;
+    let mut expression = Vec::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let (option, value) = match arg.split_once('=') {
+            Some((option, value)) if option == "--format" || option == "--k" => {
+                (option, value.to_string())
+            }
+            _ if arg == "--format" || arg == "--k" => {
+                (arg.as_str(), option_value(args.next(), arg)?)
+            }
+            _ => {
+                expression.push(arg.clone());
+                continue;
+            }
+        };
+
+        if option == "--format" {
+            format = BudgetFormat::parse(&value).ok_or_else(|| {
+                CliError::Usage(format!(
+                    "--format must be text, markdown or csv, not \"{}\"",
+                    value
+                ))
+            })?;
+        } else {
+            coverage_factor = value
+                .parse()
+                .ok()
+                .filter(|k: &f64| *k > 0.0 && k.is_finite())
+                .ok_or_else(|| {
+                    CliError::Usage(format!("--k must be a positive number, not \"{}\"", value))
+                })?;
+        }
+    }
 
-    Constant {
-        name: "electron charge to mass quotient",
-        value: -1.758_820_008_38e11,
-        si_base_units: Some("C kg^-1"),
-        uncertainty: Some(0.000_000_000_55e11),
-    },
+    Ok(Command::Budget {
+        expression: required_text(&expression, "budget needs an expression")?,
+        format,
+        coverage_factor,
+    })
+}
 
-    Constant {
-        name: "electron-deuteron mag. mom. ratio",
-        value: -2_143.923_492_1,
-        si_base_units: None,
-        uncertainty: Some(0.000_005_6),
-    },
+fn parse_compare(args: &[String]) -> Result<Command, CliError> {
+    let mut coverage_factor = 2.0;
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--k=") {
+            Some(value) => value.to_string(),
+            None if arg == "--k" => option_value(args.next(), arg)?,
+            None => {
+                positional.push(arg.clone());
+                continue;
+            }
+        };
+        coverage_factor = value
+            .parse()
+            .map_err(|_| CliError::Usage(format!("\"{}\" is not a number", value)))?;
+    }
 
-    Constant {
-        name: "electron-deuteron mass ratio",
-        value: 2.724_437_107_629e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_047e-4),
-    },
+    let [value, uncertainty, name @ ..] = positional.as_slice() else {
+        return Err(CliError::Usage(
+            "compare needs VALUE UNCERTAINTY NAME".to_string(),
+        ));
+    };
+    let number = |text: &String| {
+        text.parse()
+            .map_err(|_| CliError::Usage(format!("\"{}\" is not a number", text)))
+    };
+
+    Ok(Command::Compare {
+        value: number(value)?,
+        uncertainty: number(uncertainty)?,
+        name: required_text(name, "compare needs a constant name")?,
+        coverage_factor,
+    })
+}
 
-    Constant {
-        name: "electron g factor",
-        value: -2.002_319_304_360_92,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_000_36),
-    },
+fn parse_columns(text: &str) -> Result<Vec<Column>, CliError> {
+    Column::parse_list(text).map_err(|error| CliError::Usage(error.to_string()))
+}
 
-    Constant {
-        name: "electron gyromag. ratio",
-        value: 1.760_859_627_84e11,
-        si_base_units: Some("s^-1 T^-1"),
-        uncertainty: Some(0.000_000_000_55e11),
-    },
+fn option_value(value: Option<&String>, option: &str) -> Result<String, CliError> {
+    value
+        .cloned()
+        .ok_or_else(|| CliError::Usage(format!("{} needs a value", option)))
+}
 
-    Constant {
-        name: "electron gyromag. ratio in MHz/T",
-        value: 28_024.951_386_1,
-        si_base_units: Some("MHz T^-1"),
-        uncertainty: Some(0.000_008_7),
-    },
+// Names contain spaces, so the remaining arguments are joined rather than requiring quotes
+fn required_text(args: &[String], message: &str) -> Result<String, CliError> {
+    match args {
+        [] => Err(CliError::Usage(message.to_string())),
+        _ => Ok(args.join(" ")),
+    }
+}
 
-    Constant {
-        name: "electron-helion mass ratio",
-        value: 1.819_543_074_649e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_053e-4),
-    },
+fn name_contains(constant: &Constant, text: &str) -> bool {
+    constant.name.to_lowercase().contains(&text.to_lowercase())
+}
 
-    Constant {
-        name: "electron mag. mom.",
-        value: -9.284_764_691_7e-24,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_002_9e-24),
-    },
+fn run(
+    command: Command,
+    dataset: &Dataset,
+    out: &mut impl Write,
+    style: Style,
+) -> Result<(), CliError> {
+    match command {
+        Command::Repl => run_repl(dataset, out, style)?,
+        Command::Dump => {
+            for constant in &dataset.constants {
+                print_block(out, constant, style)?;
+            }
+        }
+        Command::List(filter) => {
+            let constants: Vec<&Constant> = dataset
+                .constants
+                .iter()
+                .filter(|constant| filter.matches(constant))
+                .collect();
+
+            if constants.is_empty() {
+                return Err(CliError::NotFound(
+                    "no constants match the filter".to_string(),
+                ));
+            }
+
+            for constant in constants {
+                print_summary(out, constant, style)?;
+            }
+        }
+        Command::Get(name) => {
+            let constant = dataset
+                .get(&name)
+                .or_else(|| {
+                    dataset
+                        .constants
+                        .iter()
+                        .find(|constant| constant.name.eq_ignore_ascii_case(&name))
+                })
+                .ok_or_else(|| {
+                    CliError::NotFound(format!("no constant named \"{}\" (try search)", name))
+                })?;
+
+            print_record(out, constant, dataset, style)?;
+        }
+        Command::Search(text) => {
+            let constants = dataset.search(&text);
+
+            if constants.is_empty() {
+                return Err(CliError::NotFound(format!(
+                    "no constant name contains \"{}\"",
+                    text
+                )));
+            }
+
+            for constant in constants {
+                print_summary(out, constant, style)?;
+            }
+        }
+        Command::Convert { value, from, to } => {
+            let converted = convert_with_provider(dataset, value, &from, &to).map_err(|error| {
+                let units: Vec<&str> = conversion::unit_names().collect();
+                CliError::Failed(format!("{} (known units: {})", error, units.join(", ")))
+            })?;
 
-    Constant {
-        name: "electron mag. mom. anomaly",
-        value: 1.159_652_180_46e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_18e-3),
-    },
+            writeln!(out, "{:e}", converted)?;
+        }
+        Command::Export { format, columns } => match format.as_str() {
+            "text" => {
+                for constant in &dataset.constants {
+                    print_block(out, constant, style)?;
+                }
+            }
+            "json" => write!(out, "{}", dataset.to_json())?,
+            "csv" => write!(out, "{}", dataset.to_csv(&columns))?,
+            "tsv" => write!(out, "{}", dataset.to_tsv(&columns))?,
+            "sty" => write!(out, "{}", dataset.to_sty(SiunitxCommand::Qty))?,
+            "sty2" => write!(out, "{}", dataset.to_sty(SiunitxCommand::Si))?,
+            other => {
+                return Err(CliError::Usage(format!(
+                    "unsupported export format \"{}\"",
+                    other
+                )))
+            }
+        },
+        Command::Identify { value, tolerance } => {
+            let hits = dataset.identify(value, tolerance);
+
+            if hits.is_empty() {
+                return Err(CliError::NotFound(format!(
+                    "nothing within a relative {:e} of {:e} (try a larger --tolerance)",
+                    tolerance, value
+                )));
+            }
+
+            for hit in &hits {
+                print_identification(out, hit, style)?;
+            }
+        }
+        Command::Calc(expression) => {
+            let calculation = Calculator::new(dataset)
+                .evaluate(&expression)
+                .map_err(|error| CliError::Failed(error.to_string()))?;
+            let format = ConciseFormat {
+                units: style.units,
+                ..ConciseFormat::default()
+            };
+
+            writeln!(out, "{}", calculation.format(&format))?;
+        }
+        Command::Budget {
+            expression,
+            format,
+            coverage_factor,
+        } => print_budget(
+            out,
+            &Calculator::new(dataset),
+            dataset,
+            &expression,
+            format,
+            coverage_factor,
+        )?,
+        Command::Compare {
+            value,
+            uncertainty,
+            name,
+            coverage_factor,
+        } => {
+            let comparison = dataset
+                .compare(&name, value, uncertainty, coverage_factor)
+                .map_err(|error| match error {
+                    ConsistencyError::UnknownConstant(_) => {
+                        CliError::NotFound(format!("{} (try search)", error))
+                    }
+                    error => CliError::Usage(error.to_string()),
+                })?;
+
+            print_comparison(out, &comparison, style)?;
+            if !comparison.is_consistent() {
+                return Err(CliError::Failed(format!(
+                    "{:e} is not consistent with \"{}\" at k = {}",
+                    value, name, coverage_factor
+                )));
+            }
+        }
+        Command::Help => writeln!(out, "{}", USAGE)?,
+    }
 
-    Constant {
-        name: "electron mag. mom. to Bohr magneton ratio",
-        value: -1.001_159_662_180_46,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_000_18),
-    },
+    Ok(())
+}
 
-    Constant {
-        name: "electron mag. mom. to nuclear magneton ratio",
-        value: -1_838.281_971_877,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_032),
-    },
+// The library's Display output with its first line highlighted
+fn print_block(out: &mut impl Write, constant: &Constant, style: Style) -> io::Result<()> {
+    let text = constant.display_with(style.units).to_string();
 
-    Constant {
-        name: "electron mass",
-        value: 9.109_383_713_9e-31,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_002_8e-31),
-    }, 
-
-    Constant {
-        name: "electron mass energy equivalent",
-        value: 8.187_105_788e-14,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_002_6e-14),
-    },
+    match text.split_once('\n') {
+        Some((heading, rest)) => writeln!(out, "{}\n{}", style.heading(heading), rest),
+        None => writeln!(out, "{}", style.heading(&text)),
+    }
+}
 
-    Constant {
-        name: "electron mass energy equivalent in MeV",
-        value: 0.510_988_950_69,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_000_16),
-    },
+// One line per constant: name, value, uncertainty and unit separated by tabs
+fn print_summary(out: &mut impl Write, constant: &Constant, style: Style) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}\t{}\t{}\t{}",
+        style.heading(&constant.name),
+        constant.value_text(),
+        uncertainty_text(constant),
+        constant.formatted_unit(style.units).unwrap_or_default()
+    )
+}
 
-    Constant {
-        name: "electron mass in u",
-        value: 5.484_799_090_441e-4,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_097e-4),
-    },
+fn print_record(
+    out: &mut impl Write,
+    constant: &Constant,
+    dataset: &Dataset,
+    style: Style,
+) -> io::Result<()> {
+    writeln!(out, "name\t{}", constant.name)?;
+    writeln!(out, "value\t{}", constant.value_text())?;
+    writeln!(out, "uncertainty\t{}", uncertainty_text(constant))?;
+    writeln!(
+        out,
+        "concise\t{}",
+        constant.format_concise(&ConciseFormat {
+            units: style.units,
+            ..ConciseFormat::default()
+        })
+    )?;
+    writeln!(
+        out,
+        "unit\t{}",
+        constant.formatted_unit(style.units).unwrap_or_default()
+    )?;
+    writeln!(
+        out,
+        "si_unit\t{}",
+        constant
+            .si_unit()
+            .map(|unit| format_unit(&unit.to_string(), style.units))
+            .unwrap_or_default()
+    )?;
+
+    // The edition for built-in entries, otherwise the file or "user-defined"
+    match dataset.source(&constant.name) {
+        Source::Base => writeln!(out, "source\t{}", dataset.edition),
+        Source::UserDefined => writeln!(out, "source\tuser-defined"),
+        Source::File(path) => writeln!(out, "source\t{}", path),
+    }
+}
 
-    Constant {
-        name: "electron molar mass",
-        value: 5.485_799_096_2e-7,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_001_7e-7),
-    },
+// Standard uncertainties away, what the number is, its value and its unit
+fn print_identification(
+    out: &mut impl Write,
+    hit: &Identification,
+    style: Style,
+) -> io::Result<()> {
+    let distance = match hit.standard_uncertainties {
+        0.0 => "exact match".to_string(),
+        distance if distance.is_infinite() => "off exact value".to_string(),
+        distance => format!("{:.2} sigma", distance),
+    };
+
+    writeln!(
+        out,
+        "{}\t{}\t{:e}\t{}",
+        distance,
+        style.heading(&hit.description()),
+        hit.candidate,
+        hit.unit()
+            .map(|unit| format_unit(&unit, style.units))
+            .unwrap_or_default()
+    )
+}
 
-    Constant {
-        name: "electron-muon mag. mom. ratio",
-        value: 206.766_988_1,
-        si_base_units: None,
-        uncertainty: Some(0.000_004_6),
-    },
+const REPL_HELP: &str = "\
+Type an expression such as m_e*c^2 in MeV to evaluate it, or one of:
+  NAME = EXPRESSION          Store a result as a variable; ans holds the last result
+  vars                       List the variables
+  history                    List earlier lines; !N runs line N again and !! the last one
+  list, get, search, convert, identify, export, calc, budget, compare, dump
+                             As on the command line, described below
+  quit                       Leave the shell (end of input does too)
+
+Variables take precedence over constant symbols and units of the same name.";
+
+// The interactive shell: commands as on the command line, calculator expressions and
+// variables that live until it exits
+struct Repl<'a> {
+    dataset: &'a Dataset,
+    calculator: Calculator<'a>,
+    history: Vec<String>,
+    style: Style,
+}
 
-    Constant {
-        name: "electron-neutron mag. mom. ratio",
-        value: 960.920_48,
-        si_base_units: None,
-        uncertainty: Some(0.000_23),
-    },
+fn run_repl(dataset: &Dataset, out: &mut impl Write, style: Style) -> Result<(), CliError> {
+    let mut repl = Repl {
+        dataset,
+        calculator: Calculator::new(dataset),
+        history: Vec::new(),
+        style,
+    };
+
+    // Piped input gets no banner or prompts, so the output holds only results
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        writeln!(
+            out,
+            "universalconstants {} with {} values; type help for commands, quit to leave",
+            env!("CARGO_PKG_VERSION"),
+            dataset.edition
+        )?;
+    }
 
-    Constant {
-        name: "electron-neutron mass ratio",
-        value: 5.438_673_441_6e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_002_2e-4),
-    },
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        if interactive {
+            write!(out, "> ")?;
+            out.flush()?;
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+
+        match repl.handle(line?.trim(), out) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(CliError::Io(error)) => return Err(CliError::Io(error)),
+            // The usage text is one "help" away, so it is not repeated with every mistake
+            Err(
+                CliError::Usage(message) | CliError::NotFound(message) | CliError::Failed(message),
+            ) => {
+                eprintln!("error: {}", message)
+            }
+        }
+    }
 
-    Constant {
-        name: "electron-proton mag. mom ratio",
-        value: -658.210_687_89,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_19),
-    },
+    Ok(())
+}
 
-    Constant {
-        name: "electron-proton mass ratio",
-        value: 5.446_170_214_889e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_094e-4),
-    },
+impl Repl<'_> {
+    // Runs one line; false means quit
+    fn handle(&mut self, line: &str, out: &mut impl Write) -> Result<bool, CliError> {
+        if line.is_empty() {
+            return Ok(true);
+        }
 
-    Constant {
-        name: "electron relative atomic mass",
-        value: 5.486_799_090_411e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_097e-4),
-    },
+        // An expanded line is echoed, as shells do
+        let expanded = self.expand_history(line)?;
+        if expanded != line {
+            writeln!(out, "{}", expanded)?;
+        }
+        let line = expanded;
+        self.history.push(line.clone());
+
+        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
+        match words[0].as_str() {
+            "quit" | "exit" => return Ok(false),
+            "help" => writeln!(out, "{}\n\n{}", REPL_HELP, USAGE)?,
+            "vars" => {
+                for (name, quantity) in self.calculator.variables() {
+                    let calculation = Calculation {
+                        quantity: quantity.clone(),
+                        unit: None,
+                        scale: 1.0,
+                    };
+                    writeln!(out, "{} = {}", name, self.format(&calculation))?;
+                }
+            }
+            "history" => {
+                for (index, line) in self.history.iter().enumerate() {
+                    writeln!(out, "{:>4}  {}", index + 1, line)?;
+                }
+            }
+            "calc" => self.calculate(None, line["calc".len()..].trim(), out)?,
+            // With the shell's variables, so that a stored result can be broken down
+            "budget" => match parse_args(&words)? {
+                Command::Budget {
+                    expression,
+                    format,
+                    coverage_factor,
+                } => print_budget(
+                    out,
+                    &self.calculator,
+                    self.dataset,
+                    &expression,
+                    format,
+                    coverage_factor,
+                )?,
+                command => run(command, self.dataset, out, self.style)?,
+            },
+            "dump" | "list" | "get" | "search" | "convert" | "identify" | "export" | "compare" => {
+                run(parse_args(&words)?, self.dataset, out, self.style)?
+            }
+            _ => match assignment(&line) {
+                Some((name, expression)) => self.calculate(Some(name), expression, out)?,
+                None => self.calculate(None, &line, out)?,
+            },
+        }
 
-    Constant {
-        name: "electron-tau mass ratio",
-        value: 2.875_85e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_19e-4),
-    },
+        Ok(true)
+    }
 
-    Constant {
-        name: "electron to alpha particle mass ratio",
-        value: 1.370_933_554_733e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_032e-4),
-    },
+    // "!!" is the last line and "!N" line N of the history
+    fn expand_history(&self, line: &str) -> Result<String, CliError> {
+        let Some(reference) = line.strip_prefix('!') else {
+            return Ok(line.to_string());
+        };
+
+        let entry = match reference {
+            "!" => self.history.last(),
+            number => number
+                .parse::<usize>()
+                .ok()
+                .and_then(|number| self.history.get(number.checked_sub(1)?)),
+        };
+
+        entry
+            .cloned()
+            .ok_or_else(|| CliError::NotFound(format!("no line {} in the history", line)))
+    }
 
-    Constant {
-        name: "electron to shielded helion mag. mom. ratio",
-        value: 864.058_239_86,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_7),
-    },
+    fn calculate(
+        &mut self,
+        name: Option<&str>,
+        expression: &str,
+        out: &mut impl Write,
+    ) -> Result<(), CliError> {
+        let calculation = self
+            .calculator
+            .evaluate(expression)
+            .map_err(|error| CliError::Failed(error.to_string()))?;
+
+        match name {
+            Some(name) => writeln!(out, "{} = {}", name, self.format(&calculation))?,
+            None => writeln!(out, "{}", self.format(&calculation))?,
+        }
+        if let Some(name) = name {
+            self.calculator
+                .set_variable(name, calculation.quantity.clone());
+        }
+        self.calculator.set_variable("ans", calculation.quantity);
 
-    Constant {
-        name: "electron to shielded proton mag. mom. ratio",
-        value: -658.227_585_6,
-        si_base_units: None,
-        uncertainty: Some(0.000_002_7),
-    },
+        Ok(())
+    }
 
-    Constant {
-        name: "electron-triton mass ratio",
-        value: 1.819_200_062_327e-4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_068e-4),
-    },
+    fn format(&self, calculation: &Calculation) -> String {
+        calculation.format(&ConciseFormat {
+            units: self.style.units,
+            ..ConciseFormat::default()
+        })
+    }
+}
 
-    Constant {
-        name: "electron volt",
-        value: 1.602_176_634e-19,
-        si_base_units: Some("J"),
-        uncertainty: None,
-    },
+// "x = 500 nm" gives ("x", "500 nm"); the name must be an identifier other than "in"
+fn assignment(line: &str) -> Option<(&str, &str)> {
+    let (name, expression) = line.split_once('=')?;
+    let name = name.trim();
+    let mut characters = name.chars();
 
-    Constant {
-        name: "electron volt-atomic mass unit relationship",
-        value: 1.073_544_100_83e-9,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_33e-9),
-    },
+    let starts_well = characters
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_');
+    let identifier = starts_well && characters.all(|c| c.is_alphanumeric() || c == '_');
 
-    Constant {
-        name: "electron volt-hartree relationship",
-        value: 3.674_932_217_566_5e-2,
-        si_base_units: Some("E_h"),
-        uncertainty: Some(0.000_000_000_004e-2),
-    },
+    (identifier && name != "in").then_some((name, expression.trim()))
+}
 
-    Constant {
-        name: "electron volt-hertz relationship",
-        value: 2.417_989_242e14,//...
-        si_base_units: Some("Hz"),
-        uncertainty: None,
-    },
+fn print_budget(
+    out: &mut impl Write,
+    calculator: &Calculator,
+    dataset: &Dataset,
+    expression: &str,
+    format: BudgetFormat,
+    coverage_factor: f64,
+) -> Result<(), CliError> {
+    let calculation = calculator
+        .evaluate(expression)
+        .map_err(|error| CliError::Failed(error.to_string()))?;
+    let budget = UncertaintyBudget::from_calculation(expression, &calculation, dataset)
+        .with_coverage_factor(coverage_factor);
+
+    write!(out, "{}", budget.render(format))?;
+    Ok(())
+}
 
-    Constant {
-        name: "electron volt-inverse meter relationship",
-        value: 8.065_543_937e5,//...
-        si_base_units: Some("m^-1"),
-        uncertainty: None,
-    },
+fn print_comparison(out: &mut impl Write, comparison: &Comparison, style: Style) -> io::Result<()> {
+    let constant = &comparison.constant;
+
+    writeln!(out, "name\t{}", constant.name)?;
+    writeln!(out, "value\t{}", constant.value_text())?;
+    writeln!(out, "uncertainty\t{}", uncertainty_text(constant))?;
+    writeln!(
+        out,
+        "unit\t{}",
+        constant.formatted_unit(style.units).unwrap_or_default()
+    )?;
+    writeln!(out, "measured\t{:e}", comparison.measured)?;
+    writeln!(
+        out,
+        "measured_uncertainty\t{:e}",
+        comparison.measured_uncertainty
+    )?;
+    writeln!(out, "difference\t{:e}", comparison.difference)?;
+    writeln!(
+        out,
+        "combined_uncertainty\t{:e}",
+        comparison.combined_uncertainty
+    )?;
+    writeln!(out, "z_score\t{:.3}", comparison.z_score)?;
+    writeln!(out, "E_n\t{:.3}", comparison.normalized_error)?;
+    writeln!(out, "k\t{}", comparison.coverage_factor)?;
+    writeln!(out, "verdict\t{}", comparison.verdict())
+}
 
-    Constant {
-        name: "electron volt-joule relationship",
-        value: 1.602_176_634e-19,
-        si_base_units: Some("J"),
-        uncertainty: None,
-    },
+fn uncertainty_text(constant: &Constant) -> String {
+    match constant.uncertainty {
+        Some(uncertainty) => format!("{:e}", uncertainty),
+        None => "exact".to_string(),
+    }
+}
 
-    Constant {
-        name: "electron volt-kelvin relationship",
-        value: 1.160_451_812e4,//...
-        si_base_units: Some("K"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "electron volt-kilogram relationship",
-        value: 1.782_661_921e-36,//...
-        si_base_units: Some("kg"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "elementary charge",
-        value: 1.602_176_634e-19,
-        si_base_units: Some("C"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "elementary charge over h-bar",
-        value: 1.519_267_447e15,//...
-        si_base_units: Some("A J^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Faraday constant",
-        value: 96.485_332_12,//...
-        si_base_units: Some("C mol^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Fermi coupling constant",
-        value: 1.166_378_7e-5,
-        si_base_units: Some("GeV^-2"),
-        uncertainty: Some(0.000_0006e-5),
-    },
-
-    Constant {
-        name: "fine-structure constant",
-        value: 7.297_352_564_3e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_001_1e-3),
-    },
-
-    Constant {
-        name: "first radiation constant",
-        value: 3.741_771_852e-16,//...
-        si_base_units: Some("W m^2"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "first radiation constant for spectral radiance",
-        value: 1.191_042_972e-16,//...
-        si_base_units: Some("W m^2 sr^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "hartree-atomic mass unit relationship",
-        value: 2.921_262_317_97e-8,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_91e-8),
-    },
-
-    Constant {
-        name: "hartree-electron volt relationship",
-        value: 27.211_386_245_981,
-        si_base_units: Some("eV"),
-        uncertainty: Some(0.000_000_000_03),
-    },
-
-    Constant {
-        name: "Hartree energy",
-        value: 4.359_744_722_206e-18,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_004_8e-18),
-    },
-
-    Constant {
-        name: "Hartree energy in eV",
-        value: 27.211_386_245_981,
-        si_base_units: Some("eV"),
-        uncertainty: Some(0.000_000_000_03),
-    },
-
-    Constant {
-        name: "hartree-hertz relationship",
-        value: 6.579_683_920_499_9e15,
-        si_base_units: Some("Hz"),
-        uncertainty: Some(0.000_000_000_007_2e15),
-    },
-
-    Constant {
-        name: "hartree inverse meter relationship",
-        value: 2.194_746_313_631_4e7,
-        si_base_units: Some("m^-1"),
-        uncertainty: Some(0.000_000_000_002_4e7),
-    },
-
-    Constant {
-        name: "hartree-joule relationship",
-        value: 4.359_744_722_206e-18,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_004_8e-18),
-    },
-
-    Constant {
-        name: "hartree-kelvin relationship",
-        value: 3.157_750_248_039_8e5,
-        si_base_units: Some("K"),
-        uncertainty: Some(0.000_000_000_003_4),
-    },
-
-    Constant {
-        name: "hartree-kilogram relationship",
-        value: 4.850_870_209_541_9e-35,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_000_005_3),
-    },
-
-    Constant {
-        name: "helion-electron mass ratio",
-        value: 5_495.885_279_84,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_16),
-    },
-
-    Constant {
-        name: "helion g factor",
-        value: -4.255_250_699_5,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_003_4),
-    },
-
-    Constant {
-        name: "helion mag. mom.",
-        value: -1.074_617_551_98e-26,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_000_93e-26),
-    },
-
-    Constant {
-        name: "helion mag. mom. to Bohr magneton ratio",
-        value: -1.158_740_980_83e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_94e-3),
-    },
-    
-    Constant {
-        name: "helion mag. mom. to nuclear magneton ratio",
-        value: -2.127_625_349_8,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_001_7),
-    },
-
-    Constant {
-        name: "helion mass",
-        value: 5.006_412_786_2e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_001_6e-27),
-    },
-
-    Constant {
-        name: "helion to mass energy equivalent",
-        value: 4.499_539_418_5e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_001_4e10),
-    },
-
-    Constant {
-        name: "helion mass energy equivalent in MeV",
-        value: 2808.391_611_12,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_88),
-    },
-
-    Constant {
-        name: "helion mass in u",
-        value: 3.014_932_246_932,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_074),
-    },
-
-    Constant {
-        name: "helion molar mass",
-        value: 3.014_932_250_1e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_000_94e-3),
-    },
-
-    Constant {
-        name: "helion-proton mass ratio",
-        value: 2.993_152_617_552,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_07),
-    },
-
-    Constant {
-        name: "helion relative atomic mass",
-        value: 3.014_932_246_932,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_74),
-    },
-
-    Constant {
-        name: "helion shielding shift",
-        value: 5.996_702_9e-5,
-        si_base_units: None,
-        uncertainty: Some(0.000_002_3),
-    },
-
-    Constant {
-        name: "hertz-atomic mass unit relationship",
-        value: 4.439_821_659e-24,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_001_4e-24),
-    },
-
-    Constant {
-        name: "hertz-electron volt relationship",
-        value: 4.135_667_696e-15,
-        si_base_units: Some("eV"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "hertz-hartree relationship",
-        value: 1.519_829_846_057_4e-16,
-        si_base_units: Some("E_h"),
-        uncertainty: Some(0.000_000_000_001_7e-16),
-    },
-
-    Constant {
-        name: "hertz-inverse meter relationship",
-        value: 3.335_640_951e-9,
-        si_base_units: Some("m^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "hertz-joule relationship",
-        value: 6.626_070_15e-34,
-        si_base_units: Some("J"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "hertz-kelvin relationship",
-        value: 4.799_243_073e-11,//...
-        si_base_units: Some("K"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "hertz-kilogram relationship",
-        value: 7.372_497_323e51,
-        si_base_units: Some("kg"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "hyperfine transition frequency of Cs-133",
-        value: 9_192_631_770.0,
-        si_base_units: Some("Hz"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "inverse fine-structure constant",
-        value: 137.035_999_177,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_021),
-    },
-
-    Constant {
-        name: "inverse meter-atomic mass unit relationship",
-        value: 1.331_025_048_24e-15,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_41e-15),
-    },
-//... check check check
-    Constant {
-        name: "inverse meter-electron volt relationship",
-        value: 1.239_841_984e-6,//...
-        si_base_units: Some("eV"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "inverse meter-hartree relationship",
-        value: 4.556_335_252_913_2e-8,//...
-        si_base_units: Some("E_h"),
-        uncertainty: Some(0.000_000_000_005e-8),
-    },
-
-    Constant {
-        name: "inverse meter-hertz relationship",
-        value: 299_792_458.0,
-        si_base_units: Some("Hz"),
-        uncertainty:   None,
-    },
-
-    Constant {
-        name: "inverse meter-joule relationship",
-        value: 1.986_445_857e-25,//...
-        si_base_units: Some("J"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "inverse meter-kelvin relatinship",
-        value: 1.438_776_877e-2,//...
-        si_base_units: Some("K"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "inverse meter-kilogram relationship",
-        value: 2.210_219_094e-42,
-        si_base_units: Some("kg"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "inverse of conductance quantum",
-        value: 12_906.403_72,//...
-        si_base_units: Some("ohm"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Josephson constant",
-        value: 483_597.848_4e9,//...
-        si_base_units: Some("Hz V^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "joule-atomic mass unit relationship",
-        value: 6.700_535_247_1e9,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_002_1e9),
-    },
-
-    Constant {
-        name: "joule-electron volt relationship",
-        value: 6.241_509_074e18,//..
-        si_base_units: Some("eV"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "joule-hartree relationship",
-        value: 2.293_712_278_396_9e17,
-        si_base_units: Some("E_h"),
-        uncertainty: Some(0.000_000_000_002_5e17),
-    },
-
-    Constant {
-        name: "joule-hertz relationship",
-        value: 1.509_190_179e33,//..
-        si_base_units: Some("Hz"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "joule-inverse meter relationship",
-        value: 5.034_116_567e24,//...
-        si_base_units: Some("m^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "joule-kelvin relationship",
-        value: 7.242_970_516e22,//...
-        si_base_units: Some("K"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "joule-kilogram relationship",
-        value: 1.112_650_056e-17,
-        si_base_units: Some("kg"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kelvin-atomic mass unit relationship",
-        value: 9.251_087_288_4e-14,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_002_9e-14),
-    },
-
-    Constant {
-        name: "kelvin-electron volt relationship",
-        value: 8.617_333_262e-5,
-        si_base_units: Some("eV"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kelvin-hartree relationship",
-        value: 3.166_811_563_456_4e-6,
-        si_base_units: Some("E_h"),
-        uncertainty: Some(0.000_000_000_003_5),
-    },
-
-    Constant {
-        name: "kelvin-hertz relationship",
-        value: 2.083_661_912e10,//...
-        si_base_units: Some("Hz"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kelvin-inverse meter relationship",
-        value: 69.503_480_04,//...
-        si_base_units: Some("m^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kelvin-joule relationship",
-        value: 1.380_649e-23,
-        si_base_units: Some("J"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kelvin-kilogram relationship",
-        value: 1.536_179_187e-40,//...
-        si_base_units: Some("kg"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kilogram-atomic mass unit relationship",
-        value: 6.002_140_757_7e26,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_001_9e26),
-    },
-
-    Constant {
-        name: "kilogram-electron volt relationship",
-        value: 5.609_588_603e35,//...
-        si_base_units: Some("eV"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kilogram-hartree relationship",
-        value: 2.061_458_788_741_5e34,
-        si_base_units: Some("E_h"),
-        uncertainty: Some(0.000_000_000_002_2e34),
-    },
-
-    Constant {
-        name: "kilogram-hertz relationship",
-        value: 1.356_392_489e50,//...
-        si_base_units: Some("Hz"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kilogram-inverse meter relationship",
-        value: 4.524_438_335e41,//...
-        si_base_units: Some("m^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kilogram-joule relationship",
-        value: 8.987_551_787e16,//...
-        si_base_units: Some("J"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "kilogram-kelvin relationship",
-        value: 6.509_657_26e39,//...
-        si_base_units: Some("K"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "lattice parameter of silicon",
-        value: 6.431_020_511e-10,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_089e-10),
-    },
-
-    Constant {
-        name: "lattice spacing of ideal Si (220)",
-        value: 1.920_155_716_716e-10,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_032e-10),
-    },
-
-    Constant {
-        name: "Loschmidt constant (273.15 K, 100 kPa)",
-        value: 2.651_645_804e25,//...
-        si_base_units: Some("m^-3"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Loschmidt constant (273.15 K, 101.325 kPa)",
-        value: 2.686_780_111e25,//...
-        si_base_units: Some("m^-3"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "luminous efficacy",
-        value: 683.0,
-        si_base_units: Some("lm W^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "mag. flux quantum",
-        value: 2.067_833_848e-15,//...
-        si_base_units: Some("Wb"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "molar gas constant",
-        value: 8.314_462_618,//...
-        si_base_units: Some(""),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "molar mass constant",
-        value: 1.000_000_001_05e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_000_31e-3),
-    },
-
-    Constant {
-        name: "molar mass of carbon-12",
-        value: 12.000_000_012_6e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_003_7e-3),
-    },
-
-    Constant {
-        name: "molar Plank constant",
-        value: 3.990_312_712e-10,//...
-        si_base_units: Some("J Hz^-1 mol^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "molar volume ideal gas (273.15 K, 100 kPa)",
-        value: 22.710_954_64e-3,
-        si_base_units: Some("m^3 mol^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "molar volume of ideal gas (273.13 K, 101.325 kPa)",
-        value: 22.413_969_54e-3,
-        si_base_units: Some("m^3 mol^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "molar volume of silicon",
-        value: 1.205_883_199e-5,
-        si_base_units: Some("m^3 mol^-1"),
-        uncertainty: Some(0.000_000_06e-5),
-    },
-
-    Constant {
-        name: "Molybdenum x unit",
-        value: 1.002_009_52e-13,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_53e-13),
-    },
-
-    Constant {
-        name: "muon Compton wavelength",
-        value: 1.173_444_110e-14,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_026),
-    },
-
-    Constant {
-        name: "muon-electron mass ratio",
-        value: 206.768_287_7,
-        si_base_units: None,
-        uncertainty: Some(0.000_004_6),
-    },
-
-    Constant {
-        name: "muon g factor",
-        value: -2.002_331_841_23,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_82),
-    },
-
-    Constant {
-        name: "muon mag. mom.",
-        value: -4.490_448_3e-26,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_1e-26),
-    },
-
-    Constant {
-        name: "muon mag. mom. anomly",
-        value: 1.165_920_62e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_41e-3),
-    },
-
-    Constant {
-        name: "muon mag. mom. to Bohr magneton ratio",
-        value: -4.841_970_48e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_11e-3),
-    },
-
-    Constant {
-        name: "muon mag. mom. to nuclear magneton ratio",
-        value: -8.890_597_04,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_2),
-    },
-
-    Constant {
-        name: "muon mag. mom. to nuclear magneton ratio",
-        value: -8.890_597_04,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_2),
-    },
-
-    Constant {
-        name: "muon mass",
-        value: 1.883_531_627e-28,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.00_000_042e-28),
-    },
-
-    Constant {
-        name: "muon mass energy equivalent",
-        value: 1.692_833_804e-11,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_038e-11),
-    },
-
-    Constant {
-        name: "muon mass in u",
-        value: 0.113_428_925_7,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_002_5),
-    },
-
-    Constant {
-        name: "muon molar mass",
-        value: 1.134_289_258e-4,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_025e-4),
-    },
-
-    Constant {
-        name: "muon-neutron mass ratio",
-        value: 0.112_454_516_8,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_002_5),
-    },
-
-    Constant {
-        name: "muon-proton mag. mom. ratio",
-        value: -3.183_345_146,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_071),
-    },
-
-    Constant {
-        name: "muon-proton mass ratio",
-        value: 0.112_609_526_2,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_002_5),
-    },
-
-    Constant {
-        name: "muon-tau mass ratio",
-        value: 5.946_35e-2,
-        si_base_units: None,
-        uncertainty: Some(0.000_4e-2),
-    },
-
-    Constant {
-        name: "natural unit of action",
-        value: 1.054_571_817e-34,//...
-        si_base_units: Some("J s"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "natural unit of action in eV s",
-        value: 6.582_119_569e-16,
-        si_base_units: Some("eV s"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "natural unit of energy",
-        value: 8.187_105_788e-14,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_002_6e-14),
-    },
-
-    Constant {
-        name: "natural unit of energy in MeV",
-        value: 0.510_988_950_69,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_000_16),
-    },
-
-    Constant {
-        name: "natural unit of length",
-        value: 3.861_592_674_4e-13,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_001_2e-13),
-    },
-
-    Constant {
-        name: "natural unit of mass",
-        value: 9.109_383_713_9e-13,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_002_8e-31),
-    },
-
-    Constant {
-        name: "natural unit of momentum",
-        value: 2.730_924_534_46e-22,
-        si_base_units: Some("kg m s^-1"),
-        uncertainty: Some(0.000_000_000_85e-22),
-    },
-
-    Constant {
-        name: "natural unit momentum in MeV/c",
-        value: 0.510_998_950_69,
-        si_base_units: Some("MeV/c"),
-        uncertainty: Some(0.000_000_000_16),
-    },
-
-    Constant {
-        name: "natural unit of time",
-        value: 1.288_088_666_44e-21,
-        si_base_units: Some("s"),
-        uncertainty: Some(0.000_000_000_4e-21),
-    },
-
-    Constant {
-        name: "natural unit of velocity",
-        value: 299_792_458.0,
-        si_base_units: Some("m s^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "neutron Compton wavelength",
-        value: 1.319_590_903_82e-15,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_000_67e-15),
-    },
-
-    Constant {
-        name: "neutron-electron mag. mom. ratio",
-        value: 1.040_668_84e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_24e-3),
-    },
-
-    Constant {
-        name: "neutron-electron mass ratio",
-        value: 1838.683_662,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_74),
-    },
-
-    Constant {
-        name: "neutron g factor",
-        value: -3.826_085_52,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_9),
-    },
-
-    Constant {
-        name: "neutron gyromag. ratio",
-        value: 1.832_471_74e8,
-        si_base_units: Some("s^-1 T^-1"),
-        uncertainty: Some(0.000_000_43e8),
-    },
-
-    Constant {
-        name: "neutron gyromag. ratio in MHz/T",
-        value: 29.164_693_5,
-        si_base_units: Some("MHz T^-1"),
-        uncertainty: Some(0.000_006_9),
-    },
-
-    Constant {
-        name: "neutron mag. mom.",
-        value: -9.662_365_3e-27,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_002_3e-27),
-    },
-
-    Constant {
-        name: "neutron mag. mom. to Bohr magneton ratio",
-        value: -1.041_875_65e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_25e-3),
-    },
-
-    Constant {
-        name: "neutron mag. mom. to nuclear magneton ratio",
-        value: -1.913_042_76,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_45),
-    },
-
-    Constant {
-        name: "neutron mass",
-        value: 1.674_927_500_56e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_000_85e-27),
-    },
-
-    Constant {
-        name: "neutron mass energy equivalent",
-        value: 1.505_349_765_14e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_76e-10),
-    },
-
-    Constant {
-        name: "neutron mass energy equivalent in MeV",
-        value: 939.565_421_94,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_48),
-    },
-
-    Constant {
-        name: "neutron mass in u",
-        value: 1.008_664_916_06,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_4),
-    },
-
-    Constant {
-        name: "neutron molar mass",
-        value: 1.008_664_917_12e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_000_51e-3),
-    },
-
-    Constant {
-        name: "neutron-muon mass ratio",
-        value: 8.892_484_08,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_2),
-    },
-
-    Constant {
-        name: "neutron-proton mag. mom. ratio",
-        value: -0.684_979_35,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_16),
-    },
-
-    Constant {
-        name: "neutron-proton mass difference",
-        value: 2.305_574_61e-30,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_67e-30),
-    },
-
-    Constant {
-        name: "neutron-proton mass difference energy equivalent",
-        value: 2.072_147_12e-13,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_6e-13),
-    },
-
-    Constant {
-        name: "neutron-proton mass difference energy equivalent in MeV",
-        value: 1.293_332_51,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_38),
-    },
-
-    Constant {
-        name: "neutron-proton mass difference in u",
-        value: 1.388_449_48e-3,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_4e-3)
-    },
-
-    Constant {
-        name: "neutron-proton mass ratio",
-        value: 1.001_378_419_46,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_4),
-    },
-
-    Constant {
-        name: "neutron relative atomic mass",
-        value: 1.008_664_916_06,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_4),
-    },
-
-    Constant {
-        name: "neutron-tau mass ratio",
-        value: 0.528_799,
-        si_base_units: None,
-        uncertainty: Some(0.000_036),
-    },
-
-    Constant {
-        name: "neutron to shielded proton mag. mom. ratio",
-        value: -0.684_996_94,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_16),
-    },
-
-    Constant {
-        name: "Newtonian constant of gravitation",
-        value: 6.674_3e-11,
-        si_base_units: Some("m^3 kg^-1 s^-2"),
-        uncertainty: Some(0.000_15e-11),
-    },
-
-    Constant {
-        name: "Newtonian constant of gravitation over h-bar c",
-        value: 6.708_83e-39,
-        si_base_units: Some("(GeV/c^2)^2"),
-        uncertainty: Some(0.000_15e-39),
-    },
-
-    Constant {
-        name: "nuclear magneton",
-        value: 5.050_783_739_3e-27,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_001_6e-27),
-    },
-
-    Constant {
-        name: "nuclear magneton in eV/T",
-        value: 3.152_451_254_17e-8,
-        si_base_units: Some("eV T^-1"),
-        uncertainty: Some(0.000_000_000_98e-8),
-    },
-
-    Constant {
-        name: "nuclear magneton in inverse meter per tesla",
-        value: 2.542_623_410_09e-2,
-        si_base_units: Some("m^-1 T^-1"),
-        uncertainty: Some(0.000_000_000_79e-2),
-    },
-
-    Constant {
-        name: "nuclear magneton in K/T",
-        value: 3.658_267_770_6e-4,
-        si_base_units: Some("K T^-1"),
-        uncertainty: Some(0.000_000_001_1e-4),
-    },
-
-    Constant {
-        name: "nuclear magneton in MHz/T",
-        value: 7.622_593_218_8,
-        si_base_units: Some("MHz T^-1"),
-        uncertainty: Some(0.000_000_002_4),
-    },
-
-    Constant {
-        name: "Planck constant",
-        value: 6.626_070_15e-34,
-        si_base_units: Some("J Hz^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Planck constant in eV/Hz",
-        value: 4.135_667_696e-15,//...
-        si_base_units: Some("eV Hz^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Planck length",
-        value: 1.616_255e-35,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_018e-35),
-    },
-
-    Constant {
-        name: "Planck mass",
-        value: 2.176_434e-8,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_024e-8),
-    },
-
-    Constant {
-        name: "Planck mass energy equivalent in GeV",
-        value: 1.220_890e19,
-        si_base_units: Some("GeV"),
-        uncertainty: Some(0.000_014e19),
-    },
-
-    Constant {
-        name: "Planck temperature",
-        value: 1.416_784e32,
-        si_base_units: Some("K"),
-        uncertainty: Some(0.000_016e32),
-    },
-
-    Constant {
-        name: "Planck time",
-        value: 5.391_247e-44,
-        si_base_units: Some("s"),
-        uncertainty: Some(0.000_06e-44),
-    },
-
-    Constant {
-        name: "proton charge to mass quotient",
-        value: 9.578_833_143e7,
-        si_base_units: Some("C kg^-1"),
-        uncertainty: Some(0.000_000_003e7),
-    },
-
-    Constant {
-        name: "proton Compton wavelength",
-        value: 1.321_409_853_6e-15,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_000_41e-15),
-    },
-
-    Constant {
-        name: "proton-electron mass ratio",
-        value: 1836.152_673_426,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_032),
-    },
-
-    Constant {
-        name: "proton g factor",
-        value: 5.585_694_689_3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_001_6),
-    },
-
-    Constant {
-        name: "proton gyromag. ratio",
-        value: 2.675_221_870_8e8,
-        si_base_units: Some("s^-1 T^-1"),
-        uncertainty: Some(0.000_000_001_1e8),
-    },
-
-    Constant {
-        name: "proton gyromag. ratio in MHz/T",
-        value: 42.577_478_461,
-        si_base_units: Some("MHz T^-1"),
-        uncertainty: Some(0.000_000_018),
-    },
-
-    Constant {
-        name: "proton mag. mom.",
-        value: 1.410_606_795_45e-26,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_000_6e-26),
-    },
-
-    Constant {
-        name: "proton mag. mom. to Bohr magneton ratio",
-        value: 1.521_032_202_3e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_45e-3),
-    },
-
-    Constant {
-        name: "proton mag. mom. to nuclear magneton ratio",
-        value: 2.792_847_344_63,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_82),
-    },
-
-    Constant {
-        name: "proton mag. shielding correction",
-        value: 2.567_15e-5,
-        si_base_units: None,
-        uncertainty: Some(0.000_41e-5),
-    },
-
-    Constant {
-        name: "proton mass",
-        value: 1.672_621_925_95e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_000_52e-27),
-    },
-
-    Constant {
-        name: "proton mass energy equivalent",
-        value: 1.503_277_618_02e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_47),
-    },
-
-    Constant {
-        name: "proton mass energy equivalent in MeV",
-        value: 938.272_089_43,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_29),
-    },
-
-    Constant {
-        name: "proton mass in u",
-        value: 1.007_276_466_578_9,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_008_3),
-    },
-
-    Constant {
-        name: "proton molar mass",
-        value: 1.007_276_467_64e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_000_31e-3),
-    },
-
-    Constant {
-        name: "proton-muon mass ratio",
-        value: 8.880_243_38,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_2),
-    },
-
-    Constant {
-        name: "proton-neutron mag. mom. ratio",
-        value: -1.459_898_02,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_34),
-    },
-
-    Constant {
-        name: "proton-neutron mass ratio",
-        value: 0.998_623_477_97,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_4),
-    },
-
-    Constant {
-        name: "proton relative atomic mass",
-        value: 1.007_276_466_578_9,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_008_3),
-    },
-
-    Constant {
-        name: "proton rms charge radius",
-        value: 8.407_5e-16,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.006_4e-16),
-    },
-
-    Constant {
-        name: "proton-tau mass ratio",
-        value: 0.528_051,
-        si_base_units: None,
-        uncertainty: Some(0.000_036),
-    },
-
-    Constant {
-        name: "quantum of circulation",
-        value: 3.636_947_546_7e-4,
-        si_base_units: Some("m^2 s^-1"),
-        uncertainty: Some(0.000_000_001_1e-4),
-    },
-
-    Constant {
-        name: "quantum of circluation times 2",
-        value: 7.273_895_093_4e-4,
-        si_base_units: Some("m^2 s^-1"),
-        uncertainty: Some(0.000_000_002_3e-4),
-    },
-
-    Constant {
-        name: "reduced Compton wavelength",
-        value: 3.861_592_674_4e-13,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_001_2e-13),
-    },
-
-    Constant {
-        name: "reduced muon Compton wavelength",
-        value: 1.867_594_306e-15,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_042e-15),
-    },
-
-    Constant {
-        name: "reduced neutron Compton wavelength",
-        value: 2.100_194_152e-16,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_001_1e-16),
-    },
-
-    Constant {
-        name: "reduced Planck constant",
-        value: 1.054_571_817e-34,//...
-        si_base_units: Some("J s"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "reduced Planck constant in eV s",
-        value: 6.582_119_569e-16,//...
-        si_base_units: Some("eV s"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "reduced Planck constant times c in MeV fm",
-        value: 197.326_980_4,
-        si_base_units: Some("MeV fm"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "reduced proton Compton wavelength",
-        value: 2.103_089_100_51e-16,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_000_000_66e-16),
-    },
-
-    Constant {
-        name: "reduced tau Compton wavelength",
-        value: 1.110_538e-16,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_075e-16),
-    },
-
-    Constant {
-        name: "Rydberg constant",
-        value: 10_973_731.568_157,
-        si_base_units: Some("m^-1"),
-        uncertainty: Some(0.000_012),
-    },
-
-    Constant {
-        name: "Rydberg constant times c in Hz",
-        value: 3.289_841_960_25e15,
-        si_base_units: Some("Hz"),
-        uncertainty: Some(0.000_000_000_003_6e15),
-    },
-
-    Constant {
-        name: "Rydberg constant times hc in eV",
-        value: 13.605_693_122_99,
-        si_base_units: Some("eV"),
-        uncertainty: Some(0.000_000_000_015),
-    },
-
-    Constant {
-        name: "Rydberg constant times hc in J",
-        value: 2.179_872_361_103e-18,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_000_002_4e-18),
-    },
-
-    Constant {
-        name: "Sackur-Tetrode constant (1 K, 100kPa)",
-        value: -1.151_707_534_96,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_47),
-    },
-
-    Constant {
-        name: "Sackur-Tetrode constant (1 K, 101.325 kPa)",
-        value: -1.164_870_521_49,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_47),
-    },
-
-    Constant {
-        name: "second radiation constant",
-        value: 1.438_776_877e-2,//...
-        si_base_units: Some("m K"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "shielded helion gyromag. ratio",
-        value: 2.037_894_607_8e8,
-        si_base_units: Some("s^-1 T^-1"),
-        uncertainty: Some(0.000_000_001_8e8),
-    },
-
-    Constant {
-        name: "shielded helion gyromag. ratio in MHz/T",
-        value: 32.434_100_033,
-        si_base_units: Some("MHz T^-1"),
-        uncertainty: Some(0.000_000_028),
-    },
-
-    Constant {
-        name: "shielded helion mag. mom.",
-        value: -1.074_533_110_35e-26,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_000_93e-26),
-    },
-
-    Constant {
-        name: "shielded helion mag. mom to Bohr magneton ratio",
-        value: -1.158_671_494_57e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_94e-3),
-    },
-
-    Constant {
-        name: "shielded helion mag. mom. to nuclear magneton ratio",
-        value: -2.117_497_762_4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_001_7),
-    },
-
-    Constant {
-        name: "shielded helion to proton mag. mom. ratio",
-        value: -0.761_766_577_21,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_66),
-    },
-
-    Constant {
-        name: "shielded helion to shielded proton mag. mom. ratio",
-        value: -0.761_786_133_4,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_003_1),
-    },
-
-    Constant {
-        name: "shielded proton gyromag. ratio",
-        value: 2.675_153_194e8,
-        si_base_units: Some("s^-1 T^-1"),
-        uncertainty: Some(0.000_000_011e8),
-    },
-
-    Constant {
-        name: "shielded proton gyromag. ratio in MHz/T",
-        value: 42.576_385_43,
-        si_base_units: Some("MHz T^-1"),
-        uncertainty: Some(0.000_000_17),
-    },
-
-    Constant {
-        name: "shielded proton mag. mom.",
-        value: 1.410_570_583e-26,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_005_8e-26),
-    },
-
-    Constant {
-        name: "shielded proton mag. mom. to Bohr magneton ratio",
-        value: 1.520_993_155_1e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_006_2e-3),
-    },
-
-    Constant {
-        name: "shielded proton mag. mom. to nuclear magneton ratio",
-        value: 2.792_755_648,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_011),
-    },
-
-    Constant {
-        name: "shielding difference to d and p in HD",
-        value: 1.987_70e-8,
-        si_base_units: None,
-        uncertainty: Some(0.000_1e-8),
-    },
-
-    Constant {
-        name: "shielding difference of t and p in HT",
-        value: 2.394_5e-8,
-        si_base_units: None,
-        uncertainty: Some(0.000_2e-8),
-    },
-
-    Constant {
-        name: "speed of light in vacuum",
-        value: 299_792_458.0,
-        si_base_units: Some("m s^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "standard acceleration of gravity",
-        value: 9.806_65,
-        si_base_units: Some("m s^-2"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "standard atmosphere",
-        value: 101_325.0,
-        si_base_units: Some("Pa"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "standard-state pressure",
-        value: 100_000.0,
-        si_base_units: Some("Pa"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Stefan-Boltzmann constant",
-        value: 5.670_374_419e-8,
-        si_base_units: Some("W m^-2 K^-4"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "tau Compton wavelength",
-        value: 6.977_71e-16,
-        si_base_units: Some("m"),
-        uncertainty: Some(0.000_47e-16),
-    },
-
-    Constant {
-        name: "tau-electron mass ratio",
-        value: 3_477.23,
-        si_base_units: None,
-        uncertainty: Some(0.23),
-    },
-
-    Constant {
-        name: "tau energy equivalent",
-        value: 1_776.86,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.12),
-    },
-
-    Constant {
-        name: "tau mass",
-        value: 3.167_54e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_21e-27),
-    },
-
-    Constant {
-        name: "tau mass energy equivalent",
-        value: 2.846_84e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_19e-10),
-    },
-
-    Constant {
-        name: "tau mass in u",
-        value: 1.907_54,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_13),
-    },
-
-    Constant {
-        name: "tau molar mass",
-        value: 1.907_54e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_13e-3),
-    },
-
-    Constant {
-        name: "tau-muon mass ratio",
-        value: 16.817,
-        si_base_units: None,
-        uncertainty: Some(0.001_1),
-    },
-
-    Constant {
-        name: "tau-neutron mass ratio",
-        value: 1.891_15,
-        si_base_units: None,
-        uncertainty: Some(0.000_13),
-    },
-
-    Constant {
-        name: "tau-proton mass ratio",
-        value: 1.893_76,
-        si_base_units: None,
-        uncertainty: Some(0.000_13),
-    },
-
-    Constant {
-        name: "Thomas cross section",
-        value: 6.652_458_705_1e-29,
-        si_base_units: Some("m^2"),
-        uncertainty: Some(0.000_000_006_2e-29),
-    },
-
-    Constant {
-        name: "triton-electron mass ratio",
-        value: 5_469.921_535_51,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_21),
-    },
-
-    Constant {
-        name: "triton g factor",
-        value: 5.957_924_93,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_012),
-    },
-
-    Constant {
-        name: "triton mag. mom.",
-        value: 1.504_609_517_8e-26,
-        si_base_units: Some("J T^-1"),
-        uncertainty: Some(0.000_000_003e-26),
-    },
-
-    Constant {
-        name: "triton mag. mom. to Bohr magneton ratio",
-        value: 1.622_393_664_8e-3,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_003_2e-3),
-    },
-
-    Constant {
-        name: "triton mag. mom. to nuclear magneton ratio",
-        value: 2.978_962_465,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_005_9),
-    },
-
-    Constant {
-        name: "triton mass",
-        value: 5.007_356_751_2e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_001_6e-27),
-    },
-
-    Constant {
-        name: "triton mass energy equivalent",
-        value: 4.500_387_811_9e-10,
-        si_base_units: Some("J"),
-        uncertainty: Some(0.000_000_001_4e-10),
-    },
-
-    Constant {
-        name: "triton mass energy equivalent in MeV",
-        value: 2_808.921_136_68,
-        si_base_units: Some("MeV"),
-        uncertainty: Some(0.000_000_88),
-    },
-
-    Constant {
-        name: "triton mass in u",
-        value: 3.015_500_715_97,
-        si_base_units: Some("u"),
-        uncertainty: Some(0.000_000_000_1),
-    },
-    Constant {
-        name: "triton molar mass",
-        value: 3.015_500_719_13e-3,
-        si_base_units: Some("kg mol^-1"),
-        uncertainty: Some(0.000_000_000_94e-3),
-    },
-
-    Constant {
-        name: "triton-proton mass ratio",
-        value: 2.993_717_034_03,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_1),
-    },
-
-    Constant {
-        name: "triton relative atomic mass",
-        value: 3.015_500_715_97,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_000_1),
-    },
-
-    Constant {
-        name: "triton to proton mag. mom. ratio",
-        value: 1.066_639_918_9,
-        si_base_units: None,
-        uncertainty: Some(0.000_000_002_1),
-    },
-
-    Constant {
-        name: "unified atomic mass unit",
-        value: 1.660_539_068_92e-27,
-        si_base_units: Some("kg"),
-        uncertainty: Some(0.000_000_000_52e-27),
-    },
-
-    Constant {
-        name: "vacuum electricy permittivity",
-        value: 8.854_187_818_8e-12,
-        si_base_units: Some("F m^-1"),
-        uncertainty: Some(0.000_000_001_4e-12),
-    },
-
-    Constant {
-        name: "vacuum mag. permeability",
-        value: 1.256_637_061_27e-6,
-        si_base_units: Some("N A^-2"),
-        uncertainty: Some(0.000_000_000_2e-6),
-    },
-
-    Constant {
-        name: "von Klitzing constant",
-        value: 25_812.807_45,
-        si_base_units: Some("ohm"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "weak mixing angle",
-        value: 0.223_05,
-        si_base_units: None,
-        uncertainty: Some(0.000_23),
-    },
-
-    Constant {
-        name: "Wien frequency displacement law constant",
-        value: 5.878_925_757e10,
-        si_base_units: Some("Hz K^-1"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "Wien wavelength displacement law constant",
-        value: 2.897_771_955e-3,
-        si_base_units: Some("m K"),
-        uncertainty: None,
-    },
-
-    Constant {
-        name: "W to Z mass ratio",
-        value: 0.881_45,
-        si_base_units: None,
-        uncertainty: Some(0.000_13),
-    },
-
-]
-} 
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-fn main() {
-    let constants = initialize_constants();    
-    
-    for constant in &constants {
-        println!("{}", constant);
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let mut out = io::stdout().lock();
+
+    let result = parse_color(&mut args).and_then(|color| {
+        let style = Style {
+            color: color.enabled(),
+            units: parse_units(&mut args)?,
+        };
+        let dataset = load_dataset(&mut args)?;
+        parse_args(&args).and_then(|command| run(command, &dataset, &mut out, style))
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // A closed pipe (e.g. `| head`) means the reader has all it wants
+        Err(CliError::Io(error)) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            error.exit_code()
+        }
     }
-
-    //println!("{}", CONSTANTS[0].value);
-}
\ No newline at end of file
+}
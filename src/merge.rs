@@ -0,0 +1,348 @@
+// Merging more constants into a dataset at runtime, from files in NIST's allascii format,
+// CSV or TSV as written by csv.rs, or JSON as written by json.rs. Merges apply in order and
+// the later one wins: an entry with the name of an existing one replaces it in place and is
+// reported as a Conflict. Every entry remembers where it came from (Dataset::source()).
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::allascii::{parse_allascii, AllasciiError};
+use crate::csv::{read_table, CsvError, Delimiter};
+use crate::json::JsonError;
+use crate::validation::ValidationError;
+use crate::{Constant, Dataset};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // Part of the dataset as created, e.g. the built-in NIST table
+    Base,
+    // Added with Dataset::register()
+    UserDefined,
+    // Merged from this file
+    File(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Base => write!(f, "the base dataset"),
+            Source::UserDefined => write!(f, "a user definition"),
+            Source::File(path) => write!(f, "{}", path),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    Allascii,
+    Csv,
+    Tsv,
+    Json,
+}
+
+impl FileFormat {
+    // By extension: .txt (as NIST names allascii.txt), .csv, .tsv or .json
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+        match extension.as_str() {
+            "txt" => Some(FileFormat::Allascii),
+            "csv" => Some(FileFormat::Csv),
+            "tsv" => Some(FileFormat::Tsv),
+            "json" => Some(FileFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn parse(self, text: &str) -> Result<Vec<Constant>, LoadErrorKind> {
+        match self {
+            FileFormat::Allascii => parse_allascii(text).map_err(LoadErrorKind::Allascii),
+            FileFormat::Csv => read_table(text, Delimiter::Comma).map_err(LoadErrorKind::Csv),
+            FileFormat::Tsv => read_table(text, Delimiter::Tab).map_err(LoadErrorKind::Csv),
+            FileFormat::Json => Dataset::from_json(text)
+                .map(|dataset| dataset.constants)
+                .map_err(LoadErrorKind::Json),
+        }
+    }
+}
+
+// An entry that replaced an earlier one of the same name with different data
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub previous: Constant,
+    pub previous_source: Source,
+    pub replacement: Constant,
+    pub source: Source,
+}
+
+// "overrides.csv overrides "Planck constant" from the base dataset: 6.62607015e-34 J Hz^-1
+// becomes 6.6e-34 J Hz^-1"
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let describe = |constant: &Constant| match constant.unit.as_str() {
+            Some(unit) => format!("{:e} {}", constant.value, unit),
+            None => format!("{:e}", constant.value),
+        };
+
+        write!(
+            f,
+            "{} overrides \"{}\" from {}: {} becomes {}",
+            self.source,
+            self.replacement.name,
+            self.previous_source,
+            describe(&self.previous),
+            describe(&self.replacement)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadError {
+    pub path: String,
+    pub kind: LoadErrorKind,
+}
+
+#[derive(Debug)]
+pub enum LoadErrorKind {
+    Io(io::Error),
+    UnknownFormat,
+    Allascii(AllasciiError),
+    Csv(CsvError),
+    Json(JsonError),
+    Invalid(ValidationError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            LoadErrorKind::Io(error) => write!(f, "{}: {}", self.path, error),
+            LoadErrorKind::UnknownFormat => write!(
+                f,
+                "{}: unknown format; use .txt (NIST allascii), .csv, .tsv or .json",
+                self.path
+            ),
+            LoadErrorKind::Allascii(error) => write!(f, "{}: {}", self.path, error),
+            LoadErrorKind::Csv(error) => write!(f, "{}: {}", self.path, error),
+            LoadErrorKind::Json(error) => write!(f, "{}: {}", self.path, error),
+            LoadErrorKind::Invalid(error) => write!(f, "{}: {}", self.path, error),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl Dataset {
+    // Where an entry came from; Source::Base unless it was registered or merged
+    pub fn source(&self, name: &str) -> &Source {
+        self.sources
+            .iter()
+            .find(|(known, _)| known == name)
+            .map_or(&Source::Base, |(_, source)| source)
+    }
+
+    // Adds the constants, each replacing any entry of the same name; only replacements
+    // that change the value, uncertainty or unit are conflicts. Nothing is merged unless
+    // every constant passes Constant::validate().
+    pub fn merge(
+        &mut self,
+        constants: Vec<Constant>,
+        source: Source,
+    ) -> Result<Vec<Conflict>, ValidationError> {
+        for constant in &constants {
+            constant.validate()?;
+        }
+
+        let mut conflicts = Vec::new();
+        for constant in constants {
+            let previous_source = self.source(&constant.name).clone();
+            self.sources.retain(|(known, _)| *known != constant.name);
+            self.sources
+                .push((constant.name.to_string(), source.clone()));
+
+            match self
+                .constants
+                .iter_mut()
+                .find(|known| known.name == constant.name)
+            {
+                Some(known) if same_data(known, &constant) => *known = constant,
+                Some(known) => conflicts.push(Conflict {
+                    previous: std::mem::replace(known, constant.clone()),
+                    previous_source,
+                    replacement: constant,
+                    source: source.clone(),
+                }),
+                None => self.constants.push(constant),
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    // Reads and merges a file, its format chosen by FileFormat::from_path()
+    pub fn merge_file(&mut self, path: impl AsRef<Path>) -> Result<Vec<Conflict>, LoadError> {
+        let path = path.as_ref();
+        let error = |kind| LoadError {
+            path: path.display().to_string(),
+            kind,
+        };
+
+        let format =
+            FileFormat::from_path(path).ok_or_else(|| error(LoadErrorKind::UnknownFormat))?;
+        let text = fs::read_to_string(path).map_err(|e| error(LoadErrorKind::Io(e)))?;
+        let constants = format.parse(&text).map_err(error)?;
+
+        self.merge(constants, Source::File(path.display().to_string()))
+            .map_err(|e| error(LoadErrorKind::Invalid(e)))
+    }
+}
+
+// A file restating an entry unchanged, e.g. a copy of the same NIST table, is no conflict
+fn same_data(a: &Constant, b: &Constant) -> bool {
+    a.value == b.value && a.uncertainty == b.uncertainty && a.unit == b.unit
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::decimal::Decimal;
+    use crate::units::Unit;
+
+    const PLANCK: &str = "Planck constant";
+
+    fn constant(name: &str, value: f64, uncertainty: Option<f64>, unit: &str) -> Constant {
+        Constant {
+            name: Cow::Owned(name.to_string()),
+            value,
+            decimal: Decimal::from_f64(value).unwrap(),
+            unit: Unit::Published(Cow::Owned(unit.to_string())),
+            uncertainty,
+        }
+    }
+
+    fn overrides() -> Source {
+        Source::File("overrides.csv".to_string())
+    }
+
+    #[test]
+    fn changed_entries_are_conflicts() {
+        let mut dataset = Dataset::codata();
+        let count = dataset.constants.len();
+        let position = dataset.constants.iter().position(|c| c.name == PLANCK);
+
+        let conflicts = dataset
+            .merge(
+                vec![constant(PLANCK, 6.6e-34, None, "J Hz^-1")],
+                overrides(),
+            )
+            .unwrap();
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].previous.value, 6.62607015e-34);
+        assert_eq!(conflicts[0].previous_source, Source::Base);
+        assert_eq!(conflicts[0].replacement.value, 6.6e-34);
+        assert_eq!(
+            conflicts[0].to_string(),
+            "overrides.csv overrides \"Planck constant\" from the base dataset: \
+             6.62607015e-34 J Hz^-1 becomes 6.6e-34 J Hz^-1"
+        );
+
+        // Replaced in place
+        assert_eq!(dataset.constants.len(), count);
+        assert_eq!(
+            dataset.constants.iter().position(|c| c.name == PLANCK),
+            position
+        );
+        assert_eq!(dataset.get(PLANCK).unwrap().value, 6.6e-34);
+        assert_eq!(*dataset.source(PLANCK), overrides());
+    }
+
+    #[test]
+    fn uncertainty_and_unit_changes_are_conflicts() {
+        let mut dataset = Dataset::codata();
+        let uncertain = constant(PLANCK, 6.62607015e-34, Some(1e-42), "J Hz^-1");
+        let other_unit = constant(PLANCK, 6.62607015e-34, Some(1e-42), "J s");
+
+        assert_eq!(
+            dataset.merge(vec![uncertain], overrides()).unwrap().len(),
+            1
+        );
+        let conflicts = dataset
+            .merge(vec![other_unit], Source::File("units.json".to_string()))
+            .unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].previous_source, overrides());
+    }
+
+    #[test]
+    fn restated_entries_are_not_conflicts() {
+        let mut dataset = Dataset::codata();
+        let same = dataset.get(PLANCK).unwrap().clone();
+
+        assert!(dataset.merge(vec![same], overrides()).unwrap().is_empty());
+        // The later file still becomes the source
+        assert_eq!(*dataset.source(PLANCK), overrides());
+    }
+
+    #[test]
+    fn new_entries_are_appended() {
+        let mut dataset = Dataset::codata();
+        let count = dataset.constants.len();
+
+        let conflicts = dataset
+            .merge(
+                vec![constant("local g", 9.80311, Some(5e-5), "m s^-2")],
+                overrides(),
+            )
+            .unwrap();
+
+        assert!(conflicts.is_empty());
+        assert_eq!(dataset.constants.len(), count + 1);
+        assert_eq!(dataset.constants[count].name, "local g");
+        assert_eq!(*dataset.source("speed of light in vacuum"), Source::Base);
+    }
+
+    #[test]
+    fn invalid_entries_merge_nothing() {
+        let mut dataset = Dataset::codata();
+        let before = dataset.clone();
+
+        let error = dataset
+            .merge(
+                vec![
+                    constant(PLANCK, 6.6e-34, None, "J Hz^-1"),
+                    constant("bad", 1.0, Some(-1.0), "m"),
+                ],
+                overrides(),
+            )
+            .unwrap_err();
+
+        assert_eq!(error.name, "bad");
+        assert_eq!(dataset, before);
+    }
+
+    #[test]
+    fn files_by_extension() {
+        let mut dataset = Dataset::codata();
+        let unknown = dataset.merge_file("constants.xml").unwrap_err();
+        assert!(matches!(unknown.kind, LoadErrorKind::UnknownFormat));
+
+        let path = std::env::temp_dir().join(format!("merge-test-{}.csv", std::process::id()));
+        fs::write(
+            &path,
+            "name,value,uncertainty,unit\nPlanck constant,6.6e-34,,J Hz^-1\n",
+        )
+        .unwrap();
+        let conflicts = dataset.merge_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        let conflicts = conflicts.unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            *dataset.source(PLANCK),
+            Source::File(path.display().to_string())
+        );
+    }
+}
@@ -0,0 +1,234 @@
+// Conventional symbols and NIST subject categories for the constants in the table.
+// Neither is part of allascii.txt, so they are looked up by name here.
+use std::fmt;
+
+use crate::Constant;
+
+// Subject areas used by NIST to group the constants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    Universal,
+    Electromagnetic,
+    AtomicAndNuclear,
+    PhysicoChemical,
+    Adopted,
+    NonSiUnits,
+    XRay,
+    EnergyEquivalents,
+}
+
+impl Category {
+    pub const ALL: [Category; 8] = [
+        Category::Universal,
+        Category::Electromagnetic,
+        Category::AtomicAndNuclear,
+        Category::PhysicoChemical,
+        Category::Adopted,
+        Category::NonSiUnits,
+        Category::XRay,
+        Category::EnergyEquivalents,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::Universal => "universal",
+            Category::Electromagnetic => "electromagnetic",
+            Category::AtomicAndNuclear => "atomic and nuclear",
+            Category::PhysicoChemical => "physico-chemical",
+            Category::Adopted => "adopted values",
+            Category::NonSiUnits => "non-SI units",
+            Category::XRay => "X-ray values",
+            Category::EnergyEquivalents => "energy equivalents",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// ASCII symbols, usable as identifiers, for the constants that have a conventional one
+const SYMBOLS: [(&str, &str); 59] = [
+    ("alpha particle mass", "m_alpha"),
+    ("atomic mass constant", "m_u"),
+    ("Avogadro constant", "N_A"),
+    ("Bohr magneton", "mu_B"),
+    ("Bohr radius", "a_0"),
+    ("Boltzmann constant", "k_B"),
+    ("characteristic impedance of vacuum", "Z_0"),
+    ("classical electron radius", "r_e"),
+    ("Compton wavelength", "lambda_C"),
+    ("conductance quantum", "G_0"),
+    ("deuteron mass", "m_d"),
+    ("electron g factor", "g_e"),
+    ("electron gyromag. ratio", "gamma_e"),
+    ("electron mag. mom.", "mu_e"),
+    ("electron mass", "m_e"),
+    ("electron volt", "eV"),
+    ("elementary charge", "e"),
+    ("Faraday constant", "F"),
+    ("Fermi coupling constant", "G_F"),
+    ("fine-structure constant", "alpha"),
+    ("first radiation constant", "c_1"),
+    ("Hartree energy", "E_h"),
+    ("helion mass", "m_h"),
+    ("hyperfine transition frequency of Cs-133", "Delta_nu_Cs"),
+    ("Josephson constant", "K_J"),
+    ("luminous efficacy", "K_cd"),
+    ("mag. flux quantum", "Phi_0"),
+    ("molar gas constant", "R"),
+    ("molar mass constant", "M_u"),
+    ("muon mass", "m_mu"),
+    ("neutron mass", "m_n"),
+    ("Newtonian constant of gravitation", "G"),
+    ("nuclear magneton", "mu_N"),
+    ("Planck constant", "h"),
+    ("Planck length", "l_P"),
+    ("Planck mass", "m_P"),
+    ("Planck temperature", "T_P"),
+    ("Planck time", "t_P"),
+    ("proton g factor", "g_p"),
+    ("proton gyromag. ratio", "gamma_p"),
+    ("proton mag. mom.", "mu_p"),
+    ("proton mass", "m_p"),
+    ("proton rms charge radius", "r_p"),
+    ("reduced Compton wavelength", "lambdabar_C"),
+    ("reduced Planck constant", "hbar"),
+    ("Rydberg constant", "R_inf"),
+    ("second radiation constant", "c_2"),
+    ("speed of light in vacuum", "c"),
+    ("standard acceleration of gravity", "g_n"),
+    ("standard atmosphere", "atm"),
+    ("Stefan-Boltzmann constant", "sigma"),
+    ("tau mass", "m_tau"),
+    ("Thomson cross section", "sigma_e"),
+    ("triton mass", "m_t"),
+    ("unified atomic mass unit", "u"),
+    ("vacuum electric permittivity", "epsilon_0"),
+    ("vacuum mag. permeability", "mu_0"),
+    ("von Klitzing constant", "R_K"),
+    ("weak mixing angle", "sin2_theta_W"),
+];
+
+const UNIVERSAL: [&str; 16] = [
+    "characteristic impedance of vacuum",
+    "Newtonian constant of gravitation",
+    "Newtonian constant of gravitation over h-bar c",
+    "Planck constant",
+    "Planck constant in eV/Hz",
+    "Planck length",
+    "Planck mass",
+    "Planck mass energy equivalent in GeV",
+    "Planck temperature",
+    "Planck time",
+    "reduced Planck constant",
+    "reduced Planck constant in eV s",
+    "reduced Planck constant times c in MeV fm",
+    "speed of light in vacuum",
+    "vacuum electric permittivity",
+    "vacuum mag. permeability",
+];
+
+const ELECTROMAGNETIC: [&str; 13] = [
+    "Bohr magneton",
+    "Bohr magneton in eV/T",
+    "Bohr magneton in Hz/T",
+    "Bohr magneton in inverse meter per tesla",
+    "Bohr magneton in K/T",
+    "conductance quantum",
+    "elementary charge",
+    "elementary charge over h-bar",
+    "inverse of conductance quantum",
+    "Josephson constant",
+    "mag. flux quantum",
+    "nuclear magneton",
+    "von Klitzing constant",
+];
+
+const PHYSICO_CHEMICAL: [&str; 25] = [
+    "atomic mass constant",
+    "atomic mass constant energy equivalent",
+    "atomic mass constant energy equivalent in MeV",
+    "Avogadro constant",
+    "Boltzmann constant",
+    "Boltzmann constant in eV/K",
+    "Boltzmann constant in Hz/K",
+    "Boltzmann constant in inverse meter per kelvin",
+    "Faraday constant",
+    "first radiation constant",
+    "first radiation constant for spectral radiance",
+    "Loschmidt constant (273.15 K, 100 kPa)",
+    "Loschmidt constant (273.15 K, 101.325 kPa)",
+    "molar gas constant",
+    "molar mass constant",
+    "molar mass of carbon-12",
+    "molar Planck constant",
+    "molar volume of ideal gas (273.15 K, 100 kPa)",
+    "molar volume of ideal gas (273.15 K, 101.325 kPa)",
+    "Sackur-Tetrode constant (1 K, 100 kPa)",
+    "Sackur-Tetrode constant (1 K, 101.325 kPa)",
+    "second radiation constant",
+    "Stefan-Boltzmann constant",
+    "Wien frequency displacement law constant",
+    "Wien wavelength displacement law constant",
+];
+
+const NON_SI_UNITS: [&str; 2] = ["electron volt", "unified atomic mass unit"];
+
+const ADOPTED: [&str; 4] = [
+    "standard acceleration of gravity",
+    "standard atmosphere",
+    "standard-state pressure",
+    "hyperfine transition frequency of Cs-133",
+];
+
+const X_RAY: [&str; 6] = [
+    "Angstrom star",
+    "Copper x unit",
+    "lattice parameter of silicon",
+    "lattice spacing of ideal Si (220)",
+    "molar volume of silicon",
+    "Molybdenum x unit",
+];
+
+impl Constant {
+    pub fn symbol(&self) -> Option<&'static str> {
+        SYMBOLS
+            .iter()
+            .find(|(name, _)| *name == self.name)
+            .map(|(_, symbol)| *symbol)
+    }
+
+    pub fn category(&self) -> Category {
+        let name: &str = &self.name;
+        let prefixed = |prefixes: &[&str]| prefixes.iter().any(|prefix| name.starts_with(prefix));
+
+        if name.ends_with("relationship") {
+            Category::EnergyEquivalents
+        } else if ADOPTED.contains(&name) || prefixed(&["conventional value"]) {
+            Category::Adopted
+        } else if NON_SI_UNITS.contains(&name) || prefixed(&["natural unit", "atomic unit"]) {
+            Category::NonSiUnits
+        } else if X_RAY.contains(&name) {
+            Category::XRay
+        } else if UNIVERSAL.contains(&name) {
+            Category::Universal
+        } else if ELECTROMAGNETIC.contains(&name) || prefixed(&["nuclear magneton in"]) {
+            Category::Electromagnetic
+        } else if PHYSICO_CHEMICAL.contains(&name) {
+            Category::PhysicoChemical
+        } else {
+            Category::AtomicAndNuclear
+        }
+    }
+}
+
+// Finds the name of the constant a symbol such as "k_B" or "m_e" stands for
+pub fn name_for_symbol(symbol: &str) -> Option<&'static str> {
+    SYMBOLS
+        .iter()
+        .find(|(_, candidate)| *candidate == symbol)
+        .map(|(name, _)| *name)
+}
@@ -0,0 +1,191 @@
+// Natural units (h-bar = c = 1) as used in particle physics, where every quantity is
+// a power of GeV. The conversion factors are taken from the NIST table itself.
+use crate::provider::{Codata, ConstantProvider, MissingConstant};
+
+const GEV_IN_EV: f64 = 1e9;
+const MEV_IN_EV: f64 = 1e6;
+const FM_IN_M: f64 = 1e-15;
+const BARN_IN_M2: f64 = 1e-28;
+
+// The SI quantity a power of GeV stands for once h-bar and c are set to 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NaturalQuantity {
+    Energy,
+    Mass,
+    Momentum,
+    Length,
+    Time,
+    CrossSection,
+}
+
+impl NaturalQuantity {
+    // Power of GeV that carries this quantity, e.g. a length is GeV^-1
+    pub fn gev_power(self) -> i32 {
+        match self {
+            NaturalQuantity::Energy | NaturalQuantity::Mass | NaturalQuantity::Momentum => 1,
+            NaturalQuantity::Length | NaturalQuantity::Time => -1,
+            NaturalQuantity::CrossSection => -2,
+        }
+    }
+
+    pub fn si_unit(self) -> &'static str {
+        match self {
+            NaturalQuantity::Energy => "J",
+            NaturalQuantity::Mass => "kg",
+            NaturalQuantity::Momentum => "kg m s^-1",
+            NaturalQuantity::Length => "m",
+            NaturalQuantity::Time => "s",
+            NaturalQuantity::CrossSection => "m^2",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NaturalUnits {
+    pub hbar_c_mev_fm: f64,
+    pub hbar_ev_s: f64,
+    pub electron_volt: f64,
+    pub speed_of_light: f64,
+}
+
+impl NaturalUnits {
+    // Reads h-bar c, h-bar, the electron volt and c from initialize_constants(), or from
+    // another provider with from_provider()
+    pub fn from_table() -> Self {
+        NaturalUnits::from_provider(&Codata).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn from_provider(
+        provider: &(impl ConstantProvider + ?Sized),
+    ) -> Result<Self, MissingConstant> {
+        Ok(NaturalUnits {
+            hbar_c_mev_fm: provider
+                .require("reduced Planck constant times c in MeV fm")?
+                .value,
+            hbar_ev_s: provider.require("reduced Planck constant in eV s")?.value,
+            electron_volt: provider.require("electron volt")?.value,
+            speed_of_light: provider.require("speed of light in vacuum")?.value,
+        })
+    }
+
+    // SI value of one GeV raised to the power natural to `quantity`
+    pub fn si_per_natural_unit(&self, quantity: NaturalQuantity) -> f64 {
+        let gev_in_joule = GEV_IN_EV * self.electron_volt;
+        let length = self.hbar_c_mev_fm * FM_IN_M * MEV_IN_EV / GEV_IN_EV;
+
+        match quantity {
+            NaturalQuantity::Energy => gev_in_joule,
+            NaturalQuantity::Mass => gev_in_joule / (self.speed_of_light * self.speed_of_light),
+            NaturalQuantity::Momentum => gev_in_joule / self.speed_of_light,
+            NaturalQuantity::Length => length,
+            NaturalQuantity::Time => self.hbar_ev_s / GEV_IN_EV,
+            NaturalQuantity::CrossSection => length * length,
+        }
+    }
+
+    // Converts `value` given in GeV^gev_power() to the SI unit of `quantity`
+    pub fn to_si(&self, value: f64, quantity: NaturalQuantity) -> f64 {
+        value * self.si_per_natural_unit(quantity)
+    }
+
+    // Converts an SI `value` of `quantity` to GeV^gev_power()
+    pub fn from_si(&self, value: f64, quantity: NaturalQuantity) -> f64 {
+        value / self.si_per_natural_unit(quantity)
+    }
+
+    // Cross sections are quoted in barn rather than m^2
+    pub fn gev_inverse_squared_to_barn(&self, value: f64) -> f64 {
+        self.to_si(value, NaturalQuantity::CrossSection) / BARN_IN_M2
+    }
+
+    pub fn barn_to_gev_inverse_squared(&self, value: f64) -> f64 {
+        self.from_si(value * BARN_IN_M2, NaturalQuantity::CrossSection)
+    }
+
+    // Converts a value carrying one of the GeV-based units used in the table, such as
+    // "GeV", "MeV", "GeV^-2", "MeV/c" or "(GeV/c^2)^-2", to SI.
+    // Negative powers of bare energy are read as lengths (GeV^-1) and areas (GeV^-2);
+    // use to_si() with NaturalQuantity::Time for times.
+    pub fn unit_to_si(&self, value: f64, unit: &str) -> Option<(f64, String)> {
+        let unit = NaturalUnit::parse(unit)?;
+        let scale = unit.energy_scale / GEV_IN_EV;
+
+        let (quantity, power) = match (unit.per_c, unit.power) {
+            (0, -1) => (NaturalQuantity::Length, 1),
+            (0, -2) => (NaturalQuantity::CrossSection, 1),
+            (0, power) => (NaturalQuantity::Energy, power),
+            (1, power) => (NaturalQuantity::Momentum, power),
+            (2, power) => (NaturalQuantity::Mass, power),
+            _ => return None,
+        };
+
+        let natural = value * scale.powi(unit.power);
+        let si = natural * self.si_per_natural_unit(quantity).powi(power);
+        let si_unit = match power {
+            1 => quantity.si_unit().to_string(),
+            _ if quantity == NaturalQuantity::Momentum => format!("(kg m s^-1)^{}", power),
+            _ => format!("{}^{}", quantity.si_unit(), power),
+        };
+
+        Some((si, si_unit))
+    }
+}
+
+impl Default for NaturalUnits {
+    fn default() -> Self {
+        NaturalUnits::from_table()
+    }
+}
+
+// A unit of the form (XeV/c^n)^p
+struct NaturalUnit {
+    energy_scale: f64,
+    per_c: i32,
+    power: i32,
+}
+
+impl NaturalUnit {
+    fn parse(unit: &str) -> Option<Self> {
+        let unit = unit.trim();
+
+        let (inner, power) = match unit.strip_prefix('(') {
+            Some(rest) => {
+                let (inner, outer) = rest.split_once(')')?;
+                (inner, parse_power(outer)?)
+            }
+            None => match unit.split_once('^') {
+                Some((inner, power)) if !inner.contains('/') => (inner, power.parse().ok()?),
+                _ => (unit, 1),
+            },
+        };
+
+        let (energy, per_c) = match inner.split_once('/') {
+            Some((energy, "c")) => (energy, 1),
+            Some((energy, "c^2")) => (energy, 2),
+            Some(_) => return None,
+            None => (inner, 0),
+        };
+
+        let energy_scale = match energy {
+            "eV" => 1.0,
+            "keV" => 1e3,
+            "MeV" => MEV_IN_EV,
+            "GeV" => GEV_IN_EV,
+            "TeV" => 1e12,
+            _ => return None,
+        };
+
+        Some(NaturalUnit {
+            energy_scale,
+            per_c,
+            power,
+        })
+    }
+}
+
+fn parse_power(text: &str) -> Option<i32> {
+    match text {
+        "" => Some(1),
+        _ => text.strip_prefix('^')?.parse().ok(),
+    }
+}
@@ -0,0 +1,337 @@
+// Planck units derived from G, h-bar, c, k_B and epsilon_0 as listed in the NIST table.
+// G is the only input with a sizeable uncertainty, so it dominates every result.
+use std::f64::consts::PI;
+use std::ops::{Div, Mul};
+
+use crate::provider::{Codata, ConstantProvider, MissingConstant};
+use crate::Constant;
+
+// Powers of h-bar, G, c, k_B and 4 pi epsilon_0 that make up a Planck unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlanckExponents {
+    pub hbar: f64,
+    pub gravitation: f64,
+    pub light: f64,
+    pub boltzmann: f64,
+    pub permittivity: f64,
+}
+
+impl PlanckExponents {
+    pub const ONE: Self = Self::new(0.0, 0.0, 0.0, 0.0, 0.0);
+    pub const LENGTH: Self = Self::new(0.5, 0.5, -1.5, 0.0, 0.0);
+    pub const MASS: Self = Self::new(0.5, -0.5, 0.5, 0.0, 0.0);
+    pub const TIME: Self = Self::new(0.5, 0.5, -2.5, 0.0, 0.0);
+    pub const TEMPERATURE: Self = Self::new(0.5, -0.5, 2.5, -1.0, 0.0);
+    pub const CHARGE: Self = Self::new(0.5, 0.0, 0.5, 0.0, 0.5);
+    pub const ENERGY: Self = Self::new(0.5, -0.5, 2.5, 0.0, 0.0);
+    pub const MOMENTUM: Self = Self::new(0.5, -0.5, 1.5, 0.0, 0.0);
+    pub const FORCE: Self = Self::new(0.0, -1.0, 4.0, 0.0, 0.0);
+    pub const POWER: Self = Self::new(0.0, -1.0, 5.0, 0.0, 0.0);
+    pub const DENSITY: Self = Self::new(-1.0, -2.0, 5.0, 0.0, 0.0);
+
+    pub const fn new(
+        hbar: f64,
+        gravitation: f64,
+        light: f64,
+        boltzmann: f64,
+        permittivity: f64,
+    ) -> Self {
+        PlanckExponents {
+            hbar,
+            gravitation,
+            light,
+            boltzmann,
+            permittivity,
+        }
+    }
+
+    pub fn powf(self, power: f64) -> Self {
+        PlanckExponents::new(
+            self.hbar * power,
+            self.gravitation * power,
+            self.light * power,
+            self.boltzmann * power,
+            self.permittivity * power,
+        )
+    }
+
+    fn as_array(self) -> [f64; 5] {
+        [
+            self.hbar,
+            self.gravitation,
+            self.light,
+            self.boltzmann,
+            self.permittivity,
+        ]
+    }
+}
+
+// Multiplying two Planck units adds their exponents
+impl Mul for PlanckExponents {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        PlanckExponents::new(
+            self.hbar + other.hbar,
+            self.gravitation + other.gravitation,
+            self.light + other.light,
+            self.boltzmann + other.boltzmann,
+            self.permittivity + other.permittivity,
+        )
+    }
+}
+
+impl Div for PlanckExponents {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self * other.powf(-1.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlanckValue {
+    pub value: f64,
+    pub uncertainty: f64,
+}
+
+impl PlanckValue {
+    pub fn relative_uncertainty(&self) -> f64 {
+        (self.uncertainty / self.value).abs()
+    }
+}
+
+// Comparison of a derived Planck unit with the value NIST tabulates for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanckCheck {
+    pub name: &'static str,
+    pub derived: PlanckValue,
+    pub tabulated: f64,
+    pub tabulated_uncertainty: Option<f64>,
+}
+
+impl PlanckCheck {
+    // Difference between derived and tabulated value in units of the derived uncertainty
+    pub fn deviation(&self) -> f64 {
+        (self.derived.value - self.tabulated) / self.derived.uncertainty
+    }
+
+    // NIST rounds the tabulated value, so agreement within one uncertainty is expected
+    pub fn agrees(&self) -> bool {
+        self.deviation().abs() <= 1.0
+    }
+}
+
+#[derive(Debug)]
+pub struct PlanckUnits {
+    pub hbar: Constant,
+    pub gravitation: Constant,
+    pub light: Constant,
+    pub boltzmann: Constant,
+    pub permittivity: Constant,
+}
+
+impl PlanckUnits {
+    pub fn from_table() -> Self {
+        PlanckUnits::from_provider(&Codata).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn from_provider(
+        provider: &(impl ConstantProvider + ?Sized),
+    ) -> Result<Self, MissingConstant> {
+        Ok(PlanckUnits {
+            hbar: provider.require("reduced Planck constant")?.into_owned(),
+            gravitation: provider
+                .require("Newtonian constant of gravitation")?
+                .into_owned(),
+            light: provider.require("speed of light in vacuum")?.into_owned(),
+            boltzmann: provider.require("Boltzmann constant")?.into_owned(),
+            permittivity: provider
+                .require("vacuum electric permittivity")?
+                .into_owned(),
+        })
+    }
+
+    // Evaluates h-bar^a G^b c^d k_B^e (4 pi epsilon_0)^f with first-order propagation
+    // of the relative uncertainties of the inputs
+    pub fn evaluate(&self, exponents: PlanckExponents) -> PlanckValue {
+        let inputs = [
+            (self.hbar.value, self.hbar.uncertainty),
+            (self.gravitation.value, self.gravitation.uncertainty),
+            (self.light.value, self.light.uncertainty),
+            (self.boltzmann.value, self.boltzmann.uncertainty),
+            (
+                4.0 * PI * self.permittivity.value,
+                self.permittivity.uncertainty.map(|u| 4.0 * PI * u),
+            ),
+        ];
+
+        let mut value = 1.0;
+        let mut relative_variance = 0.0;
+
+        for ((base, uncertainty), exponent) in inputs.iter().zip(exponents.as_array()) {
+            value *= base.powf(exponent);
+
+            if let Some(uncertainty) = uncertainty {
+                relative_variance += (exponent * uncertainty / base).powi(2);
+            }
+        }
+
+        PlanckValue {
+            value,
+            uncertainty: value.abs() * relative_variance.sqrt(),
+        }
+    }
+
+    pub fn length(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::LENGTH)
+    }
+
+    pub fn mass(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::MASS)
+    }
+
+    pub fn time(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::TIME)
+    }
+
+    pub fn temperature(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::TEMPERATURE)
+    }
+
+    pub fn charge(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::CHARGE)
+    }
+
+    pub fn energy(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::ENERGY)
+    }
+
+    pub fn momentum(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::MOMENTUM)
+    }
+
+    pub fn force(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::FORCE)
+    }
+
+    pub fn power(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::POWER)
+    }
+
+    pub fn density(&self) -> PlanckValue {
+        self.evaluate(PlanckExponents::DENSITY)
+    }
+
+    // Compares the derived length, mass, time, temperature and energy with the table
+    pub fn check_against_table(&self) -> Vec<PlanckCheck> {
+        self.check_against_provider(&Codata)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // Like check_against_table(), against the entries of another edition or dataset
+    pub fn check_against_provider(
+        &self,
+        provider: &(impl ConstantProvider + ?Sized),
+    ) -> Result<Vec<PlanckCheck>, MissingConstant> {
+        let electron_volt = provider.require("electron volt")?.value;
+        let energy = self.energy();
+        let energy_in_gev = PlanckValue {
+            value: energy.value / (electron_volt * 1e9),
+            uncertainty: energy.uncertainty / (electron_volt * 1e9),
+        };
+
+        [
+            ("Planck length", self.length()),
+            ("Planck mass", self.mass()),
+            ("Planck time", self.time()),
+            ("Planck temperature", self.temperature()),
+            ("Planck mass energy equivalent in GeV", energy_in_gev),
+        ]
+        .into_iter()
+        .map(|(name, derived)| {
+            let tabulated = provider.require(name)?;
+
+            Ok(PlanckCheck {
+                name,
+                derived,
+                tabulated: tabulated.value,
+                tabulated_uncertainty: tabulated.uncertainty,
+            })
+        })
+        .collect()
+    }
+}
+
+impl Default for PlanckUnits {
+    fn default() -> Self {
+        PlanckUnits::from_table()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::{Perturbation, Perturbed};
+
+    #[test]
+    fn derived_units_agree_with_the_table() {
+        let checks = PlanckUnits::from_table().check_against_table();
+
+        assert_eq!(checks.len(), 5);
+        for check in &checks {
+            assert!(
+                check.agrees(),
+                "{}: derived {:e} ± {:e}, tabulated {:e}",
+                check.name,
+                check.derived.value,
+                check.derived.uncertainty,
+                check.tabulated
+            );
+        }
+    }
+
+    #[test]
+    fn uncertainty_comes_from_g() {
+        let units = PlanckUnits::from_table();
+        let relative_g = units.gravitation.uncertainty.unwrap() / units.gravitation.value;
+
+        // l_P ∝ G^1/2 and F_P ∝ G^-1
+        let length = units.length().relative_uncertainty();
+        let force = units.force().relative_uncertainty();
+        assert!(
+            (length / (relative_g / 2.0) - 1.0).abs() < 1e-3,
+            "{}",
+            length
+        );
+        assert!((force / relative_g - 1.0).abs() < 1e-3, "{}", force);
+        assert_eq!(units.evaluate(PlanckExponents::ONE).value, 1.0);
+    }
+
+    #[test]
+    fn shifted_g_is_detected() {
+        let provider = Perturbed::new(Codata).with(
+            "Newtonian constant of gravitation",
+            Perturbation::StandardUncertainties(10.0),
+        );
+        let checks = PlanckUnits::from_provider(&provider)
+            .unwrap()
+            .check_against_provider(&provider)
+            .unwrap();
+
+        assert!(checks.iter().all(|check| !check.agrees()));
+    }
+
+    #[test]
+    fn missing_inputs_are_reported() {
+        let mut dataset = Codata.to_dataset();
+        dataset
+            .constants
+            .retain(|constant| constant.name != "Planck time");
+
+        let error = PlanckUnits::from_table()
+            .check_against_provider(&dataset)
+            .unwrap_err();
+        assert_eq!(error.name, "Planck time");
+    }
+}
@@ -0,0 +1,278 @@
+// Derived constants to any number of significant digits where exact rationals are not
+// enough: h-bar = h/2π, the Stefan-Boltzmann constant, the radiation constants and the
+// Wien displacement constants. Everything is done in `Rational`, rounded to a few guard
+// digits beyond the requested precision after each step, so no external crate is needed.
+use crate::exact::{DefiningConstants, ExactCheck};
+use crate::provider::{Codata, ConstantProvider, MissingConstant};
+use crate::rational::{BigUint, Rational};
+
+// Extra digits carried through intermediate steps
+const GUARD_DIGITS: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct Evaluator {
+    pub digits: usize,
+    pub defining: DefiningConstants,
+    pi: Rational,
+}
+
+impl Evaluator {
+    // Results are correct to `digits` significant digits
+    pub fn new(digits: usize) -> Self {
+        let digits = digits.max(1);
+
+        Evaluator {
+            digits,
+            defining: DefiningConstants::si_2019(),
+            pi: machin_pi(digits + GUARD_DIGITS),
+        }
+    }
+
+    fn working(&self) -> usize {
+        self.digits + GUARD_DIGITS
+    }
+
+    // The value as text with the requested number of significant digits
+    pub fn format(&self, value: &Rational) -> String {
+        value.to_scientific_string(self.digits)
+    }
+
+    pub fn pi(&self) -> Rational {
+        self.pi.clone()
+    }
+
+    // Taylor series, with e^-x taken as 1/e^x so that all terms stay positive
+    pub fn exp(&self, x: &Rational) -> Rational {
+        if x.is_negative() {
+            return self.exp(&x.abs()).recip().rounded(self.working());
+        }
+
+        let threshold = Rational::from(10).powi(-(self.working() as i32));
+        let mut sum = Rational::one();
+        let mut term = Rational::one();
+        let mut index = 1;
+
+        loop {
+            term = term
+                .unreduced_mul(x)
+                .unreduced_div(&Rational::from(index))
+                .rounded(self.working());
+            sum = sum.unreduced_add(&term).rounded(self.working());
+            if term < sum.unreduced_mul(&threshold) {
+                return sum.rounded(self.working());
+            }
+            index += 1;
+        }
+    }
+
+    // The positive root of x = n(1 - e^-x), by Newton's method; n = 5 gives the
+    // wavelength form and n = 3 the frequency form of Wien's law
+    pub fn wien_root(&self, n: i64) -> Rational {
+        let n = Rational::from(n);
+        // Steps never shrink below the rounding noise of the working precision
+        let threshold = Rational::from(10).powi(-((self.digits + GUARD_DIGITS / 2) as i32));
+        let mut x = n.clone();
+
+        for _ in 0..200 {
+            let decay = self.exp(&-x.clone());
+            let f = x.unreduced_sub(&n.unreduced_mul(&Rational::one().unreduced_sub(&decay)));
+            let slope = Rational::one().unreduced_sub(&n.unreduced_mul(&decay));
+            let step = f.unreduced_div(&slope).rounded(self.working());
+
+            x = x.unreduced_sub(&step).rounded(self.working());
+            if step.abs() < x.unreduced_mul(&threshold) {
+                break;
+            }
+        }
+
+        x
+    }
+
+    // h-bar = h/2π in J s
+    pub fn reduced_planck(&self) -> Rational {
+        let d = &self.defining;
+        self.round(d.planck.clone() / (Rational::from(2) * self.pi()))
+    }
+
+    // c_1 = 2πhc² in W m^2
+    pub fn first_radiation_constant(&self) -> Rational {
+        let d = &self.defining;
+        self.round(Rational::from(2) * self.pi() * d.planck.clone() * d.speed_of_light.powi(2))
+    }
+
+    // σ = 2π⁵k⁴/(15h³c²) in W m^-2 K^-4
+    pub fn stefan_boltzmann(&self) -> Rational {
+        let d = &self.defining;
+        self.round(
+            Rational::from(2) * self.pi().powi(5) * d.boltzmann.powi(4)
+                / (Rational::from(15) * d.planck.powi(3) * d.speed_of_light.powi(2)),
+        )
+    }
+
+    // b = hc/(k x₅) in m K
+    pub fn wien_wavelength(&self) -> Rational {
+        let d = &self.defining;
+        self.round(
+            d.planck.clone() * d.speed_of_light.clone() / (d.boltzmann.clone() * self.wien_root(5)),
+        )
+    }
+
+    // b' = x₃ k/h in Hz K^-1
+    pub fn wien_frequency(&self) -> Rational {
+        let d = &self.defining;
+        self.round(self.wien_root(3) * d.boltzmann.clone() / d.planck.clone())
+    }
+
+    // Any exact table entry: the rational ones from the defining constants, the rest here
+    pub fn evaluate(&self, name: &str) -> Option<Rational> {
+        let d = &self.defining;
+        let hbar = || self.reduced_planck();
+        let mega_electron_volt_femtometre =
+            || d.elementary_charge.clone() * Rational::from(10).powi(6 - 15);
+
+        let value = match name {
+            "atomic unit of action" | "natural unit of action" | "reduced Planck constant" => {
+                hbar()
+            }
+            "natural unit of action in eV s" | "reduced Planck constant in eV s" => {
+                hbar() / d.elementary_charge.clone()
+            }
+            "reduced Planck constant times c in MeV fm" => {
+                hbar() * d.speed_of_light.clone() / mega_electron_volt_femtometre()
+            }
+            "elementary charge over h-bar" => d.elementary_charge.clone() / hbar(),
+            "first radiation constant" => self.first_radiation_constant(),
+            "Stefan-Boltzmann constant" => self.stefan_boltzmann(),
+            "Wien wavelength displacement law constant" => self.wien_wavelength(),
+            "Wien frequency displacement law constant" => self.wien_frequency(),
+            name => return d.exact_value(name),
+        };
+
+        Some(self.round(value))
+    }
+
+    // The entries above that the rational check in `exact` cannot cover
+    pub fn check_against_table(&self) -> Vec<ExactCheck> {
+        self.check_against_provider(&Codata)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // Like check_against_table(), against the entries of another edition or dataset
+    pub fn check_against_provider(
+        &self,
+        provider: &(impl ConstantProvider + ?Sized),
+    ) -> Result<Vec<ExactCheck>, MissingConstant> {
+        [
+            "atomic unit of action",
+            "natural unit of action",
+            "natural unit of action in eV s",
+            "reduced Planck constant",
+            "reduced Planck constant in eV s",
+            "reduced Planck constant times c in MeV fm",
+            "elementary charge over h-bar",
+            "first radiation constant",
+            "Stefan-Boltzmann constant",
+            "Wien wavelength displacement law constant",
+            "Wien frequency displacement law constant",
+        ]
+        .into_iter()
+        .filter_map(|name| {
+            let exact = self.evaluate(name)?;
+
+            Some(provider.require(name).map(|tabulated| ExactCheck {
+                name,
+                exact,
+                tabulated: tabulated.published_decimal(),
+                tabulated_value: tabulated.value,
+            }))
+        })
+        .collect()
+    }
+
+    fn round(&self, value: Rational) -> Rational {
+        value.rounded(self.working())
+    }
+}
+
+// π = 16 atan(1/5) - 4 atan(1/239), summed in fixed point with `digits` decimals
+fn machin_pi(digits: usize) -> Rational {
+    let scale = BigUint::power_of_ten(digits as u32);
+    let (fifth_positive, fifth_negative) = atan_inverse(5, &scale);
+    let (far_positive, far_negative) = atan_inverse(239, &scale);
+
+    let sixteen = Rational::from(16);
+    let four = Rational::from(4);
+    let fixed = |number: BigUint| Rational::unreduced(false, number, scale.clone());
+
+    let fifth = fixed(fifth_positive).unreduced_sub(&fixed(fifth_negative));
+    let far = fixed(far_positive).unreduced_sub(&fixed(far_negative));
+    let pi = sixteen
+        .unreduced_mul(&fifth)
+        .unreduced_sub(&four.unreduced_mul(&far));
+    pi.rounded(digits)
+}
+
+// atan(1/n) × scale as the sums of its positive and negative terms, since BigUint has no sign
+fn atan_inverse(n: u64, scale: &BigUint) -> (BigUint, BigUint) {
+    let n = BigUint::from_u64(n);
+    let n_squared = &n * &n;
+    let (mut power, _) = scale.div_rem(&n);
+    let mut positive = BigUint::zero();
+    let mut negative = BigUint::zero();
+    let mut index = 0u64;
+
+    while !power.is_zero() {
+        let (term, _) = power.div_rem(&BigUint::from_u64(2 * index + 1));
+        if index.is_multiple_of(2) {
+            positive = &positive + &term;
+        } else {
+            negative = &negative + &term;
+        }
+
+        (power, _) = power.div_rem(&n_squared);
+        index += 1;
+    }
+
+    (positive, negative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derived_entries_match_the_table() {
+        let checks = Evaluator::new(30).check_against_table();
+
+        assert_eq!(checks.len(), 11);
+        for check in &checks {
+            assert!(check.agrees(), "{}: {}", check.name, check.rounded());
+        }
+    }
+
+    #[test]
+    fn known_digits() {
+        let evaluator = Evaluator::new(50);
+
+        assert_eq!(
+            evaluator.format(&evaluator.pi()),
+            "3.1415926535897932384626433832795028841971693993751e0"
+        );
+        assert_eq!(
+            evaluator.format(&evaluator.exp(&Rational::one())),
+            "2.7182818284590452353602874713526624977572470937000e0"
+        );
+        assert_eq!(
+            evaluator.format(&evaluator.wien_root(5)),
+            "4.9651142317442763036987591313228939440555849867973e0"
+        );
+    }
+
+    #[test]
+    fn negative_exponents() {
+        let evaluator = Evaluator::new(20);
+        let product = evaluator.exp(&Rational::from(3)) * evaluator.exp(&Rational::from(-3));
+
+        assert_eq!(evaluator.format(&product), "1.0000000000000000000e0");
+    }
+}
@@ -0,0 +1,277 @@
+// Where code that needs constants gets them from. Code that takes a ConstantProvider instead
+// of reading initialize_constants() can be handed the built-in table (Codata), an edited or
+// older-edition Dataset (e.g. loaded with merge_file() or from_json()), or a Perturbed view
+// that shifts chosen entries, say G by one standard uncertainty, in a sensitivity test.
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::{Constant, Dataset};
+
+pub trait ConstantProvider {
+    // The constant of this exact name, as listed by NIST
+    fn constant(&self, name: &str) -> Option<Cow<'_, Constant>>;
+
+    // Names of every constant provided, in order
+    fn names(&self) -> Vec<&str>;
+
+    fn edition(&self) -> &str;
+
+    fn value(&self, name: &str) -> Option<f64> {
+        self.constant(name).map(|constant| constant.value)
+    }
+
+    // Like constant(), for inputs the caller cannot do without
+    fn require(&self, name: &str) -> Result<Cow<'_, Constant>, MissingConstant> {
+        self.constant(name).ok_or_else(|| MissingConstant {
+            name: name.to_string(),
+            edition: self.edition().to_string(),
+        })
+    }
+
+    // Every constant as a Dataset, for the parts of the library that work on one
+    // (Calculator, identify(), the exports)
+    fn to_dataset(&self) -> Dataset {
+        Dataset {
+            edition: Cow::Owned(self.edition().to_string()),
+            constants: self
+                .names()
+                .into_iter()
+                .filter_map(|name| self.constant(name))
+                .map(Cow::into_owned)
+                .collect(),
+            sources: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingConstant {
+    pub name: String,
+    pub edition: String,
+}
+
+impl fmt::Display for MissingConstant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{}\" is missing from {}", self.name, self.edition)
+    }
+}
+
+impl std::error::Error for MissingConstant {}
+
+impl ConstantProvider for Dataset {
+    fn constant(&self, name: &str) -> Option<Cow<'_, Constant>> {
+        self.get(name).map(Cow::Borrowed)
+    }
+
+    fn names(&self) -> Vec<&str> {
+        self.constants
+            .iter()
+            .map(|constant| constant.name.as_ref())
+            .collect()
+    }
+
+    fn edition(&self) -> &str {
+        &self.edition
+    }
+
+    fn to_dataset(&self) -> Dataset {
+        self.clone()
+    }
+}
+
+impl<P: ConstantProvider + ?Sized> ConstantProvider for &P {
+    fn constant(&self, name: &str) -> Option<Cow<'_, Constant>> {
+        (**self).constant(name)
+    }
+
+    fn names(&self) -> Vec<&str> {
+        (**self).names()
+    }
+
+    fn edition(&self) -> &str {
+        (**self).edition()
+    }
+
+    fn to_dataset(&self) -> Dataset {
+        (**self).to_dataset()
+    }
+}
+
+// The built-in NIST table, built once and shared; the provider to use outside tests
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Codata;
+
+impl Codata {
+    pub fn dataset() -> &'static Dataset {
+        static DATASET: OnceLock<Dataset> = OnceLock::new();
+        DATASET.get_or_init(Dataset::codata)
+    }
+}
+
+impl ConstantProvider for Codata {
+    fn constant(&self, name: &str) -> Option<Cow<'_, Constant>> {
+        Codata::dataset().constant(name)
+    }
+
+    fn names(&self) -> Vec<&str> {
+        Codata::dataset().names()
+    }
+
+    fn edition(&self) -> &str {
+        Codata::dataset().edition()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Perturbation {
+    // Replaces the value
+    Value(f64),
+    // Adds to the value
+    Offset(f64),
+    // Multiplies the value
+    Factor(f64),
+    // Adds this many standard uncertainties; exact constants are left as they are
+    StandardUncertainties(f64),
+}
+
+impl Perturbation {
+    pub fn apply(self, constant: &Constant) -> f64 {
+        match self {
+            Perturbation::Value(value) => value,
+            Perturbation::Offset(offset) => constant.value + offset,
+            Perturbation::Factor(factor) => constant.value * factor,
+            Perturbation::StandardUncertainties(count) => {
+                constant.value + count * constant.uncertainty.unwrap_or(0.0)
+            }
+        }
+    }
+}
+
+// Another provider with some values changed, e.g. G raised by one standard uncertainty:
+//     Perturbed::new(Codata)
+//         .with("Newtonian constant of gravitation", Perturbation::StandardUncertainties(1.0))
+// Uncertainties and units stay as the inner provider has them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Perturbed<P> {
+    pub inner: P,
+    pub perturbations: Vec<(String, Perturbation)>,
+}
+
+impl<P: ConstantProvider> Perturbed<P> {
+    pub fn new(inner: P) -> Self {
+        Perturbed {
+            inner,
+            perturbations: Vec::new(),
+        }
+    }
+
+    // Perturbations of the same constant apply in the order they were added
+    pub fn with(mut self, name: &str, perturbation: Perturbation) -> Self {
+        self.perturbations.push((name.to_string(), perturbation));
+        self
+    }
+}
+
+impl<P: ConstantProvider> ConstantProvider for Perturbed<P> {
+    fn constant(&self, name: &str) -> Option<Cow<'_, Constant>> {
+        let mut constant = self.inner.constant(name)?;
+
+        for (_, perturbation) in self.perturbations.iter().filter(|(known, _)| known == name) {
+            let value = perturbation.apply(&constant);
+            let entry = constant.to_mut();
+            entry.value = value;
+            // An infinite or NaN value keeps the old digits, which published_decimal() then ignores
+            if let Some(decimal) = entry.published_decimal() {
+                entry.decimal = decimal;
+            }
+        }
+
+        Some(constant)
+    }
+
+    fn names(&self) -> Vec<&str> {
+        self.inner.names()
+    }
+
+    fn edition(&self) -> &str {
+        self.inner.edition()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::Decimal;
+
+    const G: &str = "Newtonian constant of gravitation";
+    const C: &str = "speed of light in vacuum";
+
+    #[test]
+    fn each_perturbation_changes_the_value() {
+        let value = |perturbation| {
+            Perturbed::new(Codata)
+                .with(G, perturbation)
+                .value(G)
+                .unwrap()
+        };
+
+        assert_eq!(value(Perturbation::Value(1.0)), 1.0);
+        assert_eq!(value(Perturbation::Offset(1e-15)), 6.6743e-11 + 1e-15);
+        assert_eq!(value(Perturbation::Factor(2.0)), 2.0 * 6.6743e-11);
+        assert_eq!(
+            value(Perturbation::StandardUncertainties(-2.0)),
+            6.6743e-11 - 2.0 * 0.00015e-11
+        );
+
+        // Exact constants have no standard uncertainty to shift by
+        let c = Perturbed::new(Codata).with(C, Perturbation::StandardUncertainties(3.0));
+        assert_eq!(c.value(C), Some(299_792_458.0));
+    }
+
+    #[test]
+    fn perturbations_apply_in_order_to_their_constant_only() {
+        let perturbed = Perturbed::new(Codata)
+            .with(G, Perturbation::Offset(1e-11))
+            .with(C, Perturbation::Factor(0.5))
+            .with(G, Perturbation::Factor(2.0));
+
+        assert_eq!(perturbed.value(G), Some((6.6743e-11 + 1e-11) * 2.0));
+        assert_eq!(perturbed.value(C), Some(149_896_229.0));
+        assert_eq!(
+            perturbed.constant("Planck constant"),
+            Codata.constant("Planck constant")
+        );
+        assert_eq!(perturbed.value("no such constant"), None);
+        assert!(perturbed.require("no such constant").is_err());
+    }
+
+    #[test]
+    fn perturbed_entries_keep_uncertainty_and_unit_but_not_digits() {
+        let perturbed = Perturbed::new(Codata).with(G, Perturbation::Value(6.6744e-11));
+        let g = perturbed.require(G).unwrap();
+        let original = Codata.require(G).unwrap();
+
+        assert_eq!(g.decimal, Decimal::new(66744, -15));
+        assert_eq!(g.uncertainty, original.uncertainty);
+        assert_eq!(g.unit, original.unit);
+
+        // No digits for an infinite value: the old ones stay but are not printed
+        let infinite = Perturbed::new(Codata).with(G, Perturbation::Factor(f64::INFINITY));
+        let g = infinite.require(G).unwrap();
+        assert_eq!(g.decimal, original.decimal);
+        assert_eq!(g.value_text(), "inf");
+    }
+
+    #[test]
+    fn names_edition_and_dataset_come_from_the_inner_provider() {
+        let perturbed = Perturbed::new(Codata).with(G, Perturbation::Factor(2.0));
+
+        assert_eq!(perturbed.names(), Codata.names());
+        assert_eq!(perturbed.edition(), crate::CODATA_EDITION);
+
+        let dataset = perturbed.to_dataset();
+        assert_eq!(dataset.constants.len(), Codata.names().len());
+        assert_eq!(dataset.get(G).unwrap().value, 2.0 * 6.6743e-11);
+    }
+}
@@ -0,0 +1,794 @@
+// Arbitrary-size unsigned integers and exact fractions built on them, enough to carry
+// the SI defining constants through products and quotients without any rounding.
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::decimal::Decimal;
+
+// Little-endian base 2^32 limbs with no trailing zero limbs, so zero is an empty vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        BigUint::from_u64(1)
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        }
+        .normalized()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    // Number of significant bits; zero has none
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = BigUint::one();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    pub fn power_of_ten(exponent: u32) -> Self {
+        BigUint::from_u64(10).pow(exponent)
+    }
+
+    // Quotient and remainder; panics when dividing by zero like the primitive integers do
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "attempt to divide by zero");
+
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            let (quotient, remainder) = self.div_rem_small(divisor.limbs[0]);
+            return (quotient, BigUint::from_u64(remainder as u64));
+        }
+
+        self.div_rem_long(divisor)
+    }
+
+    // Knuth's algorithm D (TAOCP vol. 2, 4.3.1): both numbers are shifted until the top bit
+    // of the divisor is set, then each quotient limb is estimated from the top two limbs of
+    // the remainder and corrected at most twice, with a rare add-back if still one too big.
+    // The divisor has at least two limbs and is not greater than self.
+    fn div_rem_long(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        let shift = divisor.limbs[divisor.limbs.len() - 1].leading_zeros();
+        let divisor = divisor.shifted_left(shift as u64).limbs;
+        let mut remainder = self.shifted_left(shift as u64).limbs;
+        remainder.resize(self.limbs.len() + 1, 0);
+
+        let n = divisor.len();
+        let (top, next) = (divisor[n - 1] as u64, divisor[n - 2] as u64);
+        let mut quotient = vec![0u32; self.limbs.len() - n + 1];
+
+        for j in (0..quotient.len()).rev() {
+            let leading = ((remainder[j + n] as u64) << 32) | remainder[j + n - 1] as u64;
+            let mut estimate = leading / top;
+            let mut rest = leading % top;
+            while estimate > u32::MAX as u64
+                || estimate * next > ((rest << 32) | remainder[j + n - 2] as u64)
+            {
+                estimate -= 1;
+                rest += top;
+                if rest > u32::MAX as u64 {
+                    break;
+                }
+            }
+
+            // remainder[j..=j + n] -= estimate × divisor
+            let mut carry = 0u64;
+            let mut borrow = 0i64;
+            for (i, limb) in divisor.iter().enumerate() {
+                let product = estimate * *limb as u64 + carry;
+                carry = product >> 32;
+                let difference = remainder[i + j] as i64 - (product & 0xffff_ffff) as i64 + borrow;
+                remainder[i + j] = difference as u32;
+                borrow = difference >> 32;
+            }
+            let difference = remainder[j + n] as i64 - carry as i64 + borrow;
+            remainder[j + n] = difference as u32;
+
+            // The estimate was one too big: add the divisor back once
+            if difference < 0 {
+                estimate -= 1;
+                let mut carry = 0u64;
+                for (i, limb) in divisor.iter().enumerate() {
+                    let sum = remainder[i + j] as u64 + *limb as u64 + carry;
+                    remainder[i + j] = sum as u32;
+                    carry = sum >> 32;
+                }
+                remainder[j + n] = remainder[j + n].wrapping_add(carry as u32);
+            }
+
+            quotient[j] = estimate as u32;
+        }
+
+        remainder.truncate(n);
+        (
+            BigUint { limbs: quotient }.normalized(),
+            BigUint { limbs: remainder }
+                .normalized()
+                .shifted_right(shift),
+        )
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut remainder = 0u64;
+
+        for (index, limb) in self.limbs.iter().enumerate().rev() {
+            let current = (remainder << 32) | *limb as u64;
+            quotient[index] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+
+        (BigUint { limbs: quotient }.normalized(), remainder as u32)
+    }
+
+    fn shifted_left(&self, bits: u64) -> Self {
+        let (limbs, bits) = ((bits / 32) as usize, (bits % 32) as u32);
+        let mut shifted = vec![0u32; limbs];
+        let mut carry = 0u32;
+
+        for limb in &self.limbs {
+            shifted.push((limb << bits) | carry);
+            carry = if bits == 0 { 0 } else { limb >> (32 - bits) };
+        }
+        shifted.push(carry);
+
+        BigUint { limbs: shifted }.normalized()
+    }
+
+    // By fewer than 32 bits
+    fn shifted_right(mut self, bits: u32) -> Self {
+        if bits == 0 {
+            return self;
+        }
+        for index in 0..self.limbs.len() {
+            let high = self
+                .limbs
+                .get(index + 1)
+                .map_or(0, |limb| limb << (32 - bits));
+            self.limbs[index] = (self.limbs[index] >> bits) | high;
+        }
+        self.normalized()
+    }
+
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, remainder) = a.div_rem(&b);
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    // Decimal digits, e.g. "602214076000000000000000"
+    pub fn parse(text: &str) -> Option<Self> {
+        if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let ten = BigUint::from_u64(10);
+        Some(text.chars().fold(BigUint::zero(), |number, digit| {
+            &(&number * &ten) + &BigUint::from_u64(digit.to_digit(10).unwrap_or(0) as u64)
+        }))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0u64;
+
+        for index in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = self.limbs.get(index).copied().unwrap_or(0) as u64
+                + other.limbs.get(index).copied().unwrap_or(0) as u64
+                + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+
+        BigUint { limbs }.normalized()
+    }
+}
+
+// Panics on underflow, as unsigned subtraction does in debug builds
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        assert!(*self >= *other, "attempt to subtract with overflow");
+
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+
+        for index in 0..self.limbs.len() {
+            let mut difference = self.limbs[index] as i64
+                - other.limbs.get(index).copied().unwrap_or(0) as i64
+                - borrow;
+            borrow = (difference < 0) as i64;
+            if difference < 0 {
+                difference += 1 << 32;
+            }
+            limbs.push(difference as u32);
+        }
+
+        BigUint { limbs }.normalized()
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, b) in other.limbs.iter().enumerate() {
+                let product = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+
+        BigUint { limbs }.normalized()
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // Nine decimal digits at a time, least significant chunk first
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem_small(1_000_000_000);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+
+        let mut text = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            text.push_str(&format!("{:09}", chunk));
+        }
+        f.pad(&text)
+    }
+}
+
+// A fraction kept in lowest terms with a positive denominator; zero is never negative
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    negative: bool,
+    numerator: BigUint,
+    denominator: BigUint,
+}
+
+impl Rational {
+    pub fn new(negative: bool, numerator: BigUint, denominator: BigUint) -> Self {
+        assert!(!denominator.is_zero(), "rational with zero denominator");
+
+        let divisor = numerator.gcd(&denominator);
+        let (numerator, _) = numerator.div_rem(&divisor);
+        let (denominator, _) = denominator.div_rem(&divisor);
+
+        Rational {
+            negative: negative && !numerator.is_zero(),
+            numerator,
+            denominator,
+        }
+    }
+
+    // Sums, products and quotients that are rounded straight away skip the gcd, which costs
+    // more than the operation itself once the numbers are long; rounded(), comparisons and
+    // the digit methods work on any fraction, so only these intermediates stay unreduced
+    pub(crate) fn unreduced(negative: bool, numerator: BigUint, denominator: BigUint) -> Self {
+        assert!(!denominator.is_zero(), "rational with zero denominator");
+
+        Rational {
+            negative: negative && !numerator.is_zero(),
+            numerator,
+            denominator,
+        }
+    }
+
+    pub(crate) fn unreduced_add(&self, other: &Rational) -> Rational {
+        let left = &self.numerator * &other.denominator;
+        let right = &other.numerator * &self.denominator;
+        let denominator = &self.denominator * &other.denominator;
+
+        match (self.negative, other.negative) {
+            (a, b) if a == b => Rational::unreduced(a, &left + &right, denominator),
+            _ if left >= right => Rational::unreduced(self.negative, &left - &right, denominator),
+            _ => Rational::unreduced(other.negative, &right - &left, denominator),
+        }
+    }
+
+    pub(crate) fn unreduced_sub(&self, other: &Rational) -> Rational {
+        self.unreduced_add(&Rational {
+            negative: !other.negative && !other.is_zero(),
+            ..other.clone()
+        })
+    }
+
+    pub(crate) fn unreduced_mul(&self, other: &Rational) -> Rational {
+        Rational::unreduced(
+            self.negative != other.negative,
+            &self.numerator * &other.numerator,
+            &self.denominator * &other.denominator,
+        )
+    }
+
+    // Panics when dividing by zero
+    pub(crate) fn unreduced_div(&self, other: &Rational) -> Rational {
+        Rational::unreduced(
+            self.negative != other.negative,
+            &self.numerator * &other.denominator,
+            &self.denominator * &other.numerator,
+        )
+    }
+
+    fn reduced(self) -> Rational {
+        Rational::new(self.negative, self.numerator, self.denominator)
+    }
+
+    pub fn zero() -> Self {
+        Rational::from(0)
+    }
+
+    pub fn one() -> Self {
+        Rational::from(1)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == BigUint::one()
+    }
+
+    pub fn numerator(&self) -> &BigUint {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &BigUint {
+        &self.denominator
+    }
+
+    pub fn abs(&self) -> Self {
+        Rational {
+            negative: false,
+            ..self.clone()
+        }
+    }
+
+    pub fn recip(&self) -> Self {
+        Rational::new(
+            self.negative,
+            self.denominator.clone(),
+            self.numerator.clone(),
+        )
+    }
+
+    pub fn powi(&self, exponent: i32) -> Self {
+        let power = Rational::new(
+            self.negative && exponent % 2 != 0,
+            self.numerator.pow(exponent.unsigned_abs()),
+            self.denominator.pow(exponent.unsigned_abs()),
+        );
+
+        if exponent < 0 {
+            power.recip()
+        } else {
+            power
+        }
+    }
+
+    // The integer nearest to self × 10^-exponent, ties away from zero, without its sign
+    fn rounded_at(&self, exponent: i32) -> BigUint {
+        let (quotient, remainder, denominator) = self.scaled_at(exponent);
+        if &remainder + &remainder >= denominator {
+            &quotient + &BigUint::one()
+        } else {
+            quotient
+        }
+    }
+
+    // |self| × 10^-exponent as a whole part, remainder and the denominator of the remainder
+    fn scaled_at(&self, exponent: i32) -> (BigUint, BigUint, BigUint) {
+        let scale = BigUint::power_of_ten(exponent.unsigned_abs());
+        let (numerator, denominator) = if exponent < 0 {
+            (&self.numerator * &scale, self.denominator.clone())
+        } else {
+            (self.numerator.clone(), &self.denominator * &scale)
+        };
+
+        let (quotient, remainder) = numerator.div_rem(&denominator);
+        (quotient, remainder, denominator)
+    }
+
+    // The power of ten of the first significant digit; zero counts as 10^0
+    pub fn leading_exponent(&self) -> i32 {
+        if self.is_zero() {
+            return 0;
+        }
+
+        // log10(2) estimate from the bit lengths, then corrected by exact comparison
+        let bits = self.numerator.bits() as f64 - self.denominator.bits() as f64;
+        let mut exponent = (bits * std::f64::consts::LOG10_2).floor() as i32;
+        let below = |exponent: i32| self.abs() < Rational::from(10).powi(exponent);
+
+        while below(exponent) {
+            exponent -= 1;
+        }
+        while !below(exponent + 1) {
+            exponent += 1;
+        }
+        exponent
+    }
+
+    // `count` significant digits, rounded half away from zero, and the power of ten of the
+    // last one; any count works since nothing is held in floating point
+    pub fn significant_digits(&self, count: usize) -> (String, i32) {
+        let (digits, last) = self.significant(count);
+        (digits.to_string(), last)
+    }
+
+    fn significant(&self, count: usize) -> (BigUint, i32) {
+        let count = count.max(1);
+        let mut last = self.leading_exponent() - count as i32 + 1;
+        let mut digits = self.rounded_at(last);
+
+        // 9.99… may round up into the next decade
+        if digits >= BigUint::power_of_ten(count as u32) {
+            last += 1;
+            digits = self.rounded_at(last);
+        }
+
+        (digits, last)
+    }
+
+    // The nearest value with `count` significant digits, to keep iterations from growing.
+    // Only 2 and 5 can divide both the digits and a power of ten, so those are all that is
+    // cancelled instead of taking a gcd.
+    pub fn rounded(&self, count: usize) -> Rational {
+        if self.is_zero() {
+            return Rational::zero();
+        }
+
+        let (mut digits, last) = self.significant(count);
+        if last >= 0 {
+            let digits = &digits * &BigUint::power_of_ten(last as u32);
+            return Rational::unreduced(self.negative, digits, BigUint::one());
+        }
+
+        let mut denominator = BigUint::one();
+        for factor in [2, 5] {
+            let mut power = last.unsigned_abs();
+            while power > 0 {
+                match digits.div_rem_small(factor) {
+                    (quotient, 0) => digits = quotient,
+                    _ => break,
+                }
+                power -= 1;
+            }
+            denominator = &denominator * &BigUint::from_u64(factor as u64).pow(power);
+        }
+
+        Rational::unreduced(self.negative, digits, denominator)
+    }
+
+    // Scientific notation with `count` significant digits: "4.835978484169836e14"
+    pub fn to_scientific_string(&self, count: usize) -> String {
+        let (digits, last) = self.significant_digits(count);
+        let sign = if self.negative { "-" } else { "" };
+        let (first, rest) = digits.split_at(1);
+        let exponent = last + digits.len() as i32 - 1;
+
+        if rest.is_empty() {
+            format!("{}{}e{}", sign, first, exponent)
+        } else {
+            format!("{}{}.{}e{}", sign, first, rest, exponent)
+        }
+    }
+
+    // Whether the decimal is self cut off at its last digit, either rounded or truncated;
+    // NIST truncates exact values that do not terminate and marks them with "..."
+    pub fn agrees_with(&self, decimal: Decimal) -> bool {
+        let digits = BigUint::from_u64(decimal.mantissa.unsigned_abs());
+        let (truncated, _, _) = self.scaled_at(decimal.exponent);
+
+        self.negative == decimal.is_negative()
+            && (truncated == digits || self.rounded_at(decimal.exponent) == digits)
+    }
+
+    // Correctly rounded unless 40 digits cannot settle a tie, which no constant comes near
+    pub fn to_f64(&self) -> f64 {
+        self.to_scientific_string(40).parse().unwrap_or(f64::NAN)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::new(
+            value < 0,
+            BigUint::from_u64(value.unsigned_abs()),
+            BigUint::one(),
+        )
+    }
+}
+
+// Published digits are exact decimals, so this loses nothing
+impl From<Decimal> for Rational {
+    fn from(decimal: Decimal) -> Self {
+        Rational::from(decimal.mantissa) * Rational::from(10).powi(decimal.exponent)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational::new(!self.negative, self.numerator, self.denominator)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        self.unreduced_add(&other).reduced()
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        self.unreduced_mul(&other).reduced()
+    }
+}
+
+// Panics when dividing by zero
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        self.unreduced_div(&other).reduced()
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let left = &self.numerator * &other.denominator;
+        let right = &other.numerator * &self.denominator;
+
+        match (self.negative, other.negative) {
+            (false, false) => left.cmp(&right),
+            (true, true) => right.cmp(&left),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+// "p/q", or just "p" for integers
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+
+        if self.is_integer() {
+            write!(f, "{}{}", sign, self.numerator)
+        } else {
+            write!(f, "{}{}/{}", sign, self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(text: &str) -> BigUint {
+        BigUint::parse(text).unwrap()
+    }
+
+    fn assert_division(dividend: &BigUint, divisor: &BigUint) {
+        let (quotient, remainder) = dividend.div_rem(divisor);
+        assert!(remainder < *divisor, "{} / {}", dividend, divisor);
+        assert_eq!(
+            &(&quotient * divisor) + &remainder,
+            *dividend,
+            "{} / {}",
+            dividend,
+            divisor
+        );
+    }
+
+    #[test]
+    fn long_division() {
+        let two_32 = BigUint::from_u64(1 << 32);
+        let dividends = [
+            number("602214076000000000000000000000000000000000000000000000000000000"),
+            number("340282366920938463463374607431768211455"),
+            number("340282366920938463463374607431768211456"),
+            &BigUint::power_of_ten(200) - &BigUint::one(),
+            // Divided by the last divisor, the first quotient estimate survives both
+            // corrections and has to be undone by adding the divisor back
+            BigUint {
+                limbs: vec![0, 0, 0x8000_0000, 0x7fff_ffff],
+            },
+            two_32.pow(7),
+            &two_32.pow(7) - &BigUint::one(),
+        ];
+        let divisors = [
+            number("18446744073709551616"),
+            number("18446744073709551615"),
+            number("18446744073709551617"),
+            number("9223372036854775808"),
+            number("79228162514264337593543950335"),
+            &BigUint::power_of_ten(60) + &BigUint::one(),
+            BigUint {
+                limbs: vec![1, 0, 0x8000_0000],
+            },
+        ];
+
+        for dividend in &dividends {
+            for divisor in &divisors {
+                assert_division(dividend, divisor);
+            }
+        }
+    }
+
+    #[test]
+    fn long_division_results() {
+        let (quotient, remainder) = BigUint::power_of_ten(40).div_rem(&BigUint::power_of_ten(25));
+        assert_eq!(quotient, BigUint::power_of_ten(15));
+        assert!(remainder.is_zero());
+
+        let (quotient, remainder) = number("100000000000000000000000000000000000007")
+            .div_rem(&number("100000000000000000000"));
+        assert_eq!(quotient.to_string(), "1000000000000000000");
+        assert_eq!(remainder.to_string(), "7");
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn division_by_zero_panics() {
+        BigUint::one().div_rem(&BigUint::zero());
+    }
+
+    #[test]
+    fn fractions_are_kept_in_lowest_terms() {
+        let sixth = Rational::from(1) / Rational::from(6);
+        let sum = sixth.clone() + sixth.clone() + sixth;
+
+        assert_eq!(sum, Rational::from(1) / Rational::from(2));
+        assert_eq!(sum.to_string(), "1/2");
+        assert_eq!((Rational::from(3) - Rational::from(3)).to_string(), "0");
+        assert!(!(Rational::from(-3) + Rational::from(3)).is_negative());
+    }
+
+    #[test]
+    fn unreduced_results_round_like_reduced_ones() {
+        let third = Rational::from(1) / Rational::from(3);
+        let product = third.unreduced_mul(&Rational::from(6));
+
+        assert_eq!(product.rounded(5), Rational::from(2));
+        assert_eq!(
+            third.unreduced_sub(&Rational::from(1)).rounded(3),
+            Rational::from(-667) / Rational::from(1000)
+        );
+    }
+
+    #[test]
+    fn significant_digits_round_half_away_from_zero() {
+        let value = Rational::from(Decimal::new(99_995, -4));
+
+        assert_eq!(value.significant_digits(4), ("1000".to_string(), -2));
+        assert_eq!(value.to_scientific_string(4), "1.000e1");
+        assert_eq!((-value).to_scientific_string(2), "-1.0e1");
+        assert_eq!(
+            (Rational::from(2) / Rational::from(3)).to_scientific_string(3),
+            "6.67e-1"
+        );
+    }
+
+    #[test]
+    fn agrees_with_rounded_or_truncated_digits() {
+        let third = Rational::from(1) / Rational::from(3);
+
+        assert!(third.agrees_with(Decimal::new(3333, -4)));
+        let two_thirds = Rational::from(2) / Rational::from(3);
+        assert!(two_thirds.agrees_with(Decimal::new(6666, -4)));
+        assert!(two_thirds.agrees_with(Decimal::new(6667, -4)));
+        assert!(!two_thirds.agrees_with(Decimal::new(6668, -4)));
+        assert!(!two_thirds.agrees_with(Decimal::new(-6667, -4)));
+    }
+
+    #[test]
+    fn converts_to_the_nearest_f64() {
+        assert_eq!((Rational::from(1) / Rational::from(10)).to_f64(), 0.1);
+        assert_eq!(
+            Rational::from(Decimal::new(662_607_015, -42)).to_f64(),
+            6.62607015e-34
+        );
+    }
+}
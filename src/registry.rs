@@ -0,0 +1,236 @@
+// User-defined constants, such as a lab's local g or a Larmor frequency per tesla, added to
+// a dataset next to the NIST entries so that lookup, search and every export see them.
+// They are given explicitly or as a calculator formula over the entries already present.
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::calc::{CalcError, Calculator};
+use crate::concise_parse::{looks_concise, ConciseParseError};
+use crate::decimal::Decimal;
+use crate::merge::Source;
+use crate::units::Unit;
+use crate::validation::{ValidationError, ValidationProblem};
+use crate::{Constant, Dataset};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistryError {
+    EmptyName,
+    DuplicateName(String),
+    Invalid(ValidationError),
+    Formula {
+        name: String,
+        error: CalcError,
+    },
+    Concise {
+        name: String,
+        error: ConciseParseError,
+    },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::EmptyName => write!(f, "a constant needs a name"),
+            RegistryError::DuplicateName(name) => {
+                write!(f, "there already is a constant named \"{}\"", name)
+            }
+            RegistryError::Invalid(error) => write!(f, "{}", error),
+            RegistryError::Formula { name, error } => {
+                write!(f, "cannot compute \"{}\": {}", name, error)
+            }
+            RegistryError::Concise { name, error } => {
+                write!(f, "cannot read the value of \"{}\": {}", name, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+impl Dataset {
+    // Adds a constant with an explicit value, unit and uncertainty, e.g. from
+    // Constant::from_concise("local g", "9.803 11(5) m s^-2")
+    pub fn register(&mut self, constant: Constant) -> Result<&Constant, RegistryError> {
+        if constant.name.trim().is_empty() {
+            return Err(RegistryError::EmptyName);
+        }
+        if self.get(&constant.name).is_some() {
+            return Err(RegistryError::DuplicateName(constant.name.to_string()));
+        }
+        constant.validate().map_err(RegistryError::Invalid)?;
+
+        self.sources
+            .push((constant.name.to_string(), Source::UserDefined));
+        self.constants.push(constant);
+        Ok(&self.constants[self.constants.len() - 1])
+    }
+
+    // Adds a constant computed from the entries by the calculator, e.g.
+    // "gamma_p/(2*pi) in MHz T^-1"; the uncertainty is propagated from the inputs
+    pub fn register_formula(
+        &mut self,
+        name: &str,
+        formula: &str,
+    ) -> Result<&Constant, RegistryError> {
+        let calculation =
+            Calculator::new(self)
+                .evaluate(formula)
+                .map_err(|error| RegistryError::Formula {
+                    name: name.to_string(),
+                    error,
+                })?;
+        // An infinity or NaN has no digits to keep, so it is rejected rather than stored
+        let invalid = |problem| {
+            RegistryError::Invalid(ValidationError {
+                name: name.to_string(),
+                problem,
+            })
+        };
+        if !calculation.value().is_finite() {
+            return Err(invalid(ValidationProblem::NonFiniteValue));
+        }
+        if calculation
+            .standard_uncertainty()
+            .is_some_and(|uncertainty| !uncertainty.is_finite())
+        {
+            return Err(invalid(ValidationProblem::InvalidUncertainty));
+        }
+
+        // Kept as NIST publishes: two digits of uncertainty and the value to the same place
+        let uncertainty = calculation
+            .standard_uncertainty()
+            .map(|uncertainty| Decimal::parse(&format!("{:.1e}", uncertainty)));
+        let decimal = match uncertainty {
+            // Beyond 18 digits the mantissa would overflow; all the f64 digits are kept then
+            Some(Ok(uncertainty)) => {
+                let value = Decimal::from_f64(calculation.value())
+                    .ok_or_else(|| invalid(ValidationProblem::NonFiniteValue))?;
+                i64::try_from(value.mantissa_at(uncertainty.exponent)).map_or(value, |mantissa| {
+                    Decimal::new(mantissa, uncertainty.exponent)
+                })
+            }
+            _ => Decimal::from_f64(calculation.rounded_value())
+                .ok_or_else(|| invalid(ValidationProblem::NonFiniteValue))?,
+        };
+
+        self.register(Constant {
+            name: Cow::Owned(name.to_string()),
+            value: decimal.to_f64(),
+            decimal,
+            unit: match calculation.unit_text() {
+                Some(unit) => Unit::Published(Cow::Owned(unit)),
+                None => Unit::Dimensionless,
+            },
+            uncertainty: uncertainty.and_then(Result::ok).map(Decimal::to_f64),
+        })
+    }
+
+    // A value in concise notation ("9.803 11(5) m s^-2") if the text reads as one,
+    // otherwise a formula. A number with an uncertainty or "..." is always concise notation,
+    // so a mistake in one is reported rather than read as a formula: "1.2(3) 10^5" is not
+    // 1.2 × 3 × 10^5
+    pub fn define(&mut self, name: &str, definition: &str) -> Result<&Constant, RegistryError> {
+        if looks_concise(definition) {
+            let constant =
+                Constant::from_concise(name.to_string(), definition).map_err(|error| {
+                    RegistryError::Concise {
+                        name: name.to_string(),
+                        error,
+                    }
+                })?;
+            return self.register(constant);
+        }
+
+        match Constant::from_concise(name.to_string(), definition) {
+            Ok(constant) if constant.validate().is_ok() => self.register(constant),
+            _ => self.register_formula(name, definition),
+        }
+    }
+
+    // Whether the entry was added by register() rather than being part of the data
+    pub fn is_user_defined(&self, name: &str) -> bool {
+        *self.source(name) == Source::UserDefined
+    }
+
+    pub fn user_defined_constants(&self) -> impl Iterator<Item = &Constant> {
+        self.constants
+            .iter()
+            .filter(|constant| self.is_user_defined(&constant.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn define_reads_concise_notation() {
+        let mut dataset = Dataset::codata();
+
+        let g = dataset.define("local g", "9.803 11(5) m s^-2").unwrap();
+        assert_eq!(g.decimal, Decimal::new(980311, -5));
+        assert_eq!(g.uncertainty, Some(0.00005));
+        assert_eq!(g.unit.as_str(), Some("m s^-2"));
+
+        let exact = dataset.define("exact", "1.5...").unwrap();
+        assert!(exact.is_exact());
+        assert!(dataset.is_user_defined("local g"));
+    }
+
+    #[test]
+    fn define_falls_back_to_a_formula() {
+        let mut dataset = Dataset::codata();
+
+        let two_pi = dataset.define("two pi", "2*pi").unwrap().value;
+        assert!((two_pi - 2.0 * std::f64::consts::PI).abs() < 1e-14);
+
+        // Concise notation would take "pi" for a unit, which validation rejects
+        assert_eq!(dataset.define("also two pi", "2 pi").unwrap().value, two_pi);
+
+        let energy = dataset.define("rest energy", "m_e*c^2 in MeV").unwrap();
+        assert_eq!(energy.unit.as_str(), Some("MeV"));
+        assert!(energy.uncertainty.is_some());
+    }
+
+    #[test]
+    fn define_reports_mistakes_in_concise_notation() {
+        let mut dataset = Dataset::codata();
+
+        for definition in [
+            "6.674 30(15) 10^-11 m^3 kg^-1 s^-2",
+            "1.2(3) 10^5",
+            "1.2(3)(4)",
+            "1.2(x)",
+            "1.5... (2)",
+        ] {
+            let error = dataset.define("x", definition).unwrap_err();
+            assert!(
+                matches!(error, RegistryError::Concise { .. }),
+                "{}: {}",
+                definition,
+                error
+            );
+        }
+
+        // Read as concise notation, so the unit is checked rather than taken for a formula
+        assert!(matches!(
+            dataset.define("x", "9.8(1) furlongs"),
+            Err(RegistryError::Invalid(_))
+        ));
+        assert!(dataset.get("x").is_none());
+    }
+
+    #[test]
+    fn formulas_without_a_finite_value_are_rejected() {
+        let mut dataset = Dataset::codata();
+
+        for formula in ["1/0", "10^400", "h*1e300*1e300", "0/0"] {
+            assert!(
+                dataset.register_formula("x", formula).is_err(),
+                "{}",
+                formula
+            );
+            assert!(dataset.get("x").is_none(), "{}", formula);
+        }
+    }
+}
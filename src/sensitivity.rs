@@ -0,0 +1,291 @@
+// Which constant dominates the uncertainty of a result? A function of several constants is
+// evaluated with each input shifted up and down in turn, the partial derivatives are taken
+// as central differences, and each input's share of the variance u²(y) = Σ (c_i u_i)² is
+// reported. Functions written against a ConstantProvider can be analysed unchanged.
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::provider::{ConstantProvider, MissingConstant, Perturbation, Perturbed};
+use crate::Constant;
+
+// Relative step for inputs whose uncertainty is zero or too small to difference over
+const RELATIVE_STEP: f64 = 1e-6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SensitivityError {
+    Missing(MissingConstant),
+    // The function gave NaN or an infinity, at the nominal inputs (None) or with this
+    // input shifted
+    NonFinite(Option<String>),
+}
+
+impl fmt::Display for SensitivityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SensitivityError::Missing(error) => write!(f, "{}", error),
+            SensitivityError::NonFinite(None) => {
+                write!(f, "the function is not finite at the nominal values")
+            }
+            SensitivityError::NonFinite(Some(name)) => {
+                write!(f, "the function is not finite when \"{}\" is shifted", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SensitivityError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensitivityEntry {
+    pub constant: Constant,
+    // ∂y/∂x_i, the sensitivity coefficient c_i
+    pub sensitivity: f64,
+    // |c_i| u_i, the standard uncertainty this input contributes to y; 0 when exact
+    pub contribution: f64,
+    // (c_i u_i)² / u²(y), between 0 and 1
+    pub variance_share: f64,
+}
+
+impl SensitivityEntry {
+    pub fn uncertainty(&self) -> f64 {
+        self.constant.uncertainty.unwrap_or(0.0)
+    }
+}
+
+// The inputs in the order they were named
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensitivityBudget {
+    pub value: f64,
+    pub entries: Vec<SensitivityEntry>,
+}
+
+impl SensitivityBudget {
+    // Combined standard uncertainty u(y), assuming uncorrelated inputs
+    pub fn combined_uncertainty(&self) -> f64 {
+        self.entries
+            .iter()
+            .fold(0.0, |sum, entry| {
+                sum + entry.contribution * entry.contribution
+            })
+            .sqrt()
+    }
+
+    // Largest share first
+    pub fn ranked(&self) -> Vec<&SensitivityEntry> {
+        let mut entries: Vec<&SensitivityEntry> = self.entries.iter().collect();
+        entries.sort_by(|a, b| b.variance_share.total_cmp(&a.variance_share));
+        entries
+    }
+
+    pub fn dominant(&self) -> Option<&SensitivityEntry> {
+        self.ranked().into_iter().next()
+    }
+}
+
+// A table of the inputs ranked by share of the variance, then y and u(y)
+impl fmt::Display for SensitivityBudget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let header = ["constant", "value", "u(x)", "c", "|c| u(x)", "share"].map(String::from);
+        let rows: Vec<[String; 6]> = self
+            .ranked()
+            .into_iter()
+            .map(|entry| {
+                [
+                    entry.constant.name.to_string(),
+                    format!("{:e}", entry.constant.value),
+                    match entry.constant.uncertainty {
+                        Some(uncertainty) => format!("{:e}", uncertainty),
+                        None => "exact".to_string(),
+                    },
+                    format!("{:.4e}", entry.sensitivity),
+                    format!("{:.4e}", entry.contribution),
+                    format!("{:.1}%", entry.variance_share * 100.0),
+                ]
+            })
+            .collect();
+
+        let mut widths = [0; 6];
+        for row in Some(&header).into_iter().chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in Some(&header).into_iter().chain(&rows) {
+            for (column, cell) in row.iter().enumerate().take(5) {
+                write!(f, "{:<width$}  ", cell, width = widths[column])?;
+            }
+            writeln!(f, "{:>width$}", row[5], width = widths[5])?;
+        }
+        write!(
+            f,
+            "y = {:e}, u(y) = {:.4e}",
+            self.value,
+            self.combined_uncertainty()
+        )
+    }
+}
+
+// Evaluates `function` on the values of the named constants, given in the same order
+pub fn sensitivity(
+    provider: &(impl ConstantProvider + ?Sized),
+    names: &[&str],
+    function: impl Fn(&[f64]) -> f64,
+) -> Result<SensitivityBudget, SensitivityError> {
+    sensitivity_with_provider(provider, names, |perturbed| {
+        let values: Vec<f64> = names
+            .iter()
+            .map(|name| perturbed.value(name).unwrap_or(f64::NAN))
+            .collect();
+        function(&values)
+    })
+}
+
+// Evaluates `function` on providers in which one of the named constants at a time is
+// shifted, for code that reads its constants from a provider itself
+pub fn sensitivity_with_provider(
+    provider: &(impl ConstantProvider + ?Sized),
+    names: &[&str],
+    function: impl Fn(&dyn ConstantProvider) -> f64,
+) -> Result<SensitivityBudget, SensitivityError> {
+    let constants = names
+        .iter()
+        .map(|name| provider.require(name).map(Cow::into_owned))
+        .collect::<Result<Vec<Constant>, _>>()
+        .map_err(SensitivityError::Missing)?;
+
+    let value = function(&Perturbed::new(provider));
+    if !value.is_finite() {
+        return Err(SensitivityError::NonFinite(None));
+    }
+
+    let mut entries = Vec::new();
+    for (name, constant) in names.iter().zip(constants) {
+        let uncertainty = constant.uncertainty.unwrap_or(0.0);
+        // Shifting by ±u as GUM 5.1.3 suggests, but by at least a millionth of the value so
+        // that the difference is not lost to rounding
+        let step = match uncertainty.max(constant.value.abs() * RELATIVE_STEP) {
+            0.0 => RELATIVE_STEP,
+            step => step,
+        };
+
+        let shifted = |offset: f64| {
+            function(&Perturbed::new(provider).with(name, Perturbation::Offset(offset)))
+        };
+        let sensitivity = (shifted(step) - shifted(-step)) / (2.0 * step);
+        if !sensitivity.is_finite() {
+            return Err(SensitivityError::NonFinite(Some(name.to_string())));
+        }
+
+        entries.push(SensitivityEntry {
+            contribution: (sensitivity * uncertainty).abs(),
+            constant,
+            sensitivity,
+            variance_share: 0.0,
+        });
+    }
+
+    let variance: f64 = entries
+        .iter()
+        .map(|entry| entry.contribution * entry.contribution)
+        .sum();
+    if variance > 0.0 {
+        for entry in &mut entries {
+            entry.variance_share = entry.contribution * entry.contribution / variance;
+        }
+    }
+
+    Ok(SensitivityBudget { value, entries })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Codata;
+    use crate::Dataset;
+
+    fn dataset() -> Dataset {
+        Dataset {
+            edition: Cow::Borrowed("test"),
+            constants: vec![
+                Constant::from_concise("a", "2.0(1)").unwrap(),
+                Constant::from_concise("b", "3.0(4)").unwrap(),
+                Constant::from_concise("c", "5 (exact)").unwrap(),
+            ],
+            sources: Vec::new(),
+        }
+    }
+
+    fn close(actual: f64, expected: f64) -> bool {
+        (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0)
+    }
+
+    #[test]
+    fn shares_of_a_product() {
+        // y = ab: c_a = b = 3 and c_b = a = 2, so the contributions are 0.3 and 0.8
+        let budget = sensitivity(&dataset(), &["a", "b"], |x| x[0] * x[1]).unwrap();
+
+        assert_eq!(budget.value, 6.0);
+        let [a, b] = &budget.entries[..] else {
+            panic!("{:?}", budget.entries)
+        };
+        assert!(close(a.sensitivity, 3.0) && close(b.sensitivity, 2.0));
+        assert!(close(a.contribution, 0.3) && close(b.contribution, 0.8));
+        assert!(close(a.variance_share, 0.09 / 0.73));
+        assert!(close(b.variance_share, 0.64 / 0.73));
+        assert!(close(budget.combined_uncertainty(), 0.73f64.sqrt()));
+        assert_eq!(budget.dominant().unwrap().constant.name, "b");
+    }
+
+    #[test]
+    fn exact_inputs_have_no_share() {
+        let budget = sensitivity(&dataset(), &["c", "a"], |x| x[0] + x[1] * x[1]).unwrap();
+
+        assert!(close(budget.entries[0].sensitivity, 1.0));
+        assert_eq!(budget.entries[0].contribution, 0.0);
+        assert_eq!(budget.entries[0].variance_share, 0.0);
+        assert!(close(budget.entries[1].sensitivity, 4.0));
+        assert!(close(budget.entries[1].variance_share, 1.0));
+
+        let exact = sensitivity(&dataset(), &["c"], |x| 2.0 * x[0]).unwrap();
+        assert_eq!(exact.combined_uncertainty(), 0.0);
+        assert_eq!(exact.entries[0].variance_share, 0.0);
+    }
+
+    #[test]
+    fn gravitation_dominates_the_planck_mass() {
+        let names = [
+            "reduced Planck constant",
+            "speed of light in vacuum",
+            "Newtonian constant of gravitation",
+        ];
+        let budget = sensitivity(&Codata, &names, |x| (x[0] * x[1] / x[2]).sqrt()).unwrap();
+
+        assert_eq!(budget.dominant().unwrap().constant.name, names[2]);
+        assert!(close(budget.entries[2].variance_share, 1.0));
+        // u(m_P)/m_P = u(G)/2G
+        let relative = budget.combined_uncertainty() / budget.value;
+        assert!(
+            (relative - 0.00015 / 6.67430 / 2.0).abs() < 1e-8,
+            "{}",
+            relative
+        );
+    }
+
+    #[test]
+    fn reports_missing_inputs_and_non_finite_results() {
+        assert!(matches!(
+            sensitivity(&dataset(), &["a", "nope"], |x| x[0]),
+            Err(SensitivityError::Missing(_))
+        ));
+        assert_eq!(
+            sensitivity(&dataset(), &["a"], |x| 1.0 / (x[0] - 2.0)),
+            Err(SensitivityError::NonFinite(None))
+        );
+        // Finite at a = 2 but not once a is shifted up
+        assert_eq!(
+            sensitivity(&dataset(), &["b", "a"], |x| (2.0 - x[1]).sqrt()),
+            Err(SensitivityError::NonFinite(Some("a".to_string())))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+// Optional Unicode rendering of the table's ASCII unit strings: "m^3 kg^-1 s^-2" becomes
+// "m³ kg⁻¹ s⁻²" (or "m³·kg⁻¹·s⁻²"), "ohm" becomes "Ω" and a "u" micro prefix becomes "µ".
+// ASCII stays the default everywhere, since it is what the data and the exports use.
+use std::fmt;
+
+use crate::Constant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    // As listed: "m^3 kg^-1 s^-2"
+    #[default]
+    Ascii,
+    // "m³ kg⁻¹ s⁻²"
+    Unicode,
+    // "m³·kg⁻¹·s⁻²"
+    UnicodeDot,
+}
+
+// Units a leading "u" stands for micro in; a lone "u" is the atomic mass unit
+const MICRO_UNITS: [&str; 14] = [
+    "A", "C", "F", "g", "H", "Hz", "J", "K", "m", "mol", "s", "T", "V", "W",
+];
+
+pub fn format_unit(unit: &str, style: UnitStyle) -> String {
+    let separator = match style {
+        UnitStyle::Ascii => return unit.to_string(),
+        UnitStyle::Unicode => " ",
+        UnitStyle::UnicodeDot => "·",
+    };
+
+    unit.split_whitespace()
+        .map(format_token)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn format_token(token: &str) -> String {
+    let (symbol, rest) = token
+        .find(['^', '/', ')'])
+        .map_or((token, ""), |index| token.split_at(index));
+
+    let symbol = match symbol {
+        "ohm" => "Ω".to_string(),
+        "E_h" => "Eₕ".to_string(),
+        symbol => match symbol.strip_prefix('u') {
+            Some(unit) if MICRO_UNITS.contains(&unit) => format!("µ{}", unit),
+            _ => symbol.to_string(),
+        },
+    };
+
+    let mut text = symbol;
+    let mut characters = rest.chars().peekable();
+
+    // "^-2" becomes "⁻²", wherever it appears, as in "(GeV/c^2)^-2"
+    while let Some(character) = characters.next() {
+        if character == '^' {
+            while let Some(next) = characters.next_if(|c| *c == '-' || c.is_ascii_digit()) {
+                text.push(superscript(next));
+            }
+        } else {
+            text.push(character);
+        }
+    }
+
+    text
+}
+
+fn superscript(character: char) -> char {
+    match character {
+        '-' => '⁻',
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        other => other,
+    }
+}
+
+// The Display output of a constant with its unit in the chosen style
+pub struct ConstantDisplay<'a> {
+    constant: &'a Constant,
+    units: UnitStyle,
+}
+
+impl Constant {
+    // `constant.display_with(UnitStyle::Unicode).to_string()`; plain Display is ASCII
+    pub fn display_with(&self, units: UnitStyle) -> ConstantDisplay<'_> {
+        ConstantDisplay {
+            constant: self,
+            units,
+        }
+    }
+
+    pub fn formatted_unit(&self, units: UnitStyle) -> Option<String> {
+        self.unit.as_str().map(|unit| format_unit(unit, units))
+    }
+}
+
+impl fmt::Display for ConstantDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.constant.write_block(f, self.units)
+    }
+}
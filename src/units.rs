@@ -0,0 +1,731 @@
+// Published units resolved into SI: a scale factor and the exponents of the seven base
+// units, so "MeV" is 1.602176634e-13 kg m^2 s^-2 and "(GeV/c^2)^-2" is a mass to the -2.
+// The unit strings themselves stay as NIST prints them in Constant::unit.
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::{Div, Mul};
+
+use crate::decimal::Decimal;
+use crate::provider::{Codata, ConstantProvider, MissingConstant};
+use crate::{Constant, Dataset};
+
+// What a constant is measured in. Dimensionless is a value of its own, distinct from a
+// unit nobody recorded; a published unit is never empty (see Constant::validate())
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Dimensionless,
+    Unknown,
+    // As printed by NIST, e.g. "J T^-1" or "MeV"
+    Published(Cow<'static, str>),
+}
+
+impl Unit {
+    // The published text; None for dimensionless and unknown units
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Unit::Published(unit) => Some(unit),
+            Unit::Dimensionless | Unit::Unknown => None,
+        }
+    }
+
+    pub fn is_dimensionless(&self) -> bool {
+        *self == Unit::Dimensionless
+    }
+
+    pub fn is_known(&self) -> bool {
+        *self != Unit::Unknown
+    }
+
+    pub fn si_unit(&self) -> Option<SiUnit> {
+        match self {
+            Unit::Dimensionless => Some(SiUnit::ONE),
+            Unit::Unknown => None,
+            Unit::Published(unit) => parse_unit(unit).ok(),
+        }
+    }
+}
+
+// "dimensionless", "unknown" or the published text
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unit::Dimensionless => write!(f, "dimensionless"),
+            Unit::Unknown => write!(f, "unknown"),
+            Unit::Published(unit) => write!(f, "{}", unit),
+        }
+    }
+}
+
+// Exponents of kg, m, s, A, K, mol and cd
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Dimension {
+    pub mass: i8,
+    pub length: i8,
+    pub time: i8,
+    pub current: i8,
+    pub temperature: i8,
+    pub amount: i8,
+    pub luminous_intensity: i8,
+}
+
+impl Dimension {
+    pub const NONE: Self = Self::new(0, 0, 0, 0, 0, 0, 0);
+    pub const MASS: Self = Self::new(1, 0, 0, 0, 0, 0, 0);
+    pub const LENGTH: Self = Self::new(0, 1, 0, 0, 0, 0, 0);
+    pub const TIME: Self = Self::new(0, 0, 1, 0, 0, 0, 0);
+    pub const CURRENT: Self = Self::new(0, 0, 0, 1, 0, 0, 0);
+    pub const TEMPERATURE: Self = Self::new(0, 0, 0, 0, 1, 0, 0);
+    pub const AMOUNT: Self = Self::new(0, 0, 0, 0, 0, 1, 0);
+    pub const LUMINOUS_INTENSITY: Self = Self::new(0, 0, 0, 0, 0, 0, 1);
+
+    pub const AREA: Self = Self::new(0, 2, 0, 0, 0, 0, 0);
+    pub const VOLUME: Self = Self::new(0, 3, 0, 0, 0, 0, 0);
+    pub const WAVENUMBER: Self = Self::new(0, -1, 0, 0, 0, 0, 0);
+    pub const FREQUENCY: Self = Self::new(0, 0, -1, 0, 0, 0, 0);
+    pub const VELOCITY: Self = Self::new(0, 1, -1, 0, 0, 0, 0);
+    pub const ACCELERATION: Self = Self::new(0, 1, -2, 0, 0, 0, 0);
+    pub const MOMENTUM: Self = Self::new(1, 1, -1, 0, 0, 0, 0);
+    pub const FORCE: Self = Self::new(1, 1, -2, 0, 0, 0, 0);
+    pub const PRESSURE: Self = Self::new(1, -1, -2, 0, 0, 0, 0);
+    pub const ENERGY: Self = Self::new(1, 2, -2, 0, 0, 0, 0);
+    pub const POWER: Self = Self::new(1, 2, -3, 0, 0, 0, 0);
+    pub const ACTION: Self = Self::new(1, 2, -1, 0, 0, 0, 0);
+    pub const ENTROPY: Self = Self::new(1, 2, -2, 0, -1, 0, 0);
+    pub const CHARGE: Self = Self::new(0, 0, 1, 1, 0, 0, 0);
+    pub const VOLTAGE: Self = Self::new(1, 2, -3, -1, 0, 0, 0);
+    pub const RESISTANCE: Self = Self::new(1, 2, -3, -2, 0, 0, 0);
+    pub const CONDUCTANCE: Self = Self::new(-1, -2, 3, 2, 0, 0, 0);
+    pub const CAPACITANCE: Self = Self::new(-1, -2, 4, 2, 0, 0, 0);
+    pub const INDUCTANCE: Self = Self::new(1, 2, -2, -2, 0, 0, 0);
+    pub const MAGNETIC_FLUX: Self = Self::new(1, 2, -2, -1, 0, 0, 0);
+    pub const MAGNETIC_FIELD: Self = Self::new(1, 0, -2, -1, 0, 0, 0);
+    pub const MAGNETIC_MOMENT: Self = Self::new(0, 2, 0, 1, 0, 0, 0);
+
+    pub const fn new(
+        mass: i8,
+        length: i8,
+        time: i8,
+        current: i8,
+        temperature: i8,
+        amount: i8,
+        luminous_intensity: i8,
+    ) -> Self {
+        Dimension {
+            mass,
+            length,
+            time,
+            current,
+            temperature,
+            amount,
+            luminous_intensity,
+        }
+    }
+
+    pub fn is_dimensionless(self) -> bool {
+        self == Dimension::NONE
+    }
+
+    // Panics when an exponent leaves the i8 range; see checked_powi()
+    pub fn powi(self, power: i8) -> Self {
+        self.checked_powi(power)
+            .expect("dimension exponent out of range")
+    }
+
+    // None when an exponent leaves the i8 range, as in (m^100)^2
+    pub fn checked_powi(self, power: i8) -> Option<Self> {
+        self.combine(Dimension::NONE, |exponent, _| exponent.checked_mul(power))
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.combine(other, i8::checked_add)
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(other.checked_powi(-1)?)
+    }
+
+    fn combine(self, other: Self, exponent: impl Fn(i8, i8) -> Option<i8>) -> Option<Self> {
+        Some(Dimension::new(
+            exponent(self.mass, other.mass)?,
+            exponent(self.length, other.length)?,
+            exponent(self.time, other.time)?,
+            exponent(self.current, other.current)?,
+            exponent(self.temperature, other.temperature)?,
+            exponent(self.amount, other.amount)?,
+            exponent(self.luminous_intensity, other.luminous_intensity)?,
+        ))
+    }
+
+    // A quantity name such as "energy" or "magnetic moment" (case and "_" ignored),
+    // or else any unit, so "eV" and "kg m^2 s^-2" both give Dimension::ENERGY
+    pub fn parse(text: &str) -> Result<Self, UnitError> {
+        let name = text.trim().replace('_', " ");
+
+        match NAMED_DIMENSIONS
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(&name))
+        {
+            Some((_, dimension)) => Ok(*dimension),
+            None => parse_unit(text).map(|unit| unit.dimension),
+        }
+    }
+
+    // The quantity name of a dimension listed in NAMED_DIMENSIONS, e.g. "energy"
+    pub fn name(self) -> Option<&'static str> {
+        NAMED_DIMENSIONS
+            .iter()
+            .find(|(_, dimension)| *dimension == self)
+            .map(|(name, _)| *name)
+    }
+
+    fn exponents(self) -> [(&'static str, i8); 7] {
+        [
+            ("kg", self.mass),
+            ("m", self.length),
+            ("s", self.time),
+            ("A", self.current),
+            ("K", self.temperature),
+            ("mol", self.amount),
+            ("cd", self.luminous_intensity),
+        ]
+    }
+}
+
+impl Mul for Dimension {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .expect("dimension exponent out of range")
+    }
+}
+
+impl Div for Dimension {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(other)
+            .expect("dimension exponent out of range")
+    }
+}
+
+// In the table's notation, "kg m^2 s^-2"; dimensionless is "1"
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts: Vec<String> = self
+            .exponents()
+            .iter()
+            .filter(|(_, exponent)| *exponent != 0)
+            .map(|(symbol, exponent)| match exponent {
+                1 => symbol.to_string(),
+                exponent => format!("{}^{}", symbol, exponent),
+            })
+            .collect();
+
+        if parts.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(f, "{}", parts.join(" "))
+        }
+    }
+}
+
+// Quantity names accepted by Dimension::parse(); the first name of a dimension is the one
+// Dimension::name() gives
+const NAMED_DIMENSIONS: [(&str, Dimension); 34] = [
+    ("dimensionless", Dimension::NONE),
+    ("mass", Dimension::MASS),
+    ("length", Dimension::LENGTH),
+    ("time", Dimension::TIME),
+    ("current", Dimension::CURRENT),
+    ("temperature", Dimension::TEMPERATURE),
+    ("amount", Dimension::AMOUNT),
+    ("luminous intensity", Dimension::LUMINOUS_INTENSITY),
+    ("area", Dimension::AREA),
+    ("cross section", Dimension::AREA),
+    ("volume", Dimension::VOLUME),
+    ("wavenumber", Dimension::WAVENUMBER),
+    ("frequency", Dimension::FREQUENCY),
+    ("velocity", Dimension::VELOCITY),
+    ("speed", Dimension::VELOCITY),
+    ("acceleration", Dimension::ACCELERATION),
+    ("momentum", Dimension::MOMENTUM),
+    ("force", Dimension::FORCE),
+    ("pressure", Dimension::PRESSURE),
+    ("energy", Dimension::ENERGY),
+    ("power", Dimension::POWER),
+    ("action", Dimension::ACTION),
+    ("angular momentum", Dimension::ACTION),
+    ("entropy", Dimension::ENTROPY),
+    ("heat capacity", Dimension::ENTROPY),
+    ("charge", Dimension::CHARGE),
+    ("voltage", Dimension::VOLTAGE),
+    ("resistance", Dimension::RESISTANCE),
+    ("conductance", Dimension::CONDUCTANCE),
+    ("capacitance", Dimension::CAPACITANCE),
+    ("inductance", Dimension::INDUCTANCE),
+    ("magnetic flux", Dimension::MAGNETIC_FLUX),
+    ("magnetic field", Dimension::MAGNETIC_FIELD),
+    ("magnetic moment", Dimension::MAGNETIC_MOMENT),
+];
+
+// One unit in SI: multiplying a value by `scale` gives it in the base units of `dimension`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SiUnit {
+    pub scale: f64,
+    pub dimension: Dimension,
+}
+
+impl SiUnit {
+    pub const ONE: Self = Self::new(1.0, Dimension::NONE);
+
+    pub const fn new(scale: f64, dimension: Dimension) -> Self {
+        SiUnit { scale, dimension }
+    }
+
+    pub fn to_si(&self, value: f64) -> f64 {
+        value * self.scale
+    }
+
+    pub fn from_si(&self, value: f64) -> f64 {
+        value / self.scale
+    }
+
+    pub fn powi(self, power: i8) -> Self {
+        SiUnit::new(self.scale.powi(power as i32), self.dimension.powi(power))
+    }
+
+    // None when a dimension exponent leaves the i8 range
+    pub fn checked_powi(self, power: i8) -> Option<Self> {
+        Some(SiUnit::new(
+            self.scale.powi(power as i32),
+            self.dimension.checked_powi(power)?,
+        ))
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(SiUnit::new(
+            self.scale * other.scale,
+            self.dimension.checked_mul(other.dimension)?,
+        ))
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        Some(SiUnit::new(
+            self.scale / other.scale,
+            self.dimension.checked_div(other.dimension)?,
+        ))
+    }
+}
+
+impl Mul for SiUnit {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        SiUnit::new(self.scale * other.scale, self.dimension * other.dimension)
+    }
+}
+
+impl Div for SiUnit {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        SiUnit::new(self.scale / other.scale, self.dimension / other.dimension)
+    }
+}
+
+// "1.602176634e-13 kg m^2 s^-2", or just "kg m^2 s^-2" when the scale is 1
+impl fmt::Display for SiUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.scale == 1.0, self.dimension.is_dimensionless()) {
+            (true, _) => write!(f, "{}", self.dimension),
+            (false, true) => write!(f, "{:e}", self.scale),
+            (false, false) => write!(f, "{:e} {}", self.scale, self.dimension),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitError {
+    pub unit: String,
+    pub message: String,
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot read unit \"{}\": {}", self.unit, self.message)
+    }
+}
+
+impl std::error::Error for UnitError {}
+
+// Powers of ten of the SI prefixes used with the table's units
+const PREFIXES: [(char, i32); 9] = [
+    ('T', 12),
+    ('G', 9),
+    ('M', 6),
+    ('k', 3),
+    ('m', -3),
+    ('u', -6),
+    ('n', -9),
+    ('p', -12),
+    ('f', -15),
+];
+
+// Symbols used in the table; prefixed forms such as "MeV" and "fm" are handled separately.
+// eV, E_h, u and c take their values from the provider.
+fn symbol_unit(
+    symbol: &str,
+    provider: &dyn ConstantProvider,
+) -> Result<Option<SiUnit>, MissingConstant> {
+    let value = |name: &str| provider.require(name).map(|constant| constant.value);
+
+    let kilogram = SiUnit::new(1.0, Dimension::MASS);
+    let metre = SiUnit::new(1.0, Dimension::LENGTH);
+    let second = SiUnit::new(1.0, Dimension::TIME);
+    let ampere = SiUnit::new(1.0, Dimension::CURRENT);
+    let newton = kilogram * metre / second.powi(2);
+    let joule = newton * metre;
+    let watt = joule / second;
+    let coulomb = ampere * second;
+    let volt = watt / ampere;
+    let ohm = volt / ampere;
+    let weber = volt * second;
+
+    let unit = match symbol {
+        "kg" => kilogram,
+        "g" => SiUnit::new(1e-3, Dimension::MASS),
+        "m" => metre,
+        "s" => second,
+        "A" => ampere,
+        "K" => SiUnit::new(1.0, Dimension::TEMPERATURE),
+        "mol" => SiUnit::new(1.0, Dimension::AMOUNT),
+        "cd" => SiUnit::new(1.0, Dimension::LUMINOUS_INTENSITY),
+        // The steradian is dimensionless, so a lumen (cd sr) has the dimension of a candela
+        "sr" => SiUnit::ONE,
+        "lm" => SiUnit::new(1.0, Dimension::LUMINOUS_INTENSITY),
+        "Hz" => second.powi(-1),
+        "N" => newton,
+        "Pa" => newton / metre.powi(2),
+        "J" => joule,
+        "W" => watt,
+        "C" => coulomb,
+        "V" => volt,
+        "F" => coulomb / volt,
+        "ohm" => ohm,
+        "S" => ohm.powi(-1),
+        "Wb" => weber,
+        "T" => weber / metre.powi(2),
+        "H" => weber / ampere,
+        "eV" => SiUnit::new(value("electron volt")?, joule.dimension),
+        "E_h" => SiUnit::new(value("Hartree energy")?, joule.dimension),
+        "u" => SiUnit::new(value("atomic mass constant")?, Dimension::MASS),
+        // As in MeV/c and (GeV/c^2)^-2
+        "c" => SiUnit::new(
+            value("speed of light in vacuum")?,
+            Dimension::LENGTH / Dimension::TIME,
+        ),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(unit))
+}
+
+fn prefixed_unit(
+    symbol: &str,
+    provider: &dyn ConstantProvider,
+) -> Result<Option<SiUnit>, MissingConstant> {
+    if let Some(unit) = symbol_unit(symbol, provider)? {
+        return Ok(Some(unit));
+    }
+
+    let mut characters = symbol.chars();
+    let Some(prefix) = characters.next() else {
+        return Ok(None);
+    };
+    let Some((_, power)) = PREFIXES.iter().find(|(known, _)| *known == prefix) else {
+        return Ok(None);
+    };
+
+    // No prefixes on kg, u or c, which would only produce nonsense like "kkg"
+    match characters.as_str() {
+        "kg" | "u" | "c" | "" => Ok(None),
+        rest => Ok(symbol_unit(rest, provider)?.and_then(|unit| {
+            // Shifting the decimal exponent avoids the rounding of 1e6 × 1.602176634e-19
+            let scale = Decimal::from_f64(unit.scale)?;
+            let scale = Decimal::new(scale.mantissa, scale.exponent + power).to_f64();
+            Some(SiUnit::new(scale, unit.dimension))
+        })),
+    }
+}
+
+// Reads the table's notation: symbols separated by spaces multiply, "/" divides by the
+// next factor only, "^" takes an integer power and parentheses group
+pub fn parse_unit(text: &str) -> Result<SiUnit, UnitError> {
+    parse_unit_with_provider(text, &Codata)
+}
+
+// Like parse_unit(), with eV, E_h, u and c as another edition or an edited dataset has them
+pub fn parse_unit_with_provider(
+    text: &str,
+    provider: &dyn ConstantProvider,
+) -> Result<SiUnit, UnitError> {
+    let mut parser = UnitParser {
+        text,
+        provider,
+        characters: text.chars().collect(),
+        position: 0,
+    };
+
+    let unit = parser.product()?;
+    match parser.peek() {
+        None => Ok(unit),
+        Some(character) => Err(parser.error(format!("unexpected '{}'", character))),
+    }
+}
+
+struct UnitParser<'a> {
+    text: &'a str,
+    provider: &'a dyn ConstantProvider,
+    characters: Vec<char>,
+    position: usize,
+}
+
+impl UnitParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.characters.get(self.position).copied()
+    }
+
+    fn error(&self, message: String) -> UnitError {
+        UnitError {
+            unit: self.text.to_string(),
+            message,
+        }
+    }
+
+    fn out_of_range(&self) -> UnitError {
+        self.error(format!("an exponent is outside {}..={}", i8::MIN, i8::MAX))
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(' ') {
+            self.position += 1;
+        }
+    }
+
+    fn product(&mut self) -> Result<SiUnit, UnitError> {
+        self.skip_spaces();
+        let mut unit = self.power()?;
+
+        loop {
+            self.skip_spaces();
+            match self.peek() {
+                Some('/') => {
+                    self.position += 1;
+                    self.skip_spaces();
+                    let divisor = self.power()?;
+                    unit = unit
+                        .checked_div(divisor)
+                        .ok_or_else(|| self.out_of_range())?;
+                }
+                Some(character) if character == '(' || character.is_ascii_alphabetic() => {
+                    let factor = self.power()?;
+                    unit = unit
+                        .checked_mul(factor)
+                        .ok_or_else(|| self.out_of_range())?;
+                }
+                _ => return Ok(unit),
+            }
+        }
+    }
+
+    fn power(&mut self) -> Result<SiUnit, UnitError> {
+        let unit = self.atom()?;
+
+        if self.peek() != Some('^') {
+            return Ok(unit);
+        }
+        self.position += 1;
+
+        let start = self.position;
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        let exponent: String = self.characters[start..self.position].iter().collect();
+        let exponent = exponent
+            .parse::<i8>()
+            .map_err(|_| self.error(format!("\"{}\" is not an integer exponent", exponent)))?;
+
+        unit.checked_powi(exponent)
+            .ok_or_else(|| self.out_of_range())
+    }
+
+    fn atom(&mut self) -> Result<SiUnit, UnitError> {
+        if self.peek() == Some('(') {
+            self.position += 1;
+            let unit = self.product()?;
+            if self.peek() != Some(')') {
+                return Err(self.error("missing ')'".to_string()));
+            }
+            self.position += 1;
+            return Ok(unit);
+        }
+
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        {
+            self.position += 1;
+        }
+
+        let symbol: String = self.characters[start..self.position].iter().collect();
+        if symbol.is_empty() {
+            return Err(self.error("expected a unit symbol".to_string()));
+        }
+
+        prefixed_unit(&symbol, self.provider)
+            .map_err(|error| self.error(error.to_string()))?
+            .ok_or_else(|| self.error(format!("unknown symbol \"{}\"", symbol)))
+    }
+}
+
+impl Constant {
+    // The unit in SI base units; dimensionless constants give SiUnit::ONE, unknown units
+    // and units that cannot be read give None
+    pub fn si_unit(&self) -> Option<SiUnit> {
+        self.unit.si_unit()
+    }
+
+    pub fn dimension(&self) -> Option<Dimension> {
+        self.si_unit().map(|unit| unit.dimension)
+    }
+
+    // The value converted to SI base units, e.g. MeV to kg m^2 s^-2 (joules)
+    pub fn si_value(&self) -> Option<f64> {
+        self.si_unit().map(|unit| unit.to_si(self.value))
+    }
+}
+
+impl Dataset {
+    // Every constant of the given dimension whatever its unit string, so
+    // Dimension::ENERGY finds J, eV, MeV, GeV and E_h alike; unknown units never match
+    pub fn by_dimension(&self, dimension: Dimension) -> Vec<&Constant> {
+        self.constants
+            .iter()
+            .filter(|constant| constant.dimension() == Some(dimension))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::{Perturbation, Perturbed};
+
+    fn unit(text: &str) -> SiUnit {
+        parse_unit(text).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual / expected - 1.0).abs() < 1e-12,
+            "{:e} is not {:e}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn base_and_derived_units() {
+        assert_eq!(unit("kg m^2 s^-2").dimension, Dimension::ENERGY);
+        assert_eq!(unit("J").dimension, Dimension::ENERGY);
+        assert_eq!(unit("C/V").dimension, Dimension::CAPACITANCE);
+        assert_eq!(unit("F").dimension, Dimension::CAPACITANCE);
+        assert_eq!(unit("W m^-2 K^-4").scale, 1.0);
+        assert_eq!(unit("sr"), SiUnit::ONE);
+    }
+
+    #[test]
+    fn prefixes_and_table_constants() {
+        assert_eq!(unit("MeV").scale, 1.602176634e-13);
+        assert_eq!(unit("fm").scale, 1e-15);
+        assert_eq!(unit("g").scale, 1e-3);
+        assert_close(unit("u").scale, 1.66053906892e-27);
+        assert_eq!(unit("MeV/c").dimension, Dimension::MOMENTUM);
+
+        let inverse_mass_squared = unit("(GeV/c^2)^-2");
+        assert_eq!(inverse_mass_squared.dimension, Dimension::MASS.powi(-2));
+        assert_close(
+            inverse_mass_squared.scale,
+            (1.602176634e-10 / 299792458f64.powi(2)).powi(-2),
+        );
+    }
+
+    #[test]
+    fn slash_divides_by_the_next_factor_only() {
+        assert_eq!(unit("J/mol K").dimension, unit("J K mol^-1").dimension);
+        assert_eq!(unit("J/(mol K)").dimension, unit("J K^-1 mol^-1").dimension);
+    }
+
+    #[test]
+    fn rejects_malformed_units() {
+        for text in ["", "kkg", "xyz", "m^", "m^x", "(m", "m)", "m^1.5", "Mc"] {
+            assert!(parse_unit(text).is_err(), "{:?}", text);
+        }
+        assert_eq!(
+            parse_unit("furlong").unwrap_err().message,
+            "unknown symbol \"furlong\""
+        );
+    }
+
+    #[test]
+    fn rejects_exponents_out_of_range() {
+        assert!(parse_unit("m^127").is_ok());
+        assert!(parse_unit("m^128").is_err());
+        assert!(parse_unit("m^100 m^100").is_err());
+        assert!(parse_unit("(m^-128)^-1").is_err());
+        assert!(parse_unit("m^-128/m").is_err());
+    }
+
+    #[test]
+    fn units_follow_the_provider() {
+        let provider = Perturbed::new(Codata).with("electron volt", Perturbation::Factor(2.0));
+        assert_eq!(
+            parse_unit_with_provider("keV", &provider).unwrap().scale,
+            2.0 * 1.602176634e-16
+        );
+
+        let mut dataset = Codata.to_dataset();
+        dataset
+            .constants
+            .retain(|constant| constant.name != "Hartree energy");
+        let error = parse_unit_with_provider("E_h", &dataset).unwrap_err();
+        assert!(error.message.contains("Hartree energy"), "{}", error);
+    }
+
+    #[test]
+    fn dimension_names() {
+        assert_eq!(Dimension::parse("energy"), Ok(Dimension::ENERGY));
+        assert_eq!(
+            Dimension::parse("magnetic_flux"),
+            Ok(Dimension::MAGNETIC_FLUX)
+        );
+        assert_eq!(Dimension::parse("N m"), Ok(Dimension::ENERGY));
+        assert_eq!(Dimension::ENERGY.name(), Some("energy"));
+        assert_eq!(Dimension::ENERGY.to_string(), "kg m^2 s^-2");
+    }
+
+    #[test]
+    fn every_published_unit_is_read() {
+        for constant in &Codata::dataset().constants {
+            if let Unit::Published(text) = &constant.unit {
+                assert!(parse_unit(text).is_ok(), "{}: {}", constant.name, text);
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Checks that a constant's unit means something: a published unit is never an empty
+// string (dimensionless constants use Unit::Dimensionless) and reads as SI base units.
+// The value must be finite and an uncertainty finite and not negative.
+use std::fmt;
+
+use crate::units::{parse_unit, Unit, UnitError};
+use crate::{Constant, Dataset};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub name: String,
+    pub problem: ValidationProblem,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationProblem {
+    // Unit::Published("") or only whitespace
+    EmptyUnit,
+    UnreadableUnit(UnitError),
+    NonFiniteValue,
+    InvalidUncertainty,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.problem {
+            ValidationProblem::EmptyUnit => write!(
+                f,
+                "\"{}\" has an empty unit; use Unit::Dimensionless instead",
+                self.name
+            ),
+            ValidationProblem::UnreadableUnit(error) => write!(f, "\"{}\": {}", self.name, error),
+            ValidationProblem::NonFiniteValue => {
+                write!(f, "\"{}\" has a value that is not finite", self.name)
+            }
+            ValidationProblem::InvalidUncertainty => write!(
+                f,
+                "\"{}\" has an uncertainty that is negative or not finite",
+                self.name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl Constant {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let error = |problem| ValidationError {
+            name: self.name.to_string(),
+            problem,
+        };
+
+        if !self.value.is_finite() {
+            return Err(error(ValidationProblem::NonFiniteValue));
+        }
+        if self
+            .uncertainty
+            .is_some_and(|uncertainty| !uncertainty.is_finite() || uncertainty < 0.0)
+        {
+            return Err(error(ValidationProblem::InvalidUncertainty));
+        }
+
+        match &self.unit {
+            Unit::Published(unit) if unit.trim().is_empty() => {
+                Err(error(ValidationProblem::EmptyUnit))
+            }
+            Unit::Published(unit) => parse_unit(unit)
+                .map(|_| ())
+                .map_err(|unit_error| error(ValidationProblem::UnreadableUnit(unit_error))),
+            Unit::Dimensionless | Unit::Unknown => Ok(()),
+        }
+    }
+}
+
+impl Dataset {
+    // Every problem in the dataset, not just the first
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let errors: Vec<_> = self
+            .constants
+            .iter()
+            .filter_map(|constant| constant.validate().err())
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}